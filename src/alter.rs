@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -12,16 +12,18 @@ use alloc::vec::Vec;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::{
+    create::parse_table_option,
     data_type::parse_data_type,
     keywords::Keyword,
     lexer::Token,
-    parser::{ParseError, Parser},
+    parser::{parse_if_exists, parse_if_not_exists, ParseError, Parser},
     qualified_name::parse_qualified_name,
-    DataType, Identifier, Issue, QualifiedName, SString, Span, Spanned, Statement,
+    DataType, Identifier, Issue, QualifiedName, SString, Span, Spanned, SpansMut, Statement,
+    TableOption,
 };
 
 /// Option on an index
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum IndexOption<'a> {
     /// The index should be a BTree
     IndexTypeBTree(Span),
@@ -31,6 +33,14 @@ pub enum IndexOption<'a> {
     IndexTypeRTree(Span),
     /// Attach a comment to the index
     Comment(SString<'a>),
+    /// Span of "KEY_BLOCK_SIZE" and the requested block size
+    KeyBlockSize(Span, (usize, Span)),
+    /// Span of "WITH PARSER" and the full-text parser plugin to use
+    WithParser(Span, Identifier<'a>),
+    /// Span of "VISIBLE"
+    Visible(Span),
+    /// Span of "INVISIBLE", the index is maintained but not used by the optimizer
+    Invisible(Span),
 }
 
 impl<'a> Spanned for IndexOption<'a> {
@@ -40,18 +50,48 @@ impl<'a> Spanned for IndexOption<'a> {
             IndexOption::IndexTypeHash(v) => v.span(),
             IndexOption::IndexTypeRTree(v) => v.span(),
             IndexOption::Comment(v) => v.span(),
+            IndexOption::KeyBlockSize(s, v) => s.join_span(v),
+            IndexOption::WithParser(s, v) => s.join_span(v),
+            IndexOption::Visible(v) => v.span(),
+            IndexOption::Invisible(v) => v.span(),
+        }
+    }
+}
+
+impl<'a> SpansMut for IndexOption<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            IndexOption::IndexTypeBTree(v) => v.spans_mut(f),
+            IndexOption::IndexTypeHash(v) => v.spans_mut(f),
+            IndexOption::IndexTypeRTree(v) => v.spans_mut(f),
+            IndexOption::Comment(v) => v.spans_mut(f),
+            IndexOption::KeyBlockSize(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            IndexOption::WithParser(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            IndexOption::Visible(v) => v.spans_mut(f),
+            IndexOption::Invisible(v) => v.spans_mut(f),
         }
     }
 }
 
 /// Type of index to add
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum IndexType {
-    Index(Span),
+    /// "INDEX" or "KEY"
+    Index(IndexKeyword),
+    /// Span of "PRIMARY KEY"
     Primary(Span),
-    Unique(Span),
-    FullText(Span),
-    Spatial(Span),
+    /// Span of "UNIQUE" and the following "INDEX" or "KEY" if specified
+    Unique(Span, Option<IndexKeyword>),
+    /// Span of "FULLTEXT" and the following "INDEX" or "KEY" if specified
+    FullText(Span, Option<IndexKeyword>),
+    /// Span of "SPATIAL" and the following "INDEX" or "KEY" if specified
+    Spatial(Span, Option<IndexKeyword>),
 }
 
 impl Spanned for IndexType {
@@ -59,15 +99,63 @@ impl Spanned for IndexType {
         match &self {
             IndexType::Index(v) => v.span(),
             IndexType::Primary(v) => v.span(),
-            IndexType::Unique(v) => v.span(),
-            IndexType::FullText(v) => v.span(),
-            IndexType::Spatial(v) => v.span(),
+            IndexType::Unique(s, v) => s.join_span(v),
+            IndexType::FullText(s, v) => s.join_span(v),
+            IndexType::Spatial(s, v) => s.join_span(v),
+        }
+    }
+}
+
+impl SpansMut for IndexType {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            IndexType::Index(v) => v.spans_mut(f),
+            IndexType::Primary(v) => v.spans_mut(f),
+            IndexType::Unique(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            IndexType::FullText(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            IndexType::Spatial(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+        }
+    }
+}
+
+/// Which of the synonyms "INDEX" and "KEY" was used
+#[derive(Clone, Debug, PartialEq)]
+pub enum IndexKeyword {
+    /// Span of "INDEX"
+    Index(Span),
+    /// Span of "KEY"
+    Key(Span),
+}
+
+impl Spanned for IndexKeyword {
+    fn span(&self) -> Span {
+        match &self {
+            IndexKeyword::Index(v) => v.span(),
+            IndexKeyword::Key(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for IndexKeyword {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            IndexKeyword::Index(v) => v.spans_mut(f),
+            IndexKeyword::Key(v) => v.spans_mut(f),
         }
     }
 }
 
 /// When to take a foreign key action
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ForeignKeyOnType {
     Update(Span),
     Delete(Span),
@@ -82,8 +170,17 @@ impl Spanned for ForeignKeyOnType {
     }
 }
 
+impl SpansMut for ForeignKeyOnType {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ForeignKeyOnType::Update(v) => v.spans_mut(f),
+            ForeignKeyOnType::Delete(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Action to take on event for foreign key
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ForeignKeyOnAction {
     Restrict(Span),
     Cascade(Span),
@@ -104,8 +201,20 @@ impl Spanned for ForeignKeyOnAction {
     }
 }
 
+impl SpansMut for ForeignKeyOnAction {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ForeignKeyOnAction::Restrict(v) => v.spans_mut(f),
+            ForeignKeyOnAction::Cascade(v) => v.spans_mut(f),
+            ForeignKeyOnAction::SetNull(v) => v.spans_mut(f),
+            ForeignKeyOnAction::NoAction(v) => v.spans_mut(f),
+            ForeignKeyOnAction::SetDefault(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Action to perform on events on foreign keys
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ForeignKeyOn {
     pub type_: ForeignKeyOnType,
     pub action: ForeignKeyOnAction,
@@ -117,8 +226,15 @@ impl Spanned for ForeignKeyOn {
     }
 }
 
+impl SpansMut for ForeignKeyOn {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.type_.spans_mut(f);
+        self.action.spans_mut(f);
+    }
+}
+
 /// Specify a column for an index, together with a with
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct IndexCol<'a> {
     /// The name of the column
     pub name: Identifier<'a>,
@@ -132,14 +248,143 @@ impl<'a> Spanned for IndexCol<'a> {
     }
 }
 
+impl<'a> SpansMut for IndexCol<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.name.spans_mut(f);
+        self.size.spans_mut(f);
+    }
+}
+
+/// Algorithm to use for an alter table, given by "ALGORITHM [=] algorithm"
+#[derive(Clone, Debug, PartialEq)]
+pub enum AlterAlgorithm {
+    /// Span of "DEFAULT"
+    Default(Span),
+    /// Span of "INPLACE"
+    Inplace(Span),
+    /// Span of "COPY"
+    Copy(Span),
+    /// Span of "NOCOPY"
+    Nocopy(Span),
+    /// Span of "INSTANT"
+    Instant(Span),
+}
+
+impl Spanned for AlterAlgorithm {
+    fn span(&self) -> Span {
+        match &self {
+            AlterAlgorithm::Default(v) => v.span(),
+            AlterAlgorithm::Inplace(v) => v.span(),
+            AlterAlgorithm::Copy(v) => v.span(),
+            AlterAlgorithm::Nocopy(v) => v.span(),
+            AlterAlgorithm::Instant(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for AlterAlgorithm {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            AlterAlgorithm::Default(v) => v.spans_mut(f),
+            AlterAlgorithm::Inplace(v) => v.spans_mut(f),
+            AlterAlgorithm::Copy(v) => v.spans_mut(f),
+            AlterAlgorithm::Nocopy(v) => v.spans_mut(f),
+            AlterAlgorithm::Instant(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Lock to take during an alter table, given by "LOCK [=] lock"
+#[derive(Clone, Debug, PartialEq)]
+pub enum AlterLock {
+    /// Span of "DEFAULT"
+    Default(Span),
+    /// Span of "NONE"
+    None(Span),
+    /// Span of "SHARED"
+    Shared(Span),
+    /// Span of "EXCLUSIVE"
+    Exclusive(Span),
+}
+
+impl Spanned for AlterLock {
+    fn span(&self) -> Span {
+        match &self {
+            AlterLock::Default(v) => v.span(),
+            AlterLock::None(v) => v.span(),
+            AlterLock::Shared(v) => v.span(),
+            AlterLock::Exclusive(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for AlterLock {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            AlterLock::Default(v) => v.spans_mut(f),
+            AlterLock::None(v) => v.spans_mut(f),
+            AlterLock::Shared(v) => v.spans_mut(f),
+            AlterLock::Exclusive(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Position of an added or modified column
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnPosition<'a> {
+    /// Place the column first, span of "FIRST"
+    First(Span),
+    /// Place the column after another column, span of "AFTER" and the column
+    After(Span, Identifier<'a>),
+}
+
+impl<'a> Spanned for ColumnPosition<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            ColumnPosition::First(v) => v.span(),
+            ColumnPosition::After(s, v) => s.join_span(v),
+        }
+    }
+}
+
+impl<'a> SpansMut for ColumnPosition<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ColumnPosition::First(v) => v.spans_mut(f),
+            ColumnPosition::After(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+        }
+    }
+}
+
+fn parse_column_position<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<Option<ColumnPosition<'a>>, ParseError> {
+    Ok(match &parser.token {
+        Token::Ident(_, Keyword::FIRST) => Some(ColumnPosition::First(parser.consume())),
+        Token::Ident(_, Keyword::AFTER) => {
+            let after_span = parser.consume();
+            Some(ColumnPosition::After(
+                after_span,
+                parser.consume_plain_identifier()?,
+            ))
+        }
+        _ => None,
+    })
+}
+
 /// Enum of alterations to perform on a table
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum AlterSpecification<'a> {
     AddColumn {
         add_span: Span,
         if_not_exists_span: Option<Span>,
         identifier: Identifier<'a>,
         data_type: DataType<'a>,
+        /// Position of the new column if specified
+        position: Option<ColumnPosition<'a>>,
     },
     /// Add an index
     AddIndex {
@@ -191,6 +436,69 @@ pub enum AlterSpecification<'a> {
         col: Identifier<'a>,
         /// New definition of column
         definition: DataType<'a>,
+        /// New position of the column if specified
+        position: Option<ColumnPosition<'a>>,
+    },
+    /// Drop an index
+    DropIndex {
+        /// Span of "DROP INDEX" or "DROP KEY"
+        drop_index_span: Span,
+        /// Span of "IF EXISTS" if specified
+        if_exists: Option<Span>,
+        /// Name of index to drop
+        name: Identifier<'a>,
+    },
+    /// Drop the primary key
+    DropPrimaryKey {
+        /// Span of "DROP PRIMARY KEY"
+        drop_primary_key_span: Span,
+    },
+    /// Drop a foreign key
+    DropForeignKey {
+        /// Span of "DROP FOREIGN KEY"
+        drop_foreign_key_span: Span,
+        /// Span of "IF EXISTS" if specified
+        if_exists: Option<Span>,
+        /// Name of foreign key to drop
+        name: Identifier<'a>,
+    },
+    /// Rename an index
+    RenameIndex {
+        /// Span of "RENAME"
+        rename_span: Span,
+        /// "INDEX" or "KEY"
+        index_keyword: IndexKeyword,
+        /// Current name of the index
+        old_name: Identifier<'a>,
+        /// Span of "TO"
+        to_span: Span,
+        /// New name of the index
+        new_name: Identifier<'a>,
+    },
+    /// Rename the table
+    RenameTo {
+        /// Span of "RENAME"
+        rename_span: Span,
+        /// Span of "TO" or "AS" if specified
+        to_span: Option<Span>,
+        /// New name of the table
+        new_name: QualifiedName<'a>,
+    },
+    /// Change a table option
+    SetOption(TableOption<'a>),
+    /// Algorithm to perform the alteration with
+    Algorithm {
+        /// Span of "ALGORITHM" and the "=" if specified
+        algorithm_span: Span,
+        /// The algorithm to use
+        algorithm: AlterAlgorithm,
+    },
+    /// Lock to hold while performing the alteration
+    Lock {
+        /// Span of "LOCK" and the "=" if specified
+        lock_span: Span,
+        /// The lock to hold
+        lock: AlterLock,
     },
     /// Modify a column
     OwnerTo {
@@ -209,10 +517,12 @@ impl<'a> Spanned for AlterSpecification<'a> {
                 if_not_exists_span,
                 identifier,
                 data_type,
+                position,
             } => add_span
                 .join_span(if_not_exists_span)
                 .join_span(identifier)
-                .join_span(data_type),
+                .join_span(data_type)
+                .join_span(position),
             AlterSpecification::AddIndex {
                 add_span,
                 index_type,
@@ -254,16 +564,185 @@ impl<'a> Spanned for AlterSpecification<'a> {
                 if_exists,
                 col,
                 definition,
+                position,
             } => modify_span
                 .join_span(if_exists)
                 .join_span(col)
-                .join_span(definition),
+                .join_span(definition)
+                .join_span(position),
+            AlterSpecification::DropIndex {
+                drop_index_span,
+                if_exists,
+                name,
+            } => drop_index_span.join_span(if_exists).join_span(name),
+            AlterSpecification::DropPrimaryKey {
+                drop_primary_key_span,
+            } => drop_primary_key_span.clone(),
+            AlterSpecification::DropForeignKey {
+                drop_foreign_key_span,
+                if_exists,
+                name,
+            } => drop_foreign_key_span.join_span(if_exists).join_span(name),
+            AlterSpecification::RenameIndex {
+                rename_span,
+                index_keyword,
+                old_name,
+                to_span,
+                new_name,
+            } => rename_span
+                .join_span(index_keyword)
+                .join_span(old_name)
+                .join_span(to_span)
+                .join_span(new_name),
+            AlterSpecification::RenameTo {
+                rename_span,
+                to_span,
+                new_name,
+            } => rename_span.join_span(to_span).join_span(new_name),
+            AlterSpecification::SetOption(v) => v.span(),
+            AlterSpecification::Algorithm {
+                algorithm_span,
+                algorithm,
+            } => algorithm_span.join_span(algorithm),
+            AlterSpecification::Lock { lock_span, lock } => lock_span.join_span(lock),
             AlterSpecification::OwnerTo { span, owner } => span.join_span(owner),
         }
     }
 }
 
-fn parse_index_type<'a>(
+impl<'a> SpansMut for AlterSpecification<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            AlterSpecification::AddColumn {
+                add_span,
+                if_not_exists_span,
+                identifier,
+                data_type,
+                position,
+            } => {
+                add_span.spans_mut(f);
+                if_not_exists_span.spans_mut(f);
+                identifier.spans_mut(f);
+                data_type.spans_mut(f);
+                position.spans_mut(f);
+            }
+            AlterSpecification::AddIndex {
+                add_span,
+                index_type,
+                if_not_exists,
+                name,
+                constraint,
+                cols,
+                index_options,
+            } => {
+                add_span.spans_mut(f);
+                index_type.spans_mut(f);
+                if_not_exists.spans_mut(f);
+                name.spans_mut(f);
+                constraint.spans_mut(f);
+                cols.spans_mut(f);
+                index_options.spans_mut(f);
+            }
+            AlterSpecification::AddForeignKey {
+                add_span,
+                constraint,
+                foreign_key_span,
+                if_not_exists,
+                name,
+                cols,
+                references_span,
+                references_table,
+                references_cols,
+                ons,
+            } => {
+                add_span.spans_mut(f);
+                constraint.spans_mut(f);
+                foreign_key_span.spans_mut(f);
+                if_not_exists.spans_mut(f);
+                name.spans_mut(f);
+                cols.spans_mut(f);
+                references_span.spans_mut(f);
+                references_table.spans_mut(f);
+                references_cols.spans_mut(f);
+                ons.spans_mut(f);
+            }
+            AlterSpecification::Modify {
+                modify_span,
+                if_exists,
+                col,
+                definition,
+                position,
+            } => {
+                modify_span.spans_mut(f);
+                if_exists.spans_mut(f);
+                col.spans_mut(f);
+                definition.spans_mut(f);
+                position.spans_mut(f);
+            }
+            AlterSpecification::DropIndex {
+                drop_index_span,
+                if_exists,
+                name,
+            } => {
+                drop_index_span.spans_mut(f);
+                if_exists.spans_mut(f);
+                name.spans_mut(f);
+            }
+            AlterSpecification::DropPrimaryKey {
+                drop_primary_key_span,
+            } => drop_primary_key_span.spans_mut(f),
+            AlterSpecification::DropForeignKey {
+                drop_foreign_key_span,
+                if_exists,
+                name,
+            } => {
+                drop_foreign_key_span.spans_mut(f);
+                if_exists.spans_mut(f);
+                name.spans_mut(f);
+            }
+            AlterSpecification::RenameIndex {
+                rename_span,
+                index_keyword,
+                old_name,
+                to_span,
+                new_name,
+            } => {
+                rename_span.spans_mut(f);
+                index_keyword.spans_mut(f);
+                old_name.spans_mut(f);
+                to_span.spans_mut(f);
+                new_name.spans_mut(f);
+            }
+            AlterSpecification::RenameTo {
+                rename_span,
+                to_span,
+                new_name,
+            } => {
+                rename_span.spans_mut(f);
+                to_span.spans_mut(f);
+                new_name.spans_mut(f);
+            }
+            AlterSpecification::SetOption(v) => v.spans_mut(f),
+            AlterSpecification::Algorithm {
+                algorithm_span,
+                algorithm,
+            } => {
+                algorithm_span.spans_mut(f);
+                algorithm.spans_mut(f);
+            }
+            AlterSpecification::Lock { lock_span, lock } => {
+                lock_span.spans_mut(f);
+                lock.spans_mut(f);
+            }
+            AlterSpecification::OwnerTo { span, owner } => {
+                span.spans_mut(f);
+                owner.spans_mut(f);
+            }
+        }
+    }
+}
+
+pub(crate) fn parse_index_type<'a>(
     parser: &mut Parser<'a, '_>,
     out: &mut Vec<IndexOption<'a>>,
 ) -> Result<(), ParseError> {
@@ -283,7 +762,7 @@ fn parse_index_type<'a>(
     Ok(())
 }
 
-fn parse_index_options<'a>(
+pub(crate) fn parse_index_options<'a>(
     parser: &mut Parser<'a, '_>,
     out: &mut Vec<IndexOption<'a>>,
 ) -> Result<(), ParseError> {
@@ -294,13 +773,31 @@ fn parse_index_options<'a>(
                 parser.consume_keyword(Keyword::COMMENT)?;
                 out.push(IndexOption::Comment(parser.consume_string()?))
             }
+            Token::Ident(_, Keyword::KEY_BLOCK_SIZE) => {
+                let span = parser.consume_keyword(Keyword::KEY_BLOCK_SIZE)?;
+                parser.skip_token(Token::Eq);
+                out.push(IndexOption::KeyBlockSize(span, parser.consume_int()?))
+            }
+            Token::Ident(_, Keyword::WITH) => {
+                let span = parser.consume_keywords(&[Keyword::WITH, Keyword::PARSER])?;
+                out.push(IndexOption::WithParser(
+                    span,
+                    parser.consume_plain_identifier()?,
+                ))
+            }
+            Token::Ident(_, Keyword::VISIBLE) => out.push(IndexOption::Visible(parser.consume())),
+            Token::Ident(_, Keyword::INVISIBLE) => {
+                out.push(IndexOption::Invisible(parser.consume()))
+            }
             _ => break,
         }
     }
     Ok(())
 }
 
-fn parse_index_cols<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<IndexCol<'a>>, ParseError> {
+pub(crate) fn parse_index_cols<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<Vec<IndexCol<'a>>, ParseError> {
     parser.consume_token(Token::LParen)?;
     let mut ans = Vec::new();
     parser.recovered("')'", &|t| t == &Token::RParen, |parser| {
@@ -329,7 +826,9 @@ fn parse_index_cols<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<IndexCol<'a>>
     Ok(ans)
 }
 
-fn parse_cols<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<Identifier<'a>>, ParseError> {
+pub(crate) fn parse_cols<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<Vec<Identifier<'a>>, ParseError> {
     parser.consume_token(Token::LParen)?;
     let mut ans = Vec::new();
     parser.recovered("')'", &|t| t == &Token::RParen, |parser| {
@@ -345,6 +844,115 @@ fn parse_cols<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<Identifier<'a>>, Pa
     Ok(ans)
 }
 
+fn skip_index_keyword(parser: &mut Parser<'_, '_>) -> Option<IndexKeyword> {
+    match &parser.token {
+        Token::Ident(_, Keyword::INDEX) => Some(IndexKeyword::Index(parser.consume())),
+        Token::Ident(_, Keyword::KEY) => Some(IndexKeyword::Key(parser.consume())),
+        _ => None,
+    }
+}
+
+fn parse_index_keyword(parser: &mut Parser<'_, '_>) -> Result<IndexKeyword, ParseError> {
+    match skip_index_keyword(parser) {
+        Some(v) => Ok(v),
+        None => parser.expected_failure("'INDEX' or 'KEY'"),
+    }
+}
+
+/// Parse the leading "PRIMARY KEY", "{INDEX | KEY}", "UNIQUE [INDEX | KEY]",
+/// "FULLTEXT [INDEX | KEY]" or "SPATIAL [INDEX | KEY]" of an index definition
+pub(crate) fn parse_index_definition_type(
+    parser: &mut Parser<'_, '_>,
+) -> Result<IndexType, ParseError> {
+    Ok(match &parser.token {
+        Token::Ident(_, Keyword::PRIMARY) => {
+            IndexType::Primary(parser.consume_keywords(&[Keyword::PRIMARY, Keyword::KEY])?)
+        }
+        Token::Ident(_, Keyword::INDEX | Keyword::KEY) => {
+            IndexType::Index(parse_index_keyword(parser)?)
+        }
+        Token::Ident(_, Keyword::UNIQUE) => {
+            let unique_span = parser.consume_keyword(Keyword::UNIQUE)?;
+            match &parser.token {
+                Token::Ident(_, kw @ (Keyword::FULLTEXT | Keyword::SPATIAL)) => {
+                    parser.issues.push(Issue::err(
+                        format!("{} indexes cannot be UNIQUE", kw.name()),
+                        &unique_span,
+                    ));
+                    parse_index_definition_type(parser)?
+                }
+                _ => IndexType::Unique(unique_span, skip_index_keyword(parser)),
+            }
+        }
+        Token::Ident(_, Keyword::FULLTEXT) => {
+            let span = parser.consume_keyword(Keyword::FULLTEXT)?;
+            if let Some(unique_span) = parser.skip_keyword(Keyword::UNIQUE) {
+                parser.issues.push(Issue::err(
+                    "FULLTEXT indexes cannot be UNIQUE",
+                    &unique_span,
+                ));
+            }
+            IndexType::FullText(span, skip_index_keyword(parser))
+        }
+        Token::Ident(_, Keyword::SPATIAL) => {
+            let span = parser.consume_keyword(Keyword::SPATIAL)?;
+            if let Some(unique_span) = parser.skip_keyword(Keyword::UNIQUE) {
+                parser
+                    .issues
+                    .push(Issue::err("SPATIAL indexes cannot be UNIQUE", &unique_span));
+            }
+            IndexType::Spatial(span, skip_index_keyword(parser))
+        }
+        _ => parser
+            .expected_failure("'PRIMARY', 'INDEX', 'KEY', 'UNIQUE', 'FULLTEXT' or 'SPATIAL'")?,
+    })
+}
+
+/// Parse a list of "ON {UPDATE | DELETE} action" following a foreign key
+pub(crate) fn parse_foreign_key_ons(
+    parser: &mut Parser<'_, '_>,
+) -> Result<Vec<ForeignKeyOn>, ParseError> {
+    let mut ons = Vec::new();
+    while let Some(on) = parser.skip_keyword(Keyword::ON) {
+        let type_ = match parser.token {
+            Token::Ident(_, Keyword::UPDATE) => {
+                ForeignKeyOnType::Update(parser.consume_keyword(Keyword::UPDATE)?.join_span(&on))
+            }
+            Token::Ident(_, Keyword::DELETE) => {
+                ForeignKeyOnType::Delete(parser.consume_keyword(Keyword::DELETE)?.join_span(&on))
+            }
+            _ => parser.expected_failure("'UPDATE' or 'DELETE'")?,
+        };
+
+        let action = match parser.token {
+            Token::Ident(_, Keyword::RESTRICT) => {
+                ForeignKeyOnAction::Restrict(parser.consume_keyword(Keyword::RESTRICT)?)
+            }
+            Token::Ident(_, Keyword::CASCADE) => {
+                ForeignKeyOnAction::Cascade(parser.consume_keyword(Keyword::CASCADE)?)
+            }
+            Token::Ident(_, Keyword::SET) => {
+                let set = parser.consume_keyword(Keyword::SET)?;
+                match parser.token {
+                    Token::Ident(_, Keyword::NULL) => ForeignKeyOnAction::SetNull(
+                        parser.consume_keyword(Keyword::NULL)?.join_span(&set),
+                    ),
+                    Token::Ident(_, Keyword::DEFAULT) => ForeignKeyOnAction::SetDefault(
+                        parser.consume_keyword(Keyword::DEFAULT)?.join_span(&set),
+                    ),
+                    _ => parser.expected_failure("'NULL' or 'DEFAULT'")?,
+                }
+            }
+            Token::Ident(_, Keyword::NO) => ForeignKeyOnAction::NoAction(
+                parser.consume_keywords(&[Keyword::NO, Keyword::ACTION])?,
+            ),
+            _ => parser.expected_failure("'RESTRICT' or 'CASCADE', 'SET' or 'NO")?,
+        };
+        ons.push(ForeignKeyOn { type_, action })
+    }
+    Ok(ons)
+}
+
 fn parse_add_alter_specification<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<AlterSpecification<'a>, ParseError> {
@@ -361,15 +969,7 @@ fn parse_add_alter_specification<'a>(
     match &parser.token {
         Token::Ident(_, Keyword::FOREIGN) => {
             let foregin_key_span = parser.consume_keywords(&[Keyword::FOREIGN, Keyword::KEY])?;
-            let if_not_exists = if let Some(s) = parser.skip_keyword(Keyword::IF) {
-                Some(
-                    parser
-                        .consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?
-                        .join_span(&s),
-                )
-            } else {
-                None
-            };
+            let if_not_exists = parse_if_not_exists(parser)?;
             let name = match &parser.token {
                 Token::Ident(_, kw) if !kw.reserved() => Some(parser.consume_plain_identifier()?),
                 _ => None,
@@ -379,44 +979,7 @@ fn parse_add_alter_specification<'a>(
             let references_span = parser.consume_keyword(Keyword::REFERENCES)?;
             let references_table = parser.consume_plain_identifier()?;
             let references_cols = parse_cols(parser)?;
-            let mut ons = Vec::new();
-            while let Some(on) = parser.skip_keyword(Keyword::ON) {
-                let type_ = match parser.token {
-                    Token::Ident(_, Keyword::UPDATE) => ForeignKeyOnType::Update(
-                        parser.consume_keyword(Keyword::UPDATE)?.join_span(&on),
-                    ),
-                    Token::Ident(_, Keyword::DELETE) => ForeignKeyOnType::Delete(
-                        parser.consume_keyword(Keyword::DELETE)?.join_span(&on),
-                    ),
-                    _ => parser.expected_failure("'UPDATE' or 'DELETE'")?,
-                };
-
-                let action = match parser.token {
-                    Token::Ident(_, Keyword::RESTRICT) => {
-                        ForeignKeyOnAction::Restrict(parser.consume_keyword(Keyword::RESTRICT)?)
-                    }
-                    Token::Ident(_, Keyword::CASCADE) => {
-                        ForeignKeyOnAction::Cascade(parser.consume_keyword(Keyword::CASCADE)?)
-                    }
-                    Token::Ident(_, Keyword::SET) => {
-                        let set = parser.consume_keyword(Keyword::SET)?;
-                        match parser.token {
-                            Token::Ident(_, Keyword::NULL) => ForeignKeyOnAction::SetNull(
-                                parser.consume_keyword(Keyword::NULL)?.join_span(&set),
-                            ),
-                            Token::Ident(_, Keyword::DELETE) => ForeignKeyOnAction::SetDefault(
-                                parser.consume_keyword(Keyword::DEFAULT)?.join_span(&set),
-                            ),
-                            _ => parser.expected_failure("'NULL' or 'DEFAULT'")?,
-                        }
-                    }
-                    Token::Ident(_, Keyword::NO) => ForeignKeyOnAction::SetNull(
-                        parser.consume_keywords(&[Keyword::NO, Keyword::ACTION])?,
-                    ),
-                    _ => parser.expected_failure("'RESTRICT' or 'CASCADE', 'SET' or 'NO")?,
-                };
-                ons.push(ForeignKeyOn { type_, action })
-            }
+            let ons = parse_foreign_key_ons(parser)?;
             Ok(AlterSpecification::AddForeignKey {
                 add_span,
                 constraint,
@@ -439,55 +1002,9 @@ fn parse_add_alter_specification<'a>(
             | Keyword::UNIQUE
             | Keyword::SPATIAL,
         ) => {
-            let index_type = match &parser.token {
-                Token::Ident(_, Keyword::PRIMARY) => {
-                    IndexType::Primary(parser.consume_keywords(&[Keyword::PRIMARY, Keyword::KEY])?)
-                }
-                Token::Ident(_, Keyword::INDEX | Keyword::KEY) => {
-                    IndexType::Index(parser.consume())
-                }
-                Token::Ident(_, Keyword::FULLTEXT) => {
-                    let s = parser.consume_keyword(Keyword::FULLTEXT)?;
-                    match &parser.token {
-                        Token::Ident(_, kw @ Keyword::INDEX | kw @ Keyword::KEY) => {
-                            let kw = *kw;
-                            IndexType::FullText(parser.consume_keyword(kw)?.join_span(&s))
-                        }
-                        _ => parser.expected_failure("'KEY' or 'INDEX'")?,
-                    }
-                }
-                Token::Ident(_, Keyword::SPATIAL) => {
-                    let s = parser.consume_keyword(Keyword::SPATIAL)?;
-                    match &parser.token {
-                        Token::Ident(_, kw @ Keyword::INDEX | kw @ Keyword::KEY) => {
-                            let kw = *kw;
-                            IndexType::FullText(parser.consume_keyword(kw)?.join_span(&s))
-                        }
-                        _ => parser.expected_failure("'KEY' or 'INDEX'")?,
-                    }
-                }
-                Token::Ident(_, Keyword::UNIQUE) => {
-                    let s = parser.consume_keyword(Keyword::UNIQUE)?;
-                    match &parser.token {
-                        Token::Ident(_, kw @ Keyword::INDEX | kw @ Keyword::KEY) => {
-                            let kw = *kw;
-                            IndexType::FullText(parser.consume_keyword(kw)?.join_span(&s))
-                        }
-                        _ => parser.expected_failure("'KEY' or 'INDEX'")?,
-                    }
-                }
-                _ => parser.ice(file!(), line!())?,
-            };
+            let index_type = parse_index_definition_type(parser)?;
 
-            let if_not_exists = if let Some(s) = parser.skip_keyword(Keyword::IF) {
-                Some(
-                    parser
-                        .consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?
-                        .join_span(&s),
-                )
-            } else {
-                None
-            };
+            let if_not_exists = parse_if_not_exists(parser)?;
 
             let name = match &parser.token {
                 Token::Ident(_, kw) if !kw.reserved() => Some(parser.consume_plain_identifier()?),
@@ -513,11 +1030,7 @@ fn parse_add_alter_specification<'a>(
         }
         Token::Ident(_, Keyword::COLUMN) => {
             parser.consume_keyword(Keyword::COLUMN)?;
-            let mut if_not_exists_span = None;
-            if matches!(parser.token, Token::Ident(_, Keyword::IF)) {
-                if_not_exists_span =
-                    Some(parser.consume_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS])?);
-            }
+            let if_not_exists_span = parse_if_not_exists(parser)?;
 
             if let Some(s) = &if_not_exists_span {
                 if parser.options.dialect.is_maria() {
@@ -529,26 +1042,72 @@ fn parse_add_alter_specification<'a>(
 
             let identifier = parser.consume_plain_identifier()?;
             let data_type = parse_data_type(parser, false)?;
+            let position = parse_column_position(parser)?;
             Ok(AlterSpecification::AddColumn {
                 add_span,
                 if_not_exists_span,
                 identifier,
                 data_type,
+                position,
             })
         }
         _ => parser.expected_failure("addable"),
     }
 }
 
+fn parse_drop_alter_specification<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<AlterSpecification<'a>, ParseError> {
+    let drop_span = parser.consume_keyword(Keyword::DROP)?;
+    match &parser.token {
+        Token::Ident(_, Keyword::INDEX | Keyword::KEY) => {
+            let drop_index_span = parser.consume().join_span(&drop_span);
+            let if_exists = parse_if_exists(parser)?;
+            let name = parser.consume_plain_identifier()?;
+            Ok(AlterSpecification::DropIndex {
+                drop_index_span,
+                if_exists,
+                name,
+            })
+        }
+        Token::Ident(_, Keyword::PRIMARY) => Ok(AlterSpecification::DropPrimaryKey {
+            drop_primary_key_span: parser
+                .consume_keywords(&[Keyword::PRIMARY, Keyword::KEY])?
+                .join_span(&drop_span),
+        }),
+        Token::Ident(_, Keyword::FOREIGN) => {
+            let drop_foreign_key_span = parser
+                .consume_keywords(&[Keyword::FOREIGN, Keyword::KEY])?
+                .join_span(&drop_span);
+            let if_exists = parse_if_exists(parser)?;
+            let name = parser.consume_plain_identifier()?;
+            Ok(AlterSpecification::DropForeignKey {
+                drop_foreign_key_span,
+                if_exists,
+                name,
+            })
+        }
+        _ => parser.expected_failure("'INDEX', 'KEY', 'PRIMARY KEY' or 'FOREIGN KEY'"),
+    }
+}
+
 /// Represent an alter table statement
 /// ```
-/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, AlterTable, Statement};
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, AlterSpecification, AlterTable, Statement, TableOption};
 /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
 /// # let mut issues = Vec::new();
 /// #
 /// let sql = "ALTER TABLE `t1`
 ///     MODIFY `id` int(11) NOT NULL AUTO_INCREMENT,
-///     ADD CONSTRAINT `t1_t2` FOREIGN KEY (`two`) REFERENCES `t2` (`id`);";
+///     ADD CONSTRAINT `t1_t2` FOREIGN KEY (`two`) REFERENCES `t2` (`id`),
+///     ADD UNIQUE INDEX `u1` (`three`),
+///     DROP INDEX `i1`,
+///     DROP PRIMARY KEY,
+///     DROP FOREIGN KEY IF EXISTS `t1_t3`,
+///     RENAME TO `t4`,
+///     ENGINE=InnoDB,
+///     AUTO_INCREMENT=100,
+///     COMMENT='x';";
 ///
 /// let mut stmts = parse_statements(sql, &mut issues, &options);
 ///
@@ -560,9 +1119,22 @@ fn parse_add_alter_specification<'a>(
 /// };
 ///
 /// assert!(alter.table.identifier.as_str() == "t1");
+/// assert!(alter.alter_specifications.len() == 10);
+/// assert!(matches!(
+///     &alter.alter_specifications[5],
+///     AlterSpecification::DropForeignKey { if_exists: Some(_), name, .. } if name.as_str() == "t1_t3"
+/// ));
+/// assert!(matches!(
+///     &alter.alter_specifications[6],
+///     AlterSpecification::RenameTo { new_name, .. } if new_name.identifier.as_str() == "t4"
+/// ));
+/// assert!(matches!(
+///     &alter.alter_specifications[8],
+///     AlterSpecification::SetOption(TableOption::AutoIncrement { value: (100, _), .. })
+/// ));
 /// println!("{:#?}", alter.alter_specifications)
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct AlterTable<'a> {
     /// Span of "ALTER"
     pub alter_span: Span,
@@ -592,6 +1164,18 @@ impl<'a> Spanned for AlterTable<'a> {
     }
 }
 
+impl<'a> SpansMut for AlterTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.alter_span.spans_mut(f);
+        self.online.spans_mut(f);
+        self.ignore.spans_mut(f);
+        self.table_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.table.spans_mut(f);
+        self.alter_specifications.spans_mut(f);
+    }
+}
+
 fn parse_alter_table<'a>(
     parser: &mut Parser<'a, '_>,
     alter_span: Span,
@@ -599,36 +1183,30 @@ fn parse_alter_table<'a>(
     ignore: Option<Span>,
 ) -> Result<AlterTable<'a>, ParseError> {
     let table_span = parser.consume_keyword(Keyword::TABLE)?;
-    let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-        Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-    } else {
-        None
-    };
+    let if_exists = parse_if_exists(parser)?;
     let table = parse_qualified_name(parser)?;
     let d = parser.delimiter.clone();
     let mut alter_specifications = Vec::new();
-    parser.recovered(d.name(), &|t| (t == &d || t == &Token::Eof), |parser| {
+    parser.recovered(d.name(), &|t| t == &d || t == &Token::Eof, |parser| {
         loop {
             alter_specifications.push(match parser.token {
                 Token::Ident(_, Keyword::ADD) => parse_add_alter_specification(parser)?,
+                Token::Ident(_, Keyword::DROP) => parse_drop_alter_specification(parser)?,
                 Token::Ident(_, Keyword::MODIFY) => {
                     let mut modify_span = parser.consume_keyword(Keyword::MODIFY)?;
                     if let Some(v) = parser.skip_keyword(Keyword::COLUMN) {
                         modify_span = modify_span.join_span(&v);
                     }
-                    let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                        Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-                    } else {
-                        None
-                    };
+                    let if_exists = parse_if_exists(parser)?;
                     let col = parser.consume_plain_identifier()?;
                     let definition = parse_data_type(parser, false)?;
-                    // TODO [FIRST | AFTER col_name]
+                    let position = parse_column_position(parser)?;
                     AlterSpecification::Modify {
                         modify_span,
                         if_exists,
                         col,
                         definition,
+                        position,
                     }
                 }
                 Token::Ident(_, Keyword::OWNER) => {
@@ -636,7 +1214,87 @@ fn parse_alter_table<'a>(
                     let owner = parser.consume_plain_identifier()?;
                     AlterSpecification::OwnerTo { span, owner }
                 }
-                _ => parser.expected_failure("alter specification")?,
+                Token::Ident(_, Keyword::RENAME) => {
+                    let rename_span = parser.consume_keyword(Keyword::RENAME)?;
+                    if let Some(index_keyword) = skip_index_keyword(parser) {
+                        let old_name = parser.consume_plain_identifier()?;
+                        let to_span = parser.consume_keyword(Keyword::TO)?;
+                        let new_name = parser.consume_plain_identifier()?;
+                        AlterSpecification::RenameIndex {
+                            rename_span,
+                            index_keyword,
+                            old_name,
+                            to_span,
+                            new_name,
+                        }
+                    } else {
+                        let to_span = match &parser.token {
+                            Token::Ident(_, Keyword::TO) => {
+                                Some(parser.consume_keyword(Keyword::TO)?)
+                            }
+                            Token::Ident(_, Keyword::AS) => {
+                                Some(parser.consume_keyword(Keyword::AS)?)
+                            }
+                            _ => None,
+                        };
+                        let new_name = parse_qualified_name(parser)?;
+                        AlterSpecification::RenameTo {
+                            rename_span,
+                            to_span,
+                            new_name,
+                        }
+                    }
+                }
+                Token::Ident(_, Keyword::ALGORITHM) => {
+                    let mut algorithm_span = parser.consume_keyword(Keyword::ALGORITHM)?;
+                    if let Some(eq) = parser.skip_token(Token::Eq) {
+                        algorithm_span = algorithm_span.join_span(&eq);
+                    }
+                    let algorithm = match &parser.token {
+                        Token::Ident(_, Keyword::DEFAULT) => {
+                            AlterAlgorithm::Default(parser.consume())
+                        }
+                        Token::Ident(_, Keyword::INPLACE) => {
+                            AlterAlgorithm::Inplace(parser.consume())
+                        }
+                        Token::Ident(_, Keyword::COPY) => AlterAlgorithm::Copy(parser.consume()),
+                        Token::Ident(_, Keyword::NOCOPY) => {
+                            AlterAlgorithm::Nocopy(parser.consume())
+                        }
+                        Token::Ident(_, Keyword::INSTANT) => {
+                            AlterAlgorithm::Instant(parser.consume())
+                        }
+                        _ => parser.expected_failure(
+                            "'DEFAULT', 'INPLACE', 'COPY', 'NOCOPY' or 'INSTANT'",
+                        )?,
+                    };
+                    AlterSpecification::Algorithm {
+                        algorithm_span,
+                        algorithm,
+                    }
+                }
+                Token::Ident(_, Keyword::LOCK) => {
+                    let mut lock_span = parser.consume_keyword(Keyword::LOCK)?;
+                    if let Some(eq) = parser.skip_token(Token::Eq) {
+                        lock_span = lock_span.join_span(&eq);
+                    }
+                    let lock = match &parser.token {
+                        Token::Ident(_, Keyword::DEFAULT) => AlterLock::Default(parser.consume()),
+                        Token::Ident(_, Keyword::NONE) => AlterLock::None(parser.consume()),
+                        Token::Ident(_, Keyword::SHARED) => AlterLock::Shared(parser.consume()),
+                        Token::Ident(_, Keyword::EXCLUSIVE) => {
+                            AlterLock::Exclusive(parser.consume())
+                        }
+                        _ => {
+                            parser.expected_failure("'DEFAULT', 'NONE', 'SHARED' or 'EXCLUSIVE'")?
+                        }
+                    };
+                    AlterSpecification::Lock { lock_span, lock }
+                }
+                _ => match parse_table_option(parser)? {
+                    Some(option) => AlterSpecification::SetOption(option),
+                    None => parser.expected_failure("alter specification")?,
+                },
             });
             if parser.skip_token(Token::Comma).is_none() {
                 break;
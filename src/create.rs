@@ -12,19 +12,24 @@ use alloc::{boxed::Box, vec::Vec};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::{
-    data_type::parse_data_type,
+    alter::{
+        parse_index_cols, parse_index_definition_type, parse_index_options, parse_index_type,
+        IndexCol, IndexOption, IndexType,
+    },
+    data_type::{parse_data_type, DataTypeProperty},
     expression::parse_expression,
     keywords::Keyword,
     lexer::Token,
-    parser::{ParseError, Parser},
+    parser::{parse_if_not_exists, ParseError, Parser},
     qualified_name::parse_qualified_name,
     select::{parse_select, Select},
-    statement::parse_statement,
-    DataType, Expression, Identifier, Issue, QualifiedName, SString, Span, Spanned, Statement,
+    statement::{parse_compound_query, parse_statement},
+    DataType, Expression, Identifier, Issue, QualifiedName, SQLDialect, SString, Span, Spanned,
+    SpansMut, Statement,
 };
 
 /// Options on created table
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum TableOption<'a> {
     AutoExtendSize {
         identifier: Span,
@@ -32,7 +37,7 @@ pub enum TableOption<'a> {
     },
     AutoIncrement {
         identifier: Span,
-        value: Identifier<'a>,
+        value: (u64, Span),
     },
     AvgRowLength {
         identifier: Span,
@@ -123,6 +128,8 @@ pub enum TableOption<'a> {
         identifier: Span,
         value: SString<'a>,
     },
+    /// Keep the history of rows, "identifier" is the span of "WITH SYSTEM VERSIONING"
+    SystemVersioning { identifier: Span },
     //StatsAutoRecalc
     //StatsPersistance
     //StatsSamplePages
@@ -161,22 +168,172 @@ impl<'a> Spanned for TableOption<'a> {
             TableOption::SecondaryEngineAttribute { identifier, value } => {
                 identifier.span().join_span(value)
             }
+            TableOption::SystemVersioning { identifier } => identifier.span(),
+        }
+    }
+}
+
+impl<'a> SpansMut for TableOption<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TableOption::AutoExtendSize { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::AutoIncrement { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::AvgRowLength { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::CharSet { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::DefaultCharSet { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::Checksum { identifier, value } => {
+                identifier.spans_mut(f);
+                value.1.spans_mut(f);
+            }
+            TableOption::Collate { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::DefaultCollate { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::Comment { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::Compression { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::Connection { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::DataDirectory { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::IndexDirectory { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::DelayKeyWrite { identifier, value } => {
+                identifier.spans_mut(f);
+                value.1.spans_mut(f);
+            }
+            TableOption::Encryption { identifier, value } => {
+                identifier.spans_mut(f);
+                value.1.spans_mut(f);
+            }
+            TableOption::Engine { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::EngineAttribute { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::InsertMethod { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::KeyBlockSize { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::MaxRows { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::MinRows { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::Password { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::RowFormat { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::SecondaryEngineAttribute { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TableOption::SystemVersioning { identifier } => identifier.spans_mut(f),
         }
     }
 }
 
 /// Definition in create table
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum CreateDefinition<'a> {
     ColumnDefinition {
         /// Name of column
         identifier: Identifier<'a>,
         /// Datatype and options for column
         data_type: DataType<'a>,
+        /// Can the column contain NULL, this is false if "NOT NULL" is specified or the
+        /// column is part of the primary key, and true otherwise
+        nullable: bool,
     },
+    /// Foreign key constraint "CONSTRAINT symbol FOREIGN KEY (cols) REFERENCES table (cols)"
     ConstraintDefinition {
+        /// Span of "CONSTRAINT"
         span: Span,
+        /// Name of the constraint
         identifier: Identifier<'a>,
+        /// Columns in this table
+        cols: Vec<Identifier<'a>>,
+        /// Referred table
+        references_table: Identifier<'a>,
+        /// Columns in referred table
+        references_cols: Vec<Identifier<'a>>,
+    },
+    /// Index definition such as "PRIMARY KEY", "UNIQUE KEY" or "FULLTEXT KEY"
+    IndexDefinition {
+        /// Optional "CONSTRAINT" with symbol if specified
+        constraint: Option<(Span, Option<Identifier<'a>>)>,
+        /// The type of index
+        index_type: IndexType,
+        /// Named of index if specified
+        name: Option<Identifier<'a>>,
+        /// Columns the index is over
+        cols: Vec<IndexCol<'a>>,
+        /// Options on the index
+        index_options: Vec<IndexOption<'a>>,
+    },
+    /// Table level check constraint "[CONSTRAINT [symbol]] CHECK (expr)"
+    CheckConstraint {
+        /// Optional "CONSTRAINT" with symbol if specified
+        constraint: Option<(Span, Option<Identifier<'a>>)>,
+        /// Span of "CHECK"
+        check_span: Span,
+        /// Expression that must hold for every row
+        expr: Expression<'a>,
+    },
+    /// Period definition "PERIOD FOR name (start, end)"
+    Period {
+        /// Span of "PERIOD FOR"
+        period_for_span: Span,
+        /// Name of the period, "SYSTEM_TIME" for system versioned tables
+        name: Identifier<'a>,
+        /// Column holding the start of the period
+        start: Identifier<'a>,
+        /// Column holding the end of the period
+        end: Identifier<'a>,
     },
 }
 
@@ -186,16 +343,111 @@ impl<'a> Spanned for CreateDefinition<'a> {
             CreateDefinition::ColumnDefinition {
                 identifier,
                 data_type,
+                nullable: _,
             } => identifier.span().join_span(data_type),
-            CreateDefinition::ConstraintDefinition { span, identifier } => {
-                span.join_span(identifier)
+            CreateDefinition::ConstraintDefinition {
+                span,
+                identifier,
+                cols,
+                references_table,
+                references_cols,
+            } => span
+                .join_span(identifier)
+                .join_span(cols)
+                .join_span(references_table)
+                .join_span(references_cols),
+            CreateDefinition::IndexDefinition {
+                constraint,
+                index_type,
+                name,
+                cols,
+                index_options,
+            } => index_type
+                .join_span(constraint)
+                .join_span(name)
+                .join_span(cols)
+                .join_span(index_options),
+            CreateDefinition::CheckConstraint {
+                constraint,
+                check_span,
+                expr,
+            } => check_span.join_span(constraint).join_span(expr),
+            CreateDefinition::Period {
+                period_for_span,
+                name,
+                start,
+                end,
+            } => period_for_span
+                .join_span(name)
+                .join_span(start)
+                .join_span(end),
+        }
+    }
+}
+
+impl<'a> SpansMut for CreateDefinition<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            CreateDefinition::ColumnDefinition {
+                identifier,
+                data_type,
+                nullable: _,
+            } => {
+                identifier.spans_mut(f);
+                data_type.spans_mut(f);
+            }
+            CreateDefinition::ConstraintDefinition {
+                span,
+                identifier,
+                cols,
+                references_table,
+                references_cols,
+            } => {
+                span.spans_mut(f);
+                identifier.spans_mut(f);
+                cols.spans_mut(f);
+                references_table.spans_mut(f);
+                references_cols.spans_mut(f);
+            }
+            CreateDefinition::IndexDefinition {
+                constraint,
+                index_type,
+                name,
+                cols,
+                index_options,
+            } => {
+                constraint.spans_mut(f);
+                index_type.spans_mut(f);
+                name.spans_mut(f);
+                cols.spans_mut(f);
+                index_options.spans_mut(f);
+            }
+            CreateDefinition::CheckConstraint {
+                constraint,
+                check_span,
+                expr,
+            } => {
+                constraint.spans_mut(f);
+                check_span.spans_mut(f);
+                expr.spans_mut(f);
+            }
+            CreateDefinition::Period {
+                period_for_span,
+                name,
+                start,
+                end,
+            } => {
+                period_for_span.spans_mut(f);
+                name.spans_mut(f);
+                start.spans_mut(f);
+                end.spans_mut(f);
             }
         }
     }
 }
 
 /// Special algorithm used for table creation
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum CreateAlgorithm {
     Undefined(Span),
     Merge(Span),
@@ -211,8 +463,18 @@ impl Spanned for CreateAlgorithm {
     }
 }
 
+impl SpansMut for CreateAlgorithm {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            CreateAlgorithm::Undefined(v) => v.spans_mut(f),
+            CreateAlgorithm::Merge(v) => v.spans_mut(f),
+            CreateAlgorithm::TempTable(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Options for create statement
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum CreateOption<'a> {
     OrReplace(Span),
     Temporary(Span),
@@ -244,6 +506,64 @@ impl<'a> Spanned for CreateOption<'a> {
     }
 }
 
+impl<'a> SpansMut for CreateOption<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            CreateOption::OrReplace(v) => v.spans_mut(f),
+            CreateOption::Temporary(v) => v.spans_mut(f),
+            CreateOption::Unique(v) => v.spans_mut(f),
+            CreateOption::Algorithm(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            CreateOption::Definer {
+                definer_span,
+                user,
+                host,
+            } => {
+                definer_span.spans_mut(f);
+                user.spans_mut(f);
+                host.spans_mut(f);
+            }
+            CreateOption::SqlSecurityDefiner(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            CreateOption::SqlSecurityUser(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+        }
+    }
+}
+
+/// How to handle rows with duplicate unique keys in CREATE TABLE ... SELECT
+#[derive(Clone, Debug, PartialEq)]
+pub enum CreateTableOnConflict {
+    /// Span of "IGNORE", rows with duplicate keys are skipped
+    Ignore(Span),
+    /// Span of "REPLACE", rows with duplicate keys replace existing rows
+    Replace(Span),
+}
+
+impl Spanned for CreateTableOnConflict {
+    fn span(&self) -> Span {
+        match &self {
+            CreateTableOnConflict::Ignore(v) => v.span(),
+            CreateTableOnConflict::Replace(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for CreateTableOnConflict {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            CreateTableOnConflict::Ignore(v) => v.spans_mut(f),
+            CreateTableOnConflict::Replace(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Represent a create table statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, CreateTable, Statement};
@@ -270,8 +590,81 @@ impl<'a> Spanned for CreateOption<'a> {
 /// assert!(create.identifier.identifier.as_str() == "parts");
 /// println!("{:#?}", create.create_definitions)
 /// ```
+///
+/// Table options are kept in [CreateTable::options]
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement, TableOption};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// let sql = "CREATE TABLE `t1` (`id` int NOT NULL) COMPRESSION='zlib' ENCRYPTION='Y'";
+/// let create = match parse_statement(sql, &mut issues, &options) {
+///     Some(Statement::CreateTable(c)) => c,
+///     _ => panic!("We should get an create table statement")
+/// };
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// assert!(matches!(&create.options[0], TableOption::Compression { value, .. } if value.as_str() == "zlib"));
+/// assert!(matches!(&create.options[1], TableOption::Encryption { value: (true, _), .. }));
+///
+/// let sql = "CREATE TABLE `t1` (`id` int NOT NULL) ENGINE=FEDERATED
+///     CONNECTION='mysql://u@remote/db/t1' DATA DIRECTORY='/data' INDEX DIRECTORY='/idx'";
+/// let create = match parse_statement(sql, &mut issues, &options) {
+///     Some(Statement::CreateTable(c)) => c,
+///     _ => panic!("We should get an create table statement")
+/// };
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// assert!(matches!(&create.options[1], TableOption::Connection { .. }));
+/// assert!(matches!(&create.options[2], TableOption::DataDirectory { value, .. } if value.as_str() == "/data"));
+/// assert!(matches!(&create.options[3], TableOption::IndexDirectory { value, .. } if value.as_str() == "/idx"));
+///
+/// let sql = "CREATE TABLE `t1` (`id` int NOT NULL)
+///     ENGINE_ATTRIBUTE='{\"key\":\"value\"}' SECONDARY_ENGINE_ATTRIBUTE='{}'";
+/// let create = match parse_statement(sql, &mut issues, &options) {
+///     Some(Statement::CreateTable(c)) => c,
+///     _ => panic!("We should get an create table statement")
+/// };
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// assert!(matches!(&create.options[0], TableOption::EngineAttribute { value, .. } if value.as_str() == "{\"key\":\"value\"}"));
+/// assert!(matches!(&create.options[1], TableOption::SecondaryEngineAttribute { .. }));
+///
+/// let sql = "CREATE TABLE `t1` (`id` int NOT NULL) COMPRESSION='gzip'";
+/// parse_statement(sql, &mut issues, &options);
+/// assert!(issues.len() == 1);
+/// ```
+///
+/// The table may be populated by a select, optionally deciding how to handle duplicate keys
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement, CreateTableOnConflict};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// let sql = "CREATE TABLE `t2` (UNIQUE KEY (`id`)) ENGINE=InnoDB
+///     IGNORE SELECT `id`, `name` FROM `t1`";
+/// let create = match parse_statement(sql, &mut issues, &options) {
+///     Some(Statement::CreateTable(c)) => c,
+///     _ => panic!("We should get an create table statement")
+/// };
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// assert!(matches!(create.on_conflict, Some(CreateTableOnConflict::Ignore(_))));
+/// assert!(create.select.is_some());
+///
+/// let sql = "CREATE TABLE `t3` AS SELECT `id` FROM `t1`";
+/// let create = match parse_statement(sql, &mut issues, &options) {
+///     Some(Statement::CreateTable(c)) => c,
+///     _ => panic!("We should get an create table statement")
+/// };
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// assert!(create.create_definitions.is_empty());
+/// assert!(create.as_span.is_some() && create.select.is_some());
+///
+/// let sql = "CREATE TABLE `t4` SELECT `id` FROM `t1` UNION SELECT `id` FROM `t2`";
+/// let create = match parse_statement(sql, &mut issues, &options) {
+///     Some(Statement::CreateTable(c)) => c,
+///     _ => panic!("We should get an create table statement")
+/// };
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// assert!(matches!(create.select.as_deref(), Some(Statement::Union(_))));
+/// ```
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CreateTable<'a> {
     /// Span of "CREATE"
     pub create_span: Span,
@@ -287,6 +680,12 @@ pub struct CreateTable<'a> {
     pub create_definitions: Vec<CreateDefinition<'a>>,
     /// Options specified after the table creation
     pub options: Vec<TableOption<'a>>,
+    /// Handling of duplicate keys if "IGNORE" or "REPLACE" is specified before the select
+    pub on_conflict: Option<CreateTableOnConflict>,
+    /// Span of "AS" if specified before the select
+    pub as_span: Option<Span>,
+    /// Select statement, possibly a union, used to populate the table
+    pub select: Option<Box<Statement<'a>>>,
 }
 
 impl<'a> Spanned for CreateTable<'a> {
@@ -298,6 +697,369 @@ impl<'a> Spanned for CreateTable<'a> {
             .join_span(&self.if_not_exists)
             .join_span(&self.create_definitions)
             .join_span(&self.options)
+            .join_span(&self.on_conflict)
+            .join_span(&self.as_span)
+            .join_span(&self.select)
+    }
+}
+
+impl<'a> SpansMut for CreateTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.table_span.spans_mut(f);
+        self.identifier.spans_mut(f);
+        self.if_not_exists.spans_mut(f);
+        self.create_definitions.spans_mut(f);
+        self.options.spans_mut(f);
+        self.on_conflict.spans_mut(f);
+        self.as_span.spans_mut(f);
+        self.select.spans_mut(f);
+    }
+}
+
+impl<'a> CreateTable<'a> {
+    /// Check the table for semantic errors not caught while parsing
+    ///
+    /// Reports duplicate column names, multiple primary keys, "AUTO_INCREMENT"
+    /// columns that are not the first column of a key and foreign keys or periods
+    /// over columns not in the table. Default values are checked by [CreateTable::validate_defaults]
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let sql = "CREATE TABLE `t1` (
+    ///     `id` int NOT NULL AUTO_INCREMENT,
+    ///     `id` int,
+    ///     `parent` int,
+    ///     CONSTRAINT `fk` FOREIGN KEY (`parnet`) REFERENCES `t1` (`id`)
+    /// )";
+    /// let create = match parse_statement(sql, &mut issues, &options) {
+    ///     Some(Statement::CreateTable(c)) => c,
+    ///     _ => panic!("We should get an create table statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let messages: Vec<_> = create.validate().iter().map(|i| i.message.to_string()).collect();
+    /// assert_eq!(messages, [
+    ///     "Duplicate column name",
+    ///     "AUTO_INCREMENT column must be the first column of a key",
+    ///     "Unknown column in foreign key",
+    /// ]);
+    /// ```
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let mut columns: Vec<&Identifier<'a>> = Vec::new();
+        let mut key_columns: Vec<&Identifier<'a>> = Vec::new();
+        let mut primary_key: Option<Span> = None;
+        let mut check_primary_key = |span: &Span, issues: &mut Vec<Issue>| match &primary_key {
+            Some(previous) => issues.push(
+                Issue::err("Multiple primary keys defined", span)
+                    .frag("Previous primary key defined here", previous),
+            ),
+            None => primary_key = Some(span.clone()),
+        };
+        for definition in &self.create_definitions {
+            match definition {
+                CreateDefinition::ColumnDefinition {
+                    identifier,
+                    data_type,
+                    ..
+                } => {
+                    match columns
+                        .iter()
+                        .find(|c| c.as_str().eq_ignore_ascii_case(identifier.as_str()))
+                    {
+                        Some(previous) => issues.push(
+                            Issue::err("Duplicate column name", identifier)
+                                .frag("Previously defined here", *previous),
+                        ),
+                        None => columns.push(identifier),
+                    }
+                    for property in &data_type.properties {
+                        match property {
+                            DataTypeProperty::PrimaryKey(span) => {
+                                check_primary_key(span, &mut issues);
+                                key_columns.push(identifier);
+                            }
+                            DataTypeProperty::Unique(_) | DataTypeProperty::UniqueKey(_) => {
+                                key_columns.push(identifier)
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                CreateDefinition::IndexDefinition {
+                    index_type, cols, ..
+                } => {
+                    if let IndexType::Primary(span) = index_type {
+                        check_primary_key(span, &mut issues);
+                    }
+                    if let Some(col) = cols.first() {
+                        key_columns.push(&col.name);
+                    }
+                }
+                CreateDefinition::ConstraintDefinition { .. }
+                | CreateDefinition::CheckConstraint { .. }
+                | CreateDefinition::Period { .. } => (),
+            }
+        }
+
+        let has_column = |columns: &[&Identifier<'a>], name: &Identifier<'a>| {
+            columns
+                .iter()
+                .any(|c| c.as_str().eq_ignore_ascii_case(name.as_str()))
+        };
+        for definition in &self.create_definitions {
+            match definition {
+                CreateDefinition::ColumnDefinition {
+                    identifier,
+                    data_type,
+                    ..
+                } => {
+                    for property in &data_type.properties {
+                        if let DataTypeProperty::AutoIncrement(span) = property {
+                            if !has_column(&key_columns, identifier) {
+                                issues.push(Issue::err(
+                                    "AUTO_INCREMENT column must be the first column of a key",
+                                    span,
+                                ));
+                            }
+                        }
+                    }
+                }
+                CreateDefinition::ConstraintDefinition {
+                    cols,
+                    references_table,
+                    references_cols,
+                    ..
+                } => {
+                    for col in cols {
+                        if !has_column(&columns, col) {
+                            issues.push(Issue::err("Unknown column in foreign key", col));
+                        }
+                    }
+                    if references_table
+                        .as_str()
+                        .eq_ignore_ascii_case(self.identifier.identifier.as_str())
+                    {
+                        for col in references_cols {
+                            if !has_column(&columns, col) {
+                                issues.push(Issue::err("Unknown referenced column", col));
+                            }
+                        }
+                    }
+                }
+                CreateDefinition::Period { start, end, .. } => {
+                    for col in [start, end] {
+                        if !has_column(&columns, col) {
+                            issues.push(Issue::err("Unknown column in period", col));
+                        }
+                    }
+                }
+                CreateDefinition::IndexDefinition { .. }
+                | CreateDefinition::CheckConstraint { .. } => (),
+            }
+        }
+        issues
+    }
+
+    /// Check the "DEFAULT" values of the columns against their types, returning warnings
+    ///
+    /// Reports string defaults that are not numbers on numeric columns, subqueries
+    /// in defaults, and for PostgreSQL defaults referring to other columns
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::PostgreSQL);
+    /// # let mut issues = Vec::new();
+    /// let sql = "CREATE TABLE t1 (
+    ///     a int DEFAULT 'x',
+    ///     b int DEFAULT '42',
+    ///     c int DEFAULT (a + 1)
+    /// )";
+    /// let create = match parse_statement(sql, &mut issues, &options) {
+    ///     Some(Statement::CreateTable(c)) => c,
+    ///     _ => panic!("We should get an create table statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let messages: Vec<_> = create
+    ///     .validate_defaults(&SQLDialect::PostgreSQL)
+    ///     .iter()
+    ///     .map(|i| i.message.to_string())
+    ///     .collect();
+    /// assert_eq!(messages, [
+    ///     "String default on numeric column",
+    ///     "Column references are not allowed in DEFAULT",
+    /// ]);
+    /// assert_eq!(create.validate_defaults(&SQLDialect::MariaDB).len(), 1);
+    /// ```
+    pub fn validate_defaults(&self, dialect: &SQLDialect) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        for definition in &self.create_definitions {
+            let data_type = match definition {
+                CreateDefinition::ColumnDefinition { data_type, .. } => data_type,
+                _ => continue,
+            };
+            for property in &data_type.properties {
+                let default = match property {
+                    DataTypeProperty::Default(default) => default,
+                    _ => continue,
+                };
+                if let Expression::String(value) = default.as_ref() {
+                    if data_type.type_.is_numeric() && value.trim().parse::<f64>().is_err() {
+                        issues.push(
+                            Issue::warn("String default on numeric column", value)
+                                .frag("Column type", &data_type.identifier),
+                        );
+                    }
+                }
+                if let Some((message, span)) = non_constant_default(default, dialect) {
+                    issues.push(Issue::warn(message, &span));
+                }
+            }
+        }
+        issues
+    }
+}
+
+/// Find a part of a default value expression not allowed by the dialect
+fn non_constant_default(
+    expression: &Expression<'_>,
+    dialect: &SQLDialect,
+) -> Option<(&'static str, Span)> {
+    let all = |expressions: &[Expression<'_>]| {
+        expressions
+            .iter()
+            .find_map(|e| non_constant_default(e, dialect))
+    };
+    match expression {
+        Expression::Subquery(_) | Expression::Exists(_) => {
+            Some(("Subqueries are not allowed in DEFAULT", expression.span()))
+        }
+        Expression::Identifier(_) if dialect.is_postgresql() => Some((
+            "Column references are not allowed in DEFAULT",
+            expression.span(),
+        )),
+        Expression::Binary { lhs, rhs, .. } => {
+            non_constant_default(lhs, dialect).or_else(|| non_constant_default(rhs, dialect))
+        }
+        Expression::In { lhs, rhs, .. } => non_constant_default(lhs, dialect).or_else(|| all(rhs)),
+        Expression::JsonValue { doc, path, .. } => {
+            non_constant_default(doc, dialect).or_else(|| non_constant_default(path, dialect))
+        }
+        Expression::Function(_, args, _)
+        | Expression::AggregateFunction { args, .. }
+        | Expression::Row { values: args, .. } => all(args),
+        Expression::Unary { operand: e, .. }
+        | Expression::Is(e, _, _)
+        | Expression::Cast { expr: e, .. }
+        | Expression::Collate { expr: e, .. }
+        | Expression::Convert { expr: e, .. }
+        | Expression::Interval { expr: e, .. }
+        | Expression::JsonExtract { target: e, .. } => non_constant_default(e, dialect),
+        _ => None,
+    }
+}
+
+/// Build a [CreateTable] programmatically
+///
+/// All spans in the built statement are empty
+/// ```
+/// # use sql_parse::{CreateTableBuilder, CreateDefinition, DataType, DataTypeProperty, Identifier, TableOption, Type};
+/// let create = CreateTableBuilder::new("users")
+///     .if_not_exists()
+///     .column("id", DataType {
+///         identifier: 0..0,
+///         type_: Type::Int(None),
+///         properties: vec![DataTypeProperty::PrimaryKey(0..0)],
+///     })
+///     .column("name", DataType {
+///         identifier: 0..0,
+///         type_: Type::VarChar(Some((64, 0..0))),
+///         properties: Vec::new(),
+///     })
+///     .option(TableOption::Engine {
+///         identifier: 0..0,
+///         value: Identifier::new("InnoDB", 0..0),
+///     })
+///     .build();
+///
+/// assert!(create.identifier.identifier.as_str() == "users");
+/// assert!(create.if_not_exists.is_some());
+/// assert!(create.create_definitions.len() == 2);
+/// assert!(matches!(
+///     &create.create_definitions[0],
+///     CreateDefinition::ColumnDefinition { nullable: false, .. }
+/// ));
+/// assert!(create.options.len() == 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateTableBuilder<'a> {
+    identifier: QualifiedName<'a>,
+    if_not_exists: bool,
+    create_definitions: Vec<CreateDefinition<'a>>,
+    options: Vec<TableOption<'a>>,
+}
+
+impl<'a> CreateTableBuilder<'a> {
+    /// Start building a table with the given name
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            identifier: QualifiedName {
+                prefix: Vec::new(),
+                identifier: Identifier::new(name, 0..0),
+            },
+            if_not_exists: false,
+            create_definitions: Vec::new(),
+            options: Vec::new(),
+        }
+    }
+
+    /// Only create the table if it does not already exist
+    pub fn if_not_exists(self) -> Self {
+        Self {
+            if_not_exists: true,
+            ..self
+        }
+    }
+
+    /// Add a column with the given name and type
+    pub fn column(mut self, name: &'a str, data_type: DataType<'a>) -> Self {
+        let nullable = column_nullable(&data_type);
+        self.create_definitions
+            .push(CreateDefinition::ColumnDefinition {
+                identifier: Identifier::new(name, 0..0),
+                data_type,
+                nullable,
+            });
+        self
+    }
+
+    /// Add a definition other than a column, such as an index
+    pub fn definition(mut self, definition: CreateDefinition<'a>) -> Self {
+        self.create_definitions.push(definition);
+        self
+    }
+
+    /// Add a table option
+    pub fn option(mut self, option: TableOption<'a>) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Construct the create table statement
+    pub fn build(self) -> CreateTable<'a> {
+        CreateTable {
+            create_span: 0..0,
+            create_options: Vec::new(),
+            table_span: 0..0,
+            identifier: self.identifier,
+            if_not_exists: self.if_not_exists.then_some(0..0),
+            create_definitions: self.create_definitions,
+            options: self.options,
+            on_conflict: None,
+            as_span: None,
+            select: None,
+        }
     }
 }
 
@@ -327,7 +1089,7 @@ impl<'a> Spanned for CreateTable<'a> {
 /// println!("{:#?}", create.select)
 /// ```
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CreateView<'a> {
     /// Span of "CREATE"
     pub create_span: Span,
@@ -357,24 +1119,42 @@ impl<'a> Spanned for CreateView<'a> {
     }
 }
 
+impl<'a> SpansMut for CreateView<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.view_span.spans_mut(f);
+        self.if_not_exists.spans_mut(f);
+        self.name.spans_mut(f);
+        self.as_span.spans_mut(f);
+        self.select.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_create_constraint_definition<'a>(
     parser: &mut Parser<'a, '_>,
+    span: Span,
+    identifier: Identifier<'a>,
 ) -> Result<CreateDefinition<'a>, ParseError> {
-    let span = parser.consume_keyword(Keyword::CONSTRAINT)?;
-    let identifier = parser.consume_plain_identifier()?;
     parser.consume_keywords(&[Keyword::FOREIGN, Keyword::KEY])?;
     parser.consume_token(Token::LParen)?;
-    parser.consume_plain_identifier()?;
-    while parser.skip_token(Token::Comma).is_some() {
-        parser.consume_plain_identifier()?;
+    let mut cols = Vec::new();
+    loop {
+        cols.push(parser.consume_plain_identifier()?);
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
     }
     parser.consume_token(Token::RParen)?;
     parser.consume_keyword(Keyword::REFERENCES)?;
-    parser.consume_plain_identifier()?;
+    let references_table = parser.consume_plain_identifier()?;
     parser.consume_token(Token::LParen)?;
-    parser.consume_plain_identifier()?;
-    while parser.skip_token(Token::Comma).is_some() {
-        parser.consume_plain_identifier()?;
+    let mut references_cols = Vec::new();
+    loop {
+        references_cols.push(parser.consume_plain_identifier()?);
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
     }
 
     parser.consume_token(Token::RParen)?;
@@ -396,22 +1176,143 @@ pub(crate) fn parse_create_constraint_definition<'a>(
             _ => parser.expected_failure("CASCADE, DELETE OR SET NULL")?,
         }
     }
-    Ok(CreateDefinition::ConstraintDefinition { span, identifier })
+    Ok(CreateDefinition::ConstraintDefinition {
+        span,
+        identifier,
+        cols,
+        references_table,
+        references_cols,
+    })
+}
+
+fn parse_create_index_definition<'a>(
+    parser: &mut Parser<'a, '_>,
+    constraint: Option<(Span, Option<Identifier<'a>>)>,
+) -> Result<CreateDefinition<'a>, ParseError> {
+    let index_type = parse_index_definition_type(parser)?;
+    let name = match &parser.token {
+        Token::Ident(_, kw) if !kw.reserved() => Some(parser.consume_plain_identifier()?),
+        _ => None,
+    };
+    let mut index_options = Vec::new();
+    if matches!(parser.token, Token::Ident(_, Keyword::USING)) {
+        parse_index_type(parser, &mut index_options)?;
+    }
+    let cols = parse_index_cols(parser)?;
+    parse_index_options(parser, &mut index_options)?;
+    Ok(CreateDefinition::IndexDefinition {
+        constraint,
+        index_type,
+        name,
+        cols,
+        index_options,
+    })
+}
+
+fn parse_create_period_definition<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<CreateDefinition<'a>, ParseError> {
+    let period_for_span = parser.consume_keywords(&[Keyword::PERIOD, Keyword::FOR])?;
+    let name = match &parser.token {
+        Token::Ident(v, Keyword::SYSTEM_TIME) => {
+            let v = *v;
+            Identifier::new(v, parser.consume())
+        }
+        _ => parser.consume_plain_identifier()?,
+    };
+    parser.consume_token(Token::LParen)?;
+    let start = parser.consume_plain_identifier()?;
+    parser.consume_token(Token::Comma)?;
+    let end = parser.consume_plain_identifier()?;
+    parser.consume_token(Token::RParen)?;
+    Ok(CreateDefinition::Period {
+        period_for_span,
+        name,
+        start,
+        end,
+    })
+}
+
+fn parse_create_check_definition<'a>(
+    parser: &mut Parser<'a, '_>,
+    constraint: Option<(Span, Option<Identifier<'a>>)>,
+) -> Result<CreateDefinition<'a>, ParseError> {
+    let check_span = parser.consume_keyword(Keyword::CHECK)?;
+    let s1 = parser.consume_token(Token::LParen)?;
+    let expr = parser.recovered(")", &|t| t == &Token::RParen, |parser| {
+        Ok(Some(parse_expression(parser, false)?))
+    })?;
+    let s2 = parser.consume_token(Token::RParen)?;
+    let expr = expr.unwrap_or_else(|| Expression::Invalid(s1.join_span(&s2)));
+    Ok(CreateDefinition::CheckConstraint {
+        constraint,
+        check_span,
+        expr,
+    })
 }
 
 pub(crate) fn parse_create_definition<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<CreateDefinition<'a>, ParseError> {
     match &parser.token {
-        Token::Ident(_, Keyword::CONSTRAINT) => parse_create_constraint_definition(parser),
-        Token::Ident(_, _) => Ok(CreateDefinition::ColumnDefinition {
-            identifier: parser.consume_plain_identifier()?,
-            data_type: parse_data_type(parser, false)?,
-        }),
+        Token::Ident(_, Keyword::CONSTRAINT) => {
+            let span = parser.consume_keyword(Keyword::CONSTRAINT)?;
+            let identifier = match &parser.token {
+                Token::Ident(_, kw) if !kw.reserved() => Some(parser.consume_plain_identifier()?),
+                _ => None,
+            };
+            match &parser.token {
+                Token::Ident(_, Keyword::FOREIGN) => match identifier {
+                    Some(identifier) => {
+                        parse_create_constraint_definition(parser, span, identifier)
+                    }
+                    None => parser.expected_failure("identifier"),
+                },
+                Token::Ident(_, Keyword::CHECK) => {
+                    parse_create_check_definition(parser, Some((span, identifier)))
+                }
+                _ => parse_create_index_definition(parser, Some((span, identifier))),
+            }
+        }
+        Token::Ident(
+            _,
+            Keyword::PRIMARY
+            | Keyword::INDEX
+            | Keyword::KEY
+            | Keyword::FULLTEXT
+            | Keyword::UNIQUE
+            | Keyword::SPATIAL,
+        ) => parse_create_index_definition(parser, None),
+        Token::Ident(_, Keyword::CHECK) => parse_create_check_definition(parser, None),
+        Token::Ident(_, Keyword::PERIOD)
+            if matches!(parser.peek_n(1), Token::Ident(_, Keyword::FOR)) =>
+        {
+            parse_create_period_definition(parser)
+        }
+        Token::Ident(_, _) => {
+            let identifier = parser.consume_plain_identifier()?;
+            let data_type = parse_data_type(parser, false)?;
+            let nullable = column_nullable(&data_type);
+            Ok(CreateDefinition::ColumnDefinition {
+                identifier,
+                data_type,
+                nullable,
+            })
+        }
         _ => parser.expected_failure("identifier"),
     }
 }
 
+/// Can a column of the given type contain NULL, ignoring table level primary keys
+fn column_nullable(data_type: &DataType<'_>) -> bool {
+    !data_type.properties.iter().any(|p| {
+        matches!(
+            p,
+            DataTypeProperty::NotNull(_) | DataTypeProperty::PrimaryKey(_)
+        )
+    })
+}
+
 fn parse_create_view<'a>(
     parser: &mut Parser<'a, '_>,
     create_span: Span,
@@ -419,15 +1320,7 @@ fn parse_create_view<'a>(
 ) -> Result<Statement<'a>, ParseError> {
     let view_span = parser.consume_keyword(Keyword::VIEW)?;
 
-    let if_not_exists = if let Some(if_) = parser.skip_keyword(Keyword::IF) {
-        Some(
-            parser
-                .consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?
-                .join_span(&if_),
-        )
-    } else {
-        None
-    };
+    let if_not_exists = parse_if_not_exists(parser)?;
 
     let name = parse_qualified_name(parser)?;
     // TODO (column_list)
@@ -450,7 +1343,7 @@ fn parse_create_view<'a>(
 }
 
 /// Characteristic of a function
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum FunctionCharacteristic<'a> {
     LanguageSql(Span),
     LanguagePlpgsql(Span),
@@ -465,7 +1358,22 @@ pub enum FunctionCharacteristic<'a> {
     Comment(SString<'a>),
 }
 
-impl<'a> Spanned for FunctionCharacteristic<'a> {
+/// Characteristics of the same kind may only be specified once
+fn characteristic_kind(characteristic: &FunctionCharacteristic<'_>) -> usize {
+    match characteristic {
+        FunctionCharacteristic::LanguageSql(_) | FunctionCharacteristic::LanguagePlpgsql(_) => 0,
+        FunctionCharacteristic::NotDeterministic(_) | FunctionCharacteristic::Deterministic(_) => 1,
+        FunctionCharacteristic::ContainsSql(_)
+        | FunctionCharacteristic::NoSql(_)
+        | FunctionCharacteristic::ReadsSqlData(_)
+        | FunctionCharacteristic::ModifiesSqlData(_) => 2,
+        FunctionCharacteristic::SqlSecurityDefiner(_)
+        | FunctionCharacteristic::SqlSecurityUser(_) => 3,
+        FunctionCharacteristic::Comment(_) => 4,
+    }
+}
+
+impl<'a> Spanned for FunctionCharacteristic<'a> {
     fn span(&self) -> Span {
         match &self {
             FunctionCharacteristic::LanguageSql(v) => v.span(),
@@ -483,8 +1391,26 @@ impl<'a> Spanned for FunctionCharacteristic<'a> {
     }
 }
 
+impl<'a> SpansMut for FunctionCharacteristic<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            FunctionCharacteristic::LanguageSql(v) => v.spans_mut(f),
+            FunctionCharacteristic::LanguagePlpgsql(v) => v.spans_mut(f),
+            FunctionCharacteristic::NotDeterministic(v) => v.spans_mut(f),
+            FunctionCharacteristic::Deterministic(v) => v.spans_mut(f),
+            FunctionCharacteristic::ContainsSql(v) => v.spans_mut(f),
+            FunctionCharacteristic::NoSql(v) => v.spans_mut(f),
+            FunctionCharacteristic::ReadsSqlData(v) => v.spans_mut(f),
+            FunctionCharacteristic::ModifiesSqlData(v) => v.spans_mut(f),
+            FunctionCharacteristic::SqlSecurityDefiner(v) => v.spans_mut(f),
+            FunctionCharacteristic::SqlSecurityUser(v) => v.spans_mut(f),
+            FunctionCharacteristic::Comment(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Direction of a function argument
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum FunctionParamDirection {
     In(Span),
     Out(Span),
@@ -501,6 +1427,16 @@ impl Spanned for FunctionParamDirection {
     }
 }
 
+impl SpansMut for FunctionParamDirection {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            FunctionParamDirection::In(v) => v.spans_mut(f),
+            FunctionParamDirection::Out(v) => v.spans_mut(f),
+            FunctionParamDirection::InOut(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Representation of Create Function Statement
 ///
 /// This is not fully implemented yet
@@ -530,7 +1466,7 @@ impl Spanned for FunctionParamDirection {
 /// assert!(create.name.as_str() == "add_func3");
 /// println!("{:#?}", create.return_)
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CreateFunction<'a> {
     /// Span of "CREATE"
     pub create_span: Span,
@@ -546,7 +1482,7 @@ pub struct CreateFunction<'a> {
     pub params: Vec<(Option<FunctionParamDirection>, Identifier<'a>, DataType<'a>)>,
     /// Span of "RETURNS"
     pub returns_span: Span,
-    /// Type of return value
+    /// Type of return value, including any "CHARSET" or "COLLATE" attributes
     pub return_type: DataType<'a>,
     /// Characteristics of created function
     pub characteristics: Vec<FunctionCharacteristic<'a>>,
@@ -567,6 +1503,21 @@ impl<'a> Spanned for CreateFunction<'a> {
     }
 }
 
+impl<'a> SpansMut for CreateFunction<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.function_span.spans_mut(f);
+        self.if_not_exists.spans_mut(f);
+        self.name.spans_mut(f);
+        self.params.spans_mut(f);
+        self.returns_span.spans_mut(f);
+        self.return_type.spans_mut(f);
+        self.characteristics.spans_mut(f);
+        self.return_.spans_mut(f);
+    }
+}
+
 fn parse_create_function<'a>(
     parser: &mut Parser<'a, '_>,
     create_span: Span,
@@ -574,20 +1525,15 @@ fn parse_create_function<'a>(
 ) -> Result<Statement<'a>, ParseError> {
     let function_span = parser.consume_keyword(Keyword::FUNCTION)?;
 
-    let if_not_exists = if let Some(if_) = parser.skip_keyword(Keyword::IF) {
-        Some(
-            parser
-                .consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?
-                .join_span(&if_),
-        )
-    } else {
-        None
-    };
+    let if_not_exists = parse_if_not_exists(parser)?;
 
     let name = parser.consume_plain_identifier()?;
     let mut params = Vec::new();
     parser.consume_token(Token::LParen)?;
     parser.recovered("')'", &|t| t == &Token::RParen, |parser| {
+        if parser.token == Token::RParen {
+            return Ok(());
+        }
         loop {
             let direction = match &parser.token {
                 Token::Ident(_, Keyword::IN) => {
@@ -699,14 +1645,30 @@ fn parse_create_function<'a>(
             }
             _ => break,
         };
+        let previous = characteristics
+            .iter()
+            .find(|c| characteristic_kind(c) == characteristic_kind(&f));
+        if let Some(previous) = previous {
+            let message = if core::mem::discriminant(previous) == core::mem::discriminant(&f) {
+                "Duplicate characteristic"
+            } else {
+                "Conflicting characteristic"
+            };
+            parser
+                .issues
+                .push(Issue::err(message, &f).frag("Previously specified here", previous));
+        }
         characteristics.push(f);
     }
 
     let return_ = if parser.options.dialect.is_maria() {
-        match parse_statement(parser)? {
-            Some(v) => Some(Box::new(v)),
+        let old = core::mem::replace(&mut parser.permit_compound_statements, true);
+        let statement = match parse_statement(parser)? {
+            Some(v) => Box::new(v),
             None => parser.expected_failure("statement")?,
-        }
+        };
+        parser.permit_compound_statements = old;
+        Some(statement)
     } else {
         None
     };
@@ -726,7 +1688,7 @@ fn parse_create_function<'a>(
 }
 
 /// When to fire the trigger
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 
 pub enum TriggerTime {
     Before(Span),
@@ -742,8 +1704,17 @@ impl Spanned for TriggerTime {
     }
 }
 
+impl SpansMut for TriggerTime {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TriggerTime::Before(v) => v.spans_mut(f),
+            TriggerTime::After(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// On what event to fire the trigger
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum TriggerEvent {
     Update(Span),
     Insert(Span),
@@ -760,9 +1731,46 @@ impl Spanned for TriggerEvent {
     }
 }
 
+impl SpansMut for TriggerEvent {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TriggerEvent::Update(v) => v.spans_mut(f),
+            TriggerEvent::Insert(v) => v.spans_mut(f),
+            TriggerEvent::Delete(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// How often the trigger should be fired
+#[derive(Clone, Debug, PartialEq)]
+pub enum TriggerGranularity {
+    /// Span of "FOR EACH ROW"
+    Row(Span),
+    /// Span of "FOR EACH STATEMENT"
+    Statement(Span),
+}
+
+impl Spanned for TriggerGranularity {
+    fn span(&self) -> Span {
+        match &self {
+            TriggerGranularity::Row(v) => v.span(),
+            TriggerGranularity::Statement(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for TriggerGranularity {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TriggerGranularity::Row(v) => v.spans_mut(f),
+            TriggerGranularity::Statement(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Represent a create trigger statement
 /// ```
-/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, CreateTrigger, Statement};
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, CreateTrigger, Statement, TriggerGranularity};
 /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
 /// # let mut issues = Vec::new();
 /// #
@@ -789,9 +1797,10 @@ impl Spanned for TriggerEvent {
 /// };
 ///
 /// assert!(create.name.as_str() == "my_trigger");
+/// assert!(matches!(create.granularity, TriggerGranularity::Row(_)));
 /// println!("{:#?}", create.statement)
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CreateTrigger<'a> {
     /// Span of "CREATE"
     pub create_span: Span,
@@ -811,8 +1820,10 @@ pub struct CreateTrigger<'a> {
     pub on_span: Span,
     /// Name of table to create the trigger on
     pub table: Identifier<'a>,
-    /// Span of "FOR EACH ROW"
-    pub for_each_row_span: Span,
+    /// Fire the trigger for each row or for each statement
+    pub granularity: TriggerGranularity,
+    /// Span of "WHEN" and condition for firing the trigger if specified
+    pub when: Option<(Span, Expression<'a>)>,
     /// Statement to execute
     pub statement: Box<Statement<'a>>,
 }
@@ -828,11 +1839,29 @@ impl<'a> Spanned for CreateTrigger<'a> {
             .join_span(&self.trigger_event)
             .join_span(&self.on_span)
             .join_span(&self.table)
-            .join_span(&self.for_each_row_span)
+            .join_span(&self.granularity)
+            .join_span(&self.when)
             .join_span(&self.statement)
     }
 }
 
+impl<'a> SpansMut for CreateTrigger<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.trigger_span.spans_mut(f);
+        self.if_not_exists.spans_mut(f);
+        self.name.spans_mut(f);
+        self.trigger_time.spans_mut(f);
+        self.trigger_event.spans_mut(f);
+        self.on_span.spans_mut(f);
+        self.table.spans_mut(f);
+        self.granularity.spans_mut(f);
+        self.when.spans_mut(f);
+        self.statement.spans_mut(f);
+    }
+}
+
 fn parse_create_trigger<'a>(
     parser: &mut Parser<'a, '_>,
     create_span: Span,
@@ -840,15 +1869,7 @@ fn parse_create_trigger<'a>(
 ) -> Result<Statement<'a>, ParseError> {
     let trigger_span = parser.consume_keyword(Keyword::TRIGGER)?;
 
-    let if_not_exists = if let Some(if_) = parser.skip_keyword(Keyword::IF) {
-        Some(
-            parser
-                .consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?
-                .join_span(&if_),
-        )
-    } else {
-        None
-    };
+    let if_not_exists = parse_if_not_exists(parser)?;
 
     let name = parser.consume_plain_identifier()?;
 
@@ -879,8 +1900,26 @@ fn parse_create_trigger<'a>(
 
     let table = parser.consume_plain_identifier()?;
 
-    let for_each_row_span =
-        parser.consume_keywords(&[Keyword::FOR, Keyword::EACH, Keyword::ROW])?;
+    let for_each_span = parser.consume_keywords(&[Keyword::FOR, Keyword::EACH])?;
+    let granularity = match &parser.token {
+        Token::Ident(_, Keyword::ROW) => TriggerGranularity::Row(
+            parser
+                .consume_keyword(Keyword::ROW)?
+                .join_span(&for_each_span),
+        ),
+        Token::Ident(_, Keyword::STATEMENT) => TriggerGranularity::Statement(
+            parser
+                .consume_keyword(Keyword::STATEMENT)?
+                .join_span(&for_each_span),
+        ),
+        _ => parser.expected_failure("'ROW' or 'STATEMENT'")?,
+    };
+
+    let when = if let Some(when_span) = parser.skip_keyword(Keyword::WHEN) {
+        Some((when_span, parse_expression(parser, false)?))
+    } else {
+        None
+    };
 
     // TODO [{ FOLLOWS | PRECEDES } other_trigger_name ]
 
@@ -901,12 +1940,13 @@ fn parse_create_trigger<'a>(
         trigger_event,
         on_span,
         table,
-        for_each_row_span,
+        granularity,
+        when,
         statement: Box::new(statement),
     }))
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CreateTypeEnum<'a> {
     /// Span of "CREATE"
     pub create_span: Span,
@@ -933,6 +1973,17 @@ impl<'a> Spanned for CreateTypeEnum<'a> {
     }
 }
 
+impl<'a> SpansMut for CreateTypeEnum<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.type_span.spans_mut(f);
+        self.name.spans_mut(f);
+        self.as_enum_span.spans_mut(f);
+        self.values.spans_mut(f);
+    }
+}
+
 fn parse_create_type<'a>(
     parser: &mut Parser<'a, '_>,
     create_span: Span,
@@ -974,7 +2025,333 @@ fn parse_create_type<'a>(
     }))
 }
 
-#[derive(Clone, Debug)]
+/// Represent a create database statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, CreateDatabase, Statement, TableOption};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "CREATE DATABASE IF NOT EXISTS `db`
+///     DEFAULT CHARACTER SET utf8mb4 DEFAULT COLLATE utf8mb4_general_ci;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let create: CreateDatabase = match stmts.pop() {
+///     Some(Statement::CreateDatabase(c)) => c,
+///     _ => panic!("We should get a create database statement")
+/// };
+///
+/// assert!(create.name.as_str() == "db");
+/// assert!(create.if_not_exists.is_some());
+/// assert!(matches!(&create.options[0], TableOption::DefaultCharSet { value, .. } if value.as_str() == "utf8mb4"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateDatabase<'a> {
+    /// Span of "CREATE"
+    pub create_span: Span,
+    /// Options after "CREATE"
+    pub create_options: Vec<CreateOption<'a>>,
+    /// Span of "DATABASE" or "SCHEMA"
+    pub database_span: Span,
+    /// Span of "IF NOT EXISTS" if specified
+    pub if_not_exists: Option<Span>,
+    /// Name of the created database
+    pub name: Identifier<'a>,
+    /// Character set and collation options
+    pub options: Vec<TableOption<'a>>,
+}
+
+impl<'a> Spanned for CreateDatabase<'a> {
+    fn span(&self) -> Span {
+        self.create_span
+            .join_span(&self.create_options)
+            .join_span(&self.database_span)
+            .join_span(&self.if_not_exists)
+            .join_span(&self.name)
+            .join_span(&self.options)
+    }
+}
+
+impl<'a> SpansMut for CreateDatabase<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.database_span.spans_mut(f);
+        self.if_not_exists.spans_mut(f);
+        self.name.spans_mut(f);
+        self.options.spans_mut(f);
+    }
+}
+
+/// Best effort check that a collation belongs to a character set, collations
+/// are named after their character set as in "utf8mb4_general_ci"
+fn collation_in_charset(charset: &str, collation: &str) -> bool {
+    let family = collation.split('_').next().unwrap_or(collation);
+    // utf8 is an alias of utf8mb3
+    fn normalize(v: &str) -> &str {
+        if v.eq_ignore_ascii_case("utf8mb3") {
+            "utf8"
+        } else {
+            v
+        }
+    }
+    normalize(family).eq_ignore_ascii_case(normalize(charset))
+}
+
+fn parse_create_database<'a>(
+    parser: &mut Parser<'a, '_>,
+    create_span: Span,
+    create_options: Vec<CreateOption<'a>>,
+) -> Result<Statement<'a>, ParseError> {
+    let database_span = match &parser.token {
+        Token::Ident(_, Keyword::DATABASE | Keyword::SCHEMA) => parser.consume(),
+        _ => parser.expected_failure("'DATABASE' or 'SCHEMA'")?,
+    };
+
+    for option in &create_options {
+        match option {
+            CreateOption::OrReplace(_) => (),
+            o => {
+                parser.issues.push(
+                    Issue::err("Not supported for CREATE DATABASE", o)
+                        .frag("Creating database", &database_span),
+                );
+            }
+        }
+    }
+
+    let if_not_exists = parse_if_not_exists(parser)?;
+
+    let name = parser.consume_plain_identifier()?;
+
+    let mut options = Vec::new();
+    while matches!(
+        parser.token,
+        Token::Ident(
+            _,
+            Keyword::DEFAULT | Keyword::CHARSET | Keyword::CHARACTER | Keyword::COLLATE
+        )
+    ) {
+        match parse_table_option(parser)? {
+            Some(v) => options.push(v),
+            None => break,
+        }
+        parser.skip_token(Token::Comma);
+    }
+
+    let mut charset = None;
+    let mut collation = None;
+    for option in &options {
+        match option {
+            TableOption::CharSet { value, .. } | TableOption::DefaultCharSet { value, .. } => {
+                charset = Some(value)
+            }
+            TableOption::Collate { value, .. } | TableOption::DefaultCollate { value, .. } => {
+                collation = Some(value)
+            }
+            _ => (),
+        }
+    }
+    if let (Some(charset), Some(collation)) = (charset, collation) {
+        if !collation_in_charset(charset.as_str(), collation.as_str()) {
+            parser.issues.push(
+                Issue::warn("Collation does not belong to the character set", collation)
+                    .frag("Character set specified here", charset),
+            );
+        }
+    }
+
+    Ok(Statement::CreateDatabase(CreateDatabase {
+        create_span,
+        create_options,
+        database_span,
+        if_not_exists,
+        name,
+        options,
+    }))
+}
+
+/// Option given to a create tablespace statement
+#[derive(Clone, Debug, PartialEq)]
+pub enum TablespaceOption<'a> {
+    InitialSize {
+        identifier: Span,
+        value: (u64, Span),
+    },
+    ExtentSize {
+        identifier: Span,
+        value: (u64, Span),
+    },
+    Engine {
+        identifier: Span,
+        value: Identifier<'a>,
+    },
+    Comment {
+        identifier: Span,
+        value: SString<'a>,
+    },
+}
+
+impl<'a> Spanned for TablespaceOption<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            TablespaceOption::InitialSize { identifier, value } => identifier.join_span(value),
+            TablespaceOption::ExtentSize { identifier, value } => identifier.join_span(value),
+            TablespaceOption::Engine { identifier, value } => identifier.join_span(value),
+            TablespaceOption::Comment { identifier, value } => identifier.join_span(value),
+        }
+    }
+}
+
+impl<'a> SpansMut for TablespaceOption<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TablespaceOption::InitialSize { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TablespaceOption::ExtentSize { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TablespaceOption::Engine { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+            TablespaceOption::Comment { identifier, value } => {
+                identifier.spans_mut(f);
+                value.spans_mut(f);
+            }
+        }
+    }
+}
+
+/// Represent a create tablespace statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, CreateTablespace, Statement, TablespaceOption};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "CREATE TABLESPACE `ts1` ADD DATAFILE 'ts1.ibd' INITIAL_SIZE = 65536 ENGINE=InnoDB;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let create: CreateTablespace = match stmts.pop() {
+///     Some(Statement::CreateTablespace(c)) => c,
+///     _ => panic!("We should get a create tablespace statement")
+/// };
+///
+/// assert!(create.name.as_str() == "ts1");
+/// assert!(create.datafile.unwrap().1.as_str() == "ts1.ibd");
+/// assert!(matches!(create.options[0], TablespaceOption::InitialSize { value: (65536, _), .. }));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateTablespace<'a> {
+    /// Span of "CREATE"
+    pub create_span: Span,
+    /// Options after "CREATE"
+    pub create_options: Vec<CreateOption<'a>>,
+    /// Span of "TABLESPACE"
+    pub tablespace_span: Span,
+    /// Name of the created tablespace
+    pub name: Identifier<'a>,
+    /// Span of "ADD DATAFILE" and the file name if specified
+    pub datafile: Option<(Span, SString<'a>)>,
+    /// Options after the data file
+    pub options: Vec<TablespaceOption<'a>>,
+}
+
+impl<'a> Spanned for CreateTablespace<'a> {
+    fn span(&self) -> Span {
+        self.create_span
+            .join_span(&self.create_options)
+            .join_span(&self.tablespace_span)
+            .join_span(&self.name)
+            .join_span(&self.datafile)
+            .join_span(&self.options)
+    }
+}
+
+impl<'a> SpansMut for CreateTablespace<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.tablespace_span.spans_mut(f);
+        self.name.spans_mut(f);
+        self.datafile.spans_mut(f);
+        self.options.spans_mut(f);
+    }
+}
+
+fn parse_create_tablespace<'a>(
+    parser: &mut Parser<'a, '_>,
+    create_span: Span,
+    create_options: Vec<CreateOption<'a>>,
+) -> Result<Statement<'a>, ParseError> {
+    let tablespace_span = parser.consume_keyword(Keyword::TABLESPACE)?;
+    let name = parser.consume_plain_identifier()?;
+    let datafile = match parser.skip_keyword(Keyword::ADD) {
+        Some(add_span) => Some((
+            parser
+                .consume_keyword(Keyword::DATAFILE)?
+                .join_span(&add_span),
+            parser.consume_string()?,
+        )),
+        None => None,
+    };
+    let mut options = Vec::new();
+    loop {
+        let identifier = parser.span.clone();
+        let option = match &parser.token {
+            Token::Ident(_, Keyword::INITIAL_SIZE) => {
+                parser.consume_keyword(Keyword::INITIAL_SIZE)?;
+                parser.skip_token(Token::Eq);
+                TablespaceOption::InitialSize {
+                    identifier,
+                    value: parser.consume_int()?,
+                }
+            }
+            Token::Ident(_, Keyword::EXTENT_SIZE) => {
+                parser.consume_keyword(Keyword::EXTENT_SIZE)?;
+                parser.skip_token(Token::Eq);
+                TablespaceOption::ExtentSize {
+                    identifier,
+                    value: parser.consume_int()?,
+                }
+            }
+            Token::Ident(_, Keyword::ENGINE) => {
+                parser.consume_keyword(Keyword::ENGINE)?;
+                parser.skip_token(Token::Eq);
+                TablespaceOption::Engine {
+                    identifier,
+                    value: parser.consume_plain_identifier()?,
+                }
+            }
+            Token::Ident(_, Keyword::COMMENT) => {
+                parser.consume_keyword(Keyword::COMMENT)?;
+                parser.skip_token(Token::Eq);
+                TablespaceOption::Comment {
+                    identifier,
+                    value: parser.consume_string()?,
+                }
+            }
+            _ => break,
+        };
+        options.push(option);
+    }
+    Ok(Statement::CreateTablespace(CreateTablespace {
+        create_span,
+        create_options,
+        tablespace_span,
+        name,
+        datafile,
+        options,
+    }))
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum CreateIndexOption {
     UsingGist(Span),
 }
@@ -987,7 +2364,15 @@ impl Spanned for CreateIndexOption {
     }
 }
 
-#[derive(Clone, Debug)]
+impl SpansMut for CreateIndexOption {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            CreateIndexOption::UsingGist(v) => v.spans_mut(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct CreateIndex<'a> {
     pub create_span: Span,
     pub create_options: Vec<CreateOption<'a>>,
@@ -1019,17 +2404,30 @@ impl<'a> Spanned for CreateIndex<'a> {
     }
 }
 
+impl<'a> SpansMut for CreateIndex<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.create_span.spans_mut(f);
+        self.create_options.spans_mut(f);
+        self.index_span.spans_mut(f);
+        self.index_name.spans_mut(f);
+        self.if_not_exists.spans_mut(f);
+        self.on_span.spans_mut(f);
+        self.table_name.spans_mut(f);
+        self.index_options.spans_mut(f);
+        self.l_paren_span.spans_mut(f);
+        self.column_names.spans_mut(f);
+        self.r_paren_span.spans_mut(f);
+        self.where_.spans_mut(f);
+    }
+}
+
 fn parse_create_index<'a>(
     parser: &mut Parser<'a, '_>,
     create_span: Span,
     create_options: Vec<CreateOption<'a>>,
 ) -> Result<Statement<'a>, ParseError> {
     let index_span = parser.consume_keyword(Keyword::INDEX)?;
-    let if_not_exists = if let Some(s) = parser.skip_keyword(Keyword::IF) {
-        Some(s.join_span(&parser.consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?))
-    } else {
-        None
-    };
+    let if_not_exists = parse_if_not_exists(parser)?;
     let index_name = parser.consume_plain_identifier()?;
     let on_span = parser.consume_keyword(Keyword::ON)?;
     let table_name = parse_qualified_name(parser)?;
@@ -1076,6 +2474,194 @@ fn parse_create_index<'a>(
     }))
 }
 
+/// Parse a single table option, returning None if the current token does not start one
+pub(crate) fn parse_table_option<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<Option<TableOption<'a>>, ParseError> {
+    let identifier = parser.span.clone();
+    Ok(Some(match &parser.token {
+        Token::Ident(_, Keyword::ENGINE) => {
+            parser.consume_keyword(Keyword::ENGINE)?;
+            parser.skip_token(Token::Eq);
+            TableOption::Engine {
+                identifier,
+                value: parser.consume_plain_identifier()?,
+            }
+        }
+        Token::Ident(_, Keyword::DEFAULT) => {
+            parser.consume_keyword(Keyword::DEFAULT)?;
+            match &parser.token {
+                Token::Ident(_, Keyword::CHARSET | Keyword::CHARACTER) => {
+                    if parser.skip_keyword(Keyword::CHARSET).is_none() {
+                        parser.consume_keywords(&[Keyword::CHARACTER, Keyword::SET])?;
+                    }
+                    parser.skip_token(Token::Eq);
+                    TableOption::DefaultCharSet {
+                        identifier,
+                        value: parser.consume_plain_identifier()?,
+                    }
+                }
+                Token::Ident(_, Keyword::COLLATE) => {
+                    parser.consume_keyword(Keyword::COLLATE)?;
+                    parser.skip_token(Token::Eq);
+                    TableOption::DefaultCollate {
+                        identifier,
+                        value: parser.consume_plain_identifier()?,
+                    }
+                }
+                _ => parser.expected_failure("'CHARSET', 'CHARACTER SET' or 'COLLATE'")?,
+            }
+        }
+        Token::Ident(_, Keyword::AUTO_INCREMENT) => {
+            parser.consume_keyword(Keyword::AUTO_INCREMENT)?;
+            parser.skip_token(Token::Eq);
+            TableOption::AutoIncrement {
+                identifier,
+                value: parser.consume_int()?,
+            }
+        }
+        Token::Ident(_, Keyword::CHARSET | Keyword::CHARACTER) => {
+            if parser.skip_keyword(Keyword::CHARSET).is_none() {
+                parser.consume_keywords(&[Keyword::CHARACTER, Keyword::SET])?;
+            }
+            parser.skip_token(Token::Eq);
+            TableOption::CharSet {
+                identifier,
+                value: parser.consume_plain_identifier()?,
+            }
+        }
+        Token::Ident(_, Keyword::COLLATE) => {
+            parser.consume_keyword(Keyword::COLLATE)?;
+            parser.skip_token(Token::Eq);
+            TableOption::Collate {
+                identifier,
+                value: parser.consume_plain_identifier()?,
+            }
+        }
+        Token::Ident(_, Keyword::ROW_FORMAT) => {
+            parser.consume_keyword(Keyword::ROW_FORMAT)?;
+            parser.skip_token(Token::Eq);
+            TableOption::RowFormat {
+                identifier,
+                value: parser.consume_plain_identifier()?,
+            }
+        }
+        Token::Ident(_, Keyword::COMMENT) => {
+            parser.consume_keyword(Keyword::COMMENT)?;
+            parser.skip_token(Token::Eq);
+            TableOption::Comment {
+                identifier,
+                value: parser.consume_string()?,
+            }
+        }
+        Token::Ident(_, Keyword::CHECKSUM) => {
+            parser.consume_keyword(Keyword::CHECKSUM)?;
+            parser.skip_token(Token::Eq);
+            let (value, span) = parser.consume_int::<u64>()?;
+            if value > 1 {
+                parser.issues.push(Issue::err("Expected 0 or 1", &span));
+            }
+            TableOption::Checksum {
+                identifier,
+                value: (value != 0, span),
+            }
+        }
+        Token::Ident(_, Keyword::COMPRESSION) => {
+            parser.consume_keyword(Keyword::COMPRESSION)?;
+            parser.skip_token(Token::Eq);
+            let value = parser.consume_string()?;
+            if !["ZLIB", "LZ4", "NONE"]
+                .iter()
+                .any(|v| v.eq_ignore_ascii_case(value.as_str()))
+            {
+                parser
+                    .issues
+                    .push(Issue::err("Expected 'ZLIB', 'LZ4' or 'NONE'", &value));
+            }
+            TableOption::Compression { identifier, value }
+        }
+        Token::Ident(_, Keyword::ENCRYPTION) => {
+            parser.consume_keyword(Keyword::ENCRYPTION)?;
+            parser.skip_token(Token::Eq);
+            let value = parser.consume_string()?;
+            let enabled = match value.as_str() {
+                "Y" | "y" => true,
+                "N" | "n" => false,
+                _ => {
+                    parser
+                        .issues
+                        .push(Issue::err("Expected 'Y' or 'N'", &value));
+                    false
+                }
+            };
+            TableOption::Encryption {
+                identifier,
+                value: (enabled, value.span),
+            }
+        }
+        Token::Ident(_, Keyword::ENGINE_ATTRIBUTE) => {
+            parser.consume_keyword(Keyword::ENGINE_ATTRIBUTE)?;
+            parser.skip_token(Token::Eq);
+            TableOption::EngineAttribute {
+                identifier,
+                value: parser.consume_string()?,
+            }
+        }
+        Token::Ident(_, Keyword::SECONDARY_ENGINE_ATTRIBUTE) => {
+            parser.consume_keyword(Keyword::SECONDARY_ENGINE_ATTRIBUTE)?;
+            parser.skip_token(Token::Eq);
+            TableOption::SecondaryEngineAttribute {
+                identifier,
+                value: parser.consume_string()?,
+            }
+        }
+        Token::Ident(_, Keyword::CONNECTION) => {
+            parser.consume_keyword(Keyword::CONNECTION)?;
+            parser.skip_token(Token::Eq);
+            TableOption::Connection {
+                identifier,
+                value: parser.consume_string()?,
+            }
+        }
+        Token::Ident(_, Keyword::PASSWORD) => {
+            parser.consume_keyword(Keyword::PASSWORD)?;
+            parser.skip_token(Token::Eq);
+            TableOption::Password {
+                identifier,
+                value: parser.consume_string()?,
+            }
+        }
+        Token::Ident(_, Keyword::DATA) => {
+            let identifier = parser.consume_keywords(&[Keyword::DATA, Keyword::DIRECTORY])?;
+            parser.skip_token(Token::Eq);
+            TableOption::DataDirectory {
+                identifier,
+                value: parser.consume_string()?,
+            }
+        }
+        Token::Ident(_, Keyword::WITH)
+            if matches!(parser.peek_n(1), Token::Ident(_, Keyword::SYSTEM)) =>
+        {
+            TableOption::SystemVersioning {
+                identifier: parser.consume_keywords(&[
+                    Keyword::WITH,
+                    Keyword::SYSTEM,
+                    Keyword::VERSIONING,
+                ])?,
+            }
+        }
+        Token::Ident(_, Keyword::INDEX) => {
+            let identifier = parser.consume_keywords(&[Keyword::INDEX, Keyword::DIRECTORY])?;
+            parser.skip_token(Token::Eq);
+            TableOption::IndexDirectory {
+                identifier,
+                value: parser.consume_string()?,
+            }
+        }
+        _ => return Ok(None),
+    }))
+}
+
 fn parse_create_table<'a>(
     parser: &mut Parser<'a, '_>,
     create_span: Span,
@@ -1089,119 +2675,131 @@ fn parse_create_table<'a>(
     };
     let mut if_not_exists = None;
 
-    parser.recovered("'('", &|t| t == &Token::LParen, |parser| {
-        if let Some(if_) = parser.skip_keyword(Keyword::IF) {
-            if_not_exists = Some(
-                if_.start
-                    ..parser
-                        .consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?
-                        .end,
-            );
-        }
-        identifier = parse_qualified_name(parser)?;
-        Ok(())
-    })?;
-
-    parser.consume_token(Token::LParen)?;
+    parser.recovered(
+        "'('",
+        &|t| {
+            matches!(
+                t,
+                Token::LParen
+                    | Token::Ident(
+                        _,
+                        Keyword::IGNORE | Keyword::REPLACE | Keyword::AS | Keyword::SELECT
+                    )
+            )
+        },
+        |parser| {
+            if_not_exists = parse_if_not_exists(parser)?;
+            identifier = parse_qualified_name(parser)?;
+            Ok(())
+        },
+    )?;
 
     let mut create_definitions = Vec::new();
-    if !matches!(parser.token, Token::RParen) {
-        loop {
-            parser.recovered(
-                "')' or ','",
-                &|t| matches!(t, Token::RParen | Token::Comma),
-                |parser| {
-                    create_definitions.push(parse_create_definition(parser)?);
-                    Ok(())
-                },
-            )?;
-            if matches!(parser.token, Token::RParen) {
-                break;
+    // The column list may be left out when the table is populated by a select
+    if !matches!(
+        parser.token,
+        Token::Ident(
+            _,
+            Keyword::IGNORE | Keyword::REPLACE | Keyword::AS | Keyword::SELECT
+        )
+    ) {
+        parser.consume_token(Token::LParen)?;
+        if !matches!(parser.token, Token::RParen) {
+            loop {
+                parser.recovered(
+                    "')' or ','",
+                    &|t| matches!(t, Token::RParen | Token::Comma),
+                    |parser| {
+                        create_definitions.push(parse_create_definition(parser)?);
+                        Ok(())
+                    },
+                )?;
+                if matches!(parser.token, Token::RParen) {
+                    break;
+                }
+                let comma_span = parser.consume_token(Token::Comma)?;
+                if matches!(parser.token, Token::RParen) {
+                    parser
+                        .issues
+                        .push(Issue::err("Trailing comma in column list", &comma_span));
+                    break;
+                }
+            }
+        }
+        parser.consume_token(Token::RParen)?;
+    }
+
+    // Columns of the primary key are implicitly NOT NULL
+    let primary_key_cols: Vec<Identifier<'a>> = create_definitions
+        .iter()
+        .filter_map(|d| match d {
+            CreateDefinition::IndexDefinition {
+                index_type: IndexType::Primary(_),
+                cols,
+                ..
+            } => Some(cols),
+            _ => None,
+        })
+        .flatten()
+        .map(|c| c.name.clone())
+        .collect();
+    if !primary_key_cols.is_empty() {
+        for definition in &mut create_definitions {
+            if let CreateDefinition::ColumnDefinition {
+                identifier,
+                nullable,
+                ..
+            } = definition
+            {
+                if primary_key_cols
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case(identifier))
+                {
+                    *nullable = false;
+                }
             }
-            parser.consume_token(Token::Comma)?;
         }
     }
-    parser.consume_token(Token::RParen)?;
 
     let mut options = Vec::new();
+    let mut on_conflict = None;
+    let mut as_span = None;
+    let mut select = None;
     let delimiter = parser.delimiter.clone();
     parser.recovered(
         delimiter.name(),
         &|t| t == &Token::Eof || t == &delimiter,
         |parser| {
             loop {
-                let identifier = parser.span.clone();
                 match &parser.token {
-                    Token::Ident(_, Keyword::ENGINE) => {
-                        parser.consume_keyword(Keyword::ENGINE)?;
-                        parser.skip_token(Token::Eq);
-                        options.push(TableOption::Engine {
-                            identifier,
-                            value: parser.consume_plain_identifier()?,
-                        });
-                    }
-                    Token::Ident(_, Keyword::DEFAULT) => {
-                        parser.consume_keyword(Keyword::DEFAULT)?;
-                        match &parser.token {
-                            Token::Ident(_, Keyword::CHARSET) => {
-                                parser.consume_keyword(Keyword::CHARSET)?;
-                                parser.skip_token(Token::Eq);
-                                options.push(TableOption::DefaultCharSet {
-                                    identifier,
-                                    value: parser.consume_plain_identifier()?,
-                                });
-                            }
-                            Token::Ident(_, Keyword::COLLATE) => {
-                                parser.consume_keyword(Keyword::COLLATE)?;
-                                parser.skip_token(Token::Eq);
-                                options.push(TableOption::DefaultCollate {
-                                    identifier,
-                                    value: parser.consume_plain_identifier()?,
-                                });
-                            }
-                            _ => parser.expected_failure("'CHARSET' or 'COLLATE'")?,
-                        }
-                    }
-                    Token::Ident(_, Keyword::CHARSET) => {
-                        parser.consume_keyword(Keyword::CHARSET)?;
-                        parser.skip_token(Token::Eq);
-                        options.push(TableOption::CharSet {
-                            identifier,
-                            value: parser.consume_plain_identifier()?,
-                        });
-                    }
-                    Token::Ident(_, Keyword::COLLATE) => {
-                        parser.consume_keyword(Keyword::COLLATE)?;
-                        parser.skip_token(Token::Eq);
-                        options.push(TableOption::Collate {
-                            identifier,
-                            value: parser.consume_plain_identifier()?,
-                        });
-                    }
-                    Token::Ident(_, Keyword::ROW_FORMAT) => {
-                        parser.consume_keyword(Keyword::ROW_FORMAT)?;
-                        parser.skip_token(Token::Eq);
-                        options.push(TableOption::RowFormat {
-                            identifier,
-                            value: parser.consume_plain_identifier()?,
-                        });
-                        //TODO validate raw format is in the keyword set
-                    }
-                    Token::Ident(_, Keyword::COMMENT) => {
-                        parser.consume_keyword(Keyword::COMMENT)?;
-                        parser.skip_token(Token::Eq);
-                        options.push(TableOption::Comment {
-                            identifier,
-                            value: parser.consume_string()?,
-                        });
-                    }
                     t if t == &parser.delimiter => break,
                     Token::Eof => break,
-                    _ => {
-                        parser.expected_failure("table option or delimiter")?;
-                    }
+                    Token::Ident(
+                        _,
+                        Keyword::IGNORE | Keyword::REPLACE | Keyword::AS | Keyword::SELECT,
+                    ) => break,
+                    _ => match parse_table_option(parser)? {
+                        Some(option) => options.push(option),
+                        None => parser.expected_failure("table option or delimiter")?,
+                    },
                 }
             }
+            on_conflict = match &parser.token {
+                Token::Ident(_, Keyword::IGNORE) => Some(CreateTableOnConflict::Ignore(
+                    parser.consume_keyword(Keyword::IGNORE)?,
+                )),
+                Token::Ident(_, Keyword::REPLACE) => Some(CreateTableOnConflict::Replace(
+                    parser.consume_keyword(Keyword::REPLACE)?,
+                )),
+                _ => None,
+            };
+            as_span = parser.skip_keyword(Keyword::AS);
+            if on_conflict.is_some()
+                || as_span.is_some()
+                || matches!(parser.token, Token::Ident(_, Keyword::SELECT))
+            {
+                select = Some(Box::new(parse_compound_query(parser)?));
+            }
             Ok(())
         },
     )?;
@@ -1214,15 +2812,33 @@ fn parse_create_table<'a>(
         if_not_exists,
         options,
         create_definitions,
+        on_conflict,
+        as_span,
+        select,
     }))
 }
 
+/// Parse the user or host part of an account, given either as an identifier
+/// or as a string
+pub(crate) fn parse_account_name<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<Identifier<'a>, ParseError> {
+    match &parser.token {
+        Token::SingleQuotedString(v) | Token::DoubleQuotedString(v) => {
+            let v = *v;
+            Ok(Identifier::new(v, parser.consume()))
+        }
+        _ => parser.consume_plain_identifier(),
+    }
+}
+
 pub(crate) fn parse_create<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
     let create_span = parser.span.clone();
     parser.consume_keyword(Keyword::CREATE)?;
 
     let mut create_options = Vec::new();
-    const CREATABLE: &str = "'TABLE' | 'VIEW' | 'TRIGGER' | 'FUNCTION' | 'INDEX' | 'TYPE'";
+    const CREATABLE: &str =
+        "'TABLE' | 'VIEW' | 'TRIGGER' | 'FUNCTION' | 'INDEX' | 'TYPE' | 'DATABASE' | 'TABLESPACE'";
 
     parser.recovered(
         CREATABLE,
@@ -1237,6 +2853,9 @@ pub(crate) fn parse_create<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<
                         | Keyword::FUNCTION
                         | Keyword::INDEX
                         | Keyword::TYPE
+                        | Keyword::DATABASE
+                        | Keyword::SCHEMA
+                        | Keyword::TABLESPACE
                 )
             )
         },
@@ -1273,9 +2892,9 @@ pub(crate) fn parse_create<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<
                         let definer_span = parser.consume_keyword(Keyword::DEFINER)?;
                         parser.consume_token(Token::Eq)?;
                         // TODO user | CURRENT_USER | role | CURRENT_ROLE
-                        let user = parser.consume_plain_identifier()?;
+                        let user = parse_account_name(parser)?;
                         parser.consume_token(Token::At)?;
-                        let host = parser.consume_plain_identifier()?;
+                        let host = parse_account_name(parser)?;
                         CreateOption::Definer {
                             definer_span,
                             user,
@@ -1305,6 +2924,25 @@ pub(crate) fn parse_create<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<
         },
     )?;
 
+    // OR REPLACE is accepted for tables and indexes only by MariaDB
+    let or_replace_supported = match &parser.token {
+        Token::Ident(_, Keyword::TABLE | Keyword::INDEX | Keyword::DATABASE | Keyword::SCHEMA) => {
+            parser.options.dialect.is_maria()
+        }
+        Token::Ident(_, Keyword::TYPE | Keyword::TABLESPACE) => false,
+        _ => true,
+    };
+    if !or_replace_supported {
+        for option in &create_options {
+            if let CreateOption::OrReplace(or_replace_span) = option {
+                parser.issues.push(
+                    Issue::err("OR REPLACE is not supported here", or_replace_span)
+                        .frag("Creating this", &parser.span),
+                );
+            }
+        }
+    }
+
     match &parser.token {
         Token::Ident(_, Keyword::INDEX) => parse_create_index(parser, create_span, create_options),
         Token::Ident(_, Keyword::TABLE) => parse_create_table(parser, create_span, create_options),
@@ -1316,6 +2954,12 @@ pub(crate) fn parse_create<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<
             parse_create_trigger(parser, create_span, create_options)
         }
         Token::Ident(_, Keyword::TYPE) => parse_create_type(parser, create_span, create_options),
+        Token::Ident(_, Keyword::DATABASE | Keyword::SCHEMA) => {
+            parse_create_database(parser, create_span, create_options)
+        }
+        Token::Ident(_, Keyword::TABLESPACE) => {
+            parse_create_tablespace(parser, create_span, create_options)
+        }
         _ => parser.expected_failure(CREATABLE),
     }
 }
@@ -13,16 +13,17 @@
 use alloc::{boxed::Box, vec::Vec};
 
 use crate::{
+    alter::{parse_cols, parse_foreign_key_ons, ForeignKeyOn},
     expression::{parse_expression, Expression},
     keywords::Keyword,
     lexer::Token,
     parser::{ParseError, Parser},
     span::OptSpanned,
-    Identifier, SString, Span, Spanned,
+    Identifier, Issue, SString, Span, Spanned, SpansMut,
 };
 
 /// A property on a datatype
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum DataTypeProperty<'a> {
     Signed(Span),
     Unsigned(Span),
@@ -43,6 +44,25 @@ pub enum DataTypeProperty<'a> {
     PrimaryKey(Span),
     As((Span, Box<Expression<'a>>)),
     Check((Span, Box<Expression<'a>>)),
+    /// Span of "AS ROW START", the column holds the start of a system versioned row
+    AsRowStart(Span),
+    /// Span of "AS ROW END", the column holds the end of a system versioned row
+    AsRowEnd(Span),
+    /// Span of "VISIBLE"
+    Visible(Span),
+    /// Span of "INVISIBLE", the column is left out of "SELECT *"
+    Invisible(Span),
+    /// Inline foreign key "REFERENCES table (cols) [ON {UPDATE | DELETE} action]"
+    References {
+        /// Span of "REFERENCES"
+        references_span: Span,
+        /// Referred table
+        table: Identifier<'a>,
+        /// Columns in referred table
+        cols: Vec<Identifier<'a>>,
+        /// List of what should happen at specified events
+        ons: Vec<ForeignKeyOn>,
+    },
 }
 
 impl<'a> Spanned for DataTypeProperty<'a> {
@@ -67,16 +87,80 @@ impl<'a> Spanned for DataTypeProperty<'a> {
             DataTypeProperty::As((s, v)) => s.join_span(v),
             DataTypeProperty::Check((s, v)) => s.join_span(v),
             DataTypeProperty::PrimaryKey(v) => v.span(),
+            DataTypeProperty::AsRowStart(v) => v.span(),
+            DataTypeProperty::AsRowEnd(v) => v.span(),
+            DataTypeProperty::Visible(v) => v.span(),
+            DataTypeProperty::Invisible(v) => v.span(),
+            DataTypeProperty::References {
+                references_span,
+                table,
+                cols,
+                ons,
+            } => references_span
+                .join_span(table)
+                .join_span(cols)
+                .join_span(ons),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+impl<'a> SpansMut for DataTypeProperty<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            DataTypeProperty::Signed(v) => v.spans_mut(f),
+            DataTypeProperty::Unsigned(v) => v.spans_mut(f),
+            DataTypeProperty::Zerofill(v) => v.spans_mut(f),
+            DataTypeProperty::Null(v) => v.spans_mut(f),
+            DataTypeProperty::NotNull(v) => v.spans_mut(f),
+            DataTypeProperty::Default(v) => v.spans_mut(f),
+            DataTypeProperty::Comment(v) => v.spans_mut(f),
+            DataTypeProperty::Charset(v) => v.spans_mut(f),
+            DataTypeProperty::Collate(v) => v.spans_mut(f),
+            DataTypeProperty::Virtual(v) => v.spans_mut(f),
+            DataTypeProperty::Persistent(v) => v.spans_mut(f),
+            DataTypeProperty::Stored(v) => v.spans_mut(f),
+            DataTypeProperty::Unique(v) => v.spans_mut(f),
+            DataTypeProperty::UniqueKey(v) => v.spans_mut(f),
+            DataTypeProperty::GeneratedAlways(v) => v.spans_mut(f),
+            DataTypeProperty::AutoIncrement(v) => v.spans_mut(f),
+            DataTypeProperty::PrimaryKey(v) => v.spans_mut(f),
+            DataTypeProperty::As(v) => v.spans_mut(f),
+            DataTypeProperty::Check(v) => v.spans_mut(f),
+            DataTypeProperty::AsRowStart(v) => v.spans_mut(f),
+            DataTypeProperty::AsRowEnd(v) => v.spans_mut(f),
+            DataTypeProperty::Visible(v) => v.spans_mut(f),
+            DataTypeProperty::Invisible(v) => v.spans_mut(f),
+            DataTypeProperty::References {
+                references_span,
+                table,
+                cols,
+                ons,
+            } => {
+                references_span.spans_mut(f);
+                table.spans_mut(f);
+                cols.spans_mut(f);
+                ons.spans_mut(f);
+            }
+        }
+    }
+}
+
+/// Timestamp type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Timestamp {
+    /// Fractional seconds precision if specified
     pub width: Option<(usize, Span)>,
+    /// Span of "WITH TIME ZONE" if specified
     pub with_time_zone: Option<Span>,
 }
 
+impl SpansMut for Timestamp {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.width.spans_mut(f);
+        self.with_time_zone.spans_mut(f);
+    }
+}
+
 impl OptSpanned for Timestamp {
     fn opt_span(&self) -> Option<Span> {
         self.width.opt_span().opt_join_span(&self.with_time_zone)
@@ -84,7 +168,7 @@ impl OptSpanned for Timestamp {
 }
 
 /// Type of datatype
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Type<'a> {
     Boolean,
     TinyInt(Option<(usize, Span)>),
@@ -104,9 +188,11 @@ pub enum Type<'a> {
     Float(Option<(usize, usize, Span)>),
     Double(Option<(usize, usize, Span)>),
     Numeric(usize, usize, Span),
+    /// Datetime with fractional seconds precision if specified
     DateTime(Option<(usize, Span)>),
     Timestamp(Timestamp),
     Timestamptz,
+    /// Time with fractional seconds precision if specified
     Time(Option<(usize, Span)>),
     TinyBlob(Option<(usize, Span)>),
     MediumBlob(Option<(usize, Span)>),
@@ -123,6 +209,55 @@ pub enum Type<'a> {
     Inet6,
 }
 
+impl<'a> SpansMut for Type<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Type::TinyInt(v) => v.spans_mut(f),
+            Type::SmallInt(v) => v.spans_mut(f),
+            Type::Integer(v) => v.spans_mut(f),
+            Type::Int(v) => v.spans_mut(f),
+            Type::BigInt(v) => v.spans_mut(f),
+            Type::Char(v) => v.spans_mut(f),
+            Type::VarChar(v) => v.spans_mut(f),
+            Type::TinyText(v) => v.spans_mut(f),
+            Type::MediumText(v) => v.spans_mut(f),
+            Type::Text(v) => v.spans_mut(f),
+            Type::LongText(v) => v.spans_mut(f),
+            Type::Enum(v) => v.spans_mut(f),
+            Type::Set(v) => v.spans_mut(f),
+            Type::Float(v) => v.spans_mut(f),
+            Type::Double(v) => v.spans_mut(f),
+            Type::Numeric(v0, v1, v2) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+                v2.spans_mut(f);
+            }
+            Type::DateTime(v) => v.spans_mut(f),
+            Type::Timestamp(v) => v.spans_mut(f),
+            Type::Time(v) => v.spans_mut(f),
+            Type::TinyBlob(v) => v.spans_mut(f),
+            Type::MediumBlob(v) => v.spans_mut(f),
+            Type::Blob(v) => v.spans_mut(f),
+            Type::LongBlob(v) => v.spans_mut(f),
+            Type::VarBinary(v) => v.spans_mut(f),
+            Type::Binary(v) => v.spans_mut(f),
+            Type::Named(v) => v.spans_mut(f),
+            Type::Bit(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            Type::Boolean
+            | Type::Float8
+            | Type::Timestamptz
+            | Type::Date
+            | Type::Json
+            | Type::Bytea
+            | Type::Inet4
+            | Type::Inet6 => (),
+        }
+    }
+}
+
 impl<'a> OptSpanned for Type<'a> {
     fn opt_span(&self) -> Option<Span> {
         match &self {
@@ -165,8 +300,26 @@ impl<'a> OptSpanned for Type<'a> {
     }
 }
 
+impl<'a> Type<'a> {
+    /// Is this an integer, fixed point or floating point type
+    pub(crate) fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Type::TinyInt(_)
+                | Type::SmallInt(_)
+                | Type::Integer(_)
+                | Type::Int(_)
+                | Type::BigInt(_)
+                | Type::Float8
+                | Type::Float(_)
+                | Type::Double(_)
+                | Type::Numeric(_, _, _)
+        )
+    }
+}
+
 /// Type of data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DataType<'a> {
     /// Span of type_ identifier
     pub identifier: Span,
@@ -183,6 +336,15 @@ impl<'a> Spanned for DataType<'a> {
             .join_span(&self.properties)
     }
 }
+
+impl<'a> SpansMut for DataType<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.identifier.spans_mut(f);
+        self.type_.spans_mut(f);
+        self.properties.spans_mut(f);
+    }
+}
+
 fn parse_width(parser: &mut Parser<'_, '_>) -> Result<Option<(usize, Span)>, ParseError> {
     if !matches!(parser.token, Token::LParen) {
         return Ok(None);
@@ -193,6 +355,19 @@ fn parse_width(parser: &mut Parser<'_, '_>) -> Result<Option<(usize, Span)>, Par
     Ok(Some(value))
 }
 
+fn parse_precision(parser: &mut Parser<'_, '_>) -> Result<Option<(usize, Span)>, ParseError> {
+    let precision = parse_width(parser)?;
+    if let Some((v, span)) = &precision {
+        if *v > 6 {
+            parser.issues.push(Issue::err(
+                "Fractional seconds precision must be between 0 and 6",
+                span,
+            ));
+        }
+    }
+    Ok(precision)
+}
+
 fn parse_width_req(parser: &mut Parser<'_, '_>) -> Result<(usize, Span), ParseError> {
     if !matches!(parser.token, Token::LParen) {
         return parser.expected_failure("'('");
@@ -220,11 +395,9 @@ fn parse_enum_set_values<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<SString<
     Ok(ans)
 }
 
-pub(crate) fn parse_data_type<'a>(
-    parser: &mut Parser<'a, '_>,
-    no_as: bool,
-) -> Result<DataType<'a>, ParseError> {
-    let (identifier, type_) = match &parser.token {
+/// Parse a type name and its parameters, without any properties
+fn parse_type<'a>(parser: &mut Parser<'a, '_>) -> Result<(Span, Type<'a>), ParseError> {
+    Ok(match &parser.token {
         Token::Ident(_, Keyword::BOOLEAN) => {
             (parser.consume_keyword(Keyword::BOOLEAN)?, Type::Boolean)
         }
@@ -336,11 +509,11 @@ pub(crate) fn parse_data_type<'a>(
         }
         Token::Ident(_, Keyword::DATETIME) => (
             parser.consume_keyword(Keyword::DATETIME)?,
-            Type::DateTime(parse_width(parser)?),
+            Type::DateTime(parse_precision(parser)?),
         ),
         Token::Ident(_, Keyword::TIME) => (
             parser.consume_keyword(Keyword::TIME)?,
-            Type::Time(parse_width(parser)?),
+            Type::Time(parse_precision(parser)?),
         ),
         Token::Ident(_, Keyword::TIMESTAMPTZ) => (
             parser.consume_keyword(Keyword::TIMESTAMPTZ)?,
@@ -348,7 +521,7 @@ pub(crate) fn parse_data_type<'a>(
         ),
         Token::Ident(_, Keyword::TIMESTAMP) => {
             let timestamp_span = parser.consume_keyword(Keyword::TIMESTAMP)?;
-            let width = parse_width(parser)?;
+            let width = parse_precision(parser)?;
             let with_time_zone = match parser.skip_keyword(Keyword::WITH) {
                 Some(with_span) => Some(
                     with_span.join_span(&parser.consume_keywords(&[Keyword::TIME, Keyword::ZONE])?),
@@ -382,7 +555,27 @@ pub(crate) fn parse_data_type<'a>(
             (name.clone(), Type::Named(name))
         }
         _ => parser.expected_failure("type")?,
-    };
+    })
+}
+
+/// Parse a data type without properties, for use where keywords such as
+/// "NULL" or "DEFAULT" may follow the type
+pub(crate) fn parse_data_type_without_properties<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<DataType<'a>, ParseError> {
+    let (identifier, type_) = parse_type(parser)?;
+    Ok(DataType {
+        identifier,
+        type_,
+        properties: Vec::new(),
+    })
+}
+
+pub(crate) fn parse_data_type<'a>(
+    parser: &mut Parser<'a, '_>,
+    no_as: bool,
+) -> Result<DataType<'a>, ParseError> {
+    let (identifier, type_) = parse_type(parser)?;
     let mut properties = Vec::new();
     loop {
         match parser.token {
@@ -413,9 +606,15 @@ pub(crate) fn parse_data_type<'a>(
                     parser.consume_plain_identifier()?,
                 ));
             }
+            Token::Ident(_, Keyword::CHARSET) => {
+                parser.consume_keyword(Keyword::CHARSET)?;
+                properties.push(DataTypeProperty::Charset(
+                    parser.consume_plain_identifier()?,
+                ));
+            }
             Token::Ident(_, Keyword::COLLATE) => {
                 parser.consume_keyword(Keyword::COLLATE)?;
-                properties.push(DataTypeProperty::Charset(
+                properties.push(DataTypeProperty::Collate(
                     parser.consume_plain_identifier()?,
                 ));
             }
@@ -438,6 +637,12 @@ pub(crate) fn parse_data_type<'a>(
             Token::Ident(_, Keyword::STORED) => properties.push(DataTypeProperty::Stored(
                 parser.consume_keyword(Keyword::STORED)?,
             )),
+            Token::Ident(_, Keyword::VISIBLE) => {
+                properties.push(DataTypeProperty::Visible(parser.consume()))
+            }
+            Token::Ident(_, Keyword::INVISIBLE) => {
+                properties.push(DataTypeProperty::Invisible(parser.consume()))
+            }
             Token::Ident(_, Keyword::UNIQUE) => {
                 let span = parser.consume_keyword(Keyword::UNIQUE)?;
                 if let Some(s2) = parser.skip_keyword(Keyword::KEY) {
@@ -462,6 +667,19 @@ pub(crate) fn parse_data_type<'a>(
                     ))
                 }
             }
+            Token::Ident(_, Keyword::AS)
+                if !no_as && matches!(parser.peek_n(1), Token::Ident(_, Keyword::ROW)) =>
+            {
+                let span = parser.consume_keywords(&[Keyword::AS, Keyword::ROW])?;
+                match &parser.token {
+                    Token::Ident(_, Keyword::START) => properties.push(
+                        DataTypeProperty::AsRowStart(parser.consume().join_span(&span)),
+                    ),
+                    _ => properties.push(DataTypeProperty::AsRowEnd(
+                        parser.consume_keyword(Keyword::END)?.join_span(&span),
+                    )),
+                }
+            }
             Token::Ident(_, Keyword::AS) if !no_as => {
                 let span = parser.consume_keyword(Keyword::AS)?;
                 let s1 = parser.consume_token(Token::LParen)?;
@@ -485,9 +703,39 @@ pub(crate) fn parse_data_type<'a>(
                 let e = e.unwrap_or_else(|| Expression::Invalid(s1.join_span(&s2)));
                 properties.push(DataTypeProperty::Check((span, Box::new(e))));
             }
+            Token::Ident(_, Keyword::REFERENCES) => {
+                let references_span = parser.consume_keyword(Keyword::REFERENCES)?;
+                let table = parser.consume_plain_identifier()?;
+                let cols = if matches!(parser.token, Token::LParen) {
+                    parse_cols(parser)?
+                } else {
+                    Vec::new()
+                };
+                let ons = parse_foreign_key_ons(parser)?;
+                properties.push(DataTypeProperty::References {
+                    references_span,
+                    table,
+                    cols,
+                    ons,
+                });
+            }
             _ => break,
         }
     }
+    let null = properties.iter().find_map(|p| match p {
+        DataTypeProperty::Null(v) => Some(v),
+        _ => None,
+    });
+    let not_null = properties.iter().find_map(|p| match p {
+        DataTypeProperty::NotNull(v) => Some(v),
+        _ => None,
+    });
+    if let (Some(null), Some(not_null)) = (null, not_null) {
+        parser.issues.push(
+            Issue::err("Column cannot be both NULL and NOT NULL", null)
+                .frag("NOT NULL specified here", not_null),
+        );
+    }
     // TODO validate properties order
     // TODO validate allowed properties
     Ok(DataType {
@@ -18,12 +18,12 @@ use crate::{
     lexer::Token,
     parser::{ParseError, Parser},
     qualified_name::parse_qualified_name,
-    select::parse_table_reference,
-    Issue, QualifiedName, Span, Spanned, TableReference,
+    select::{parse_partition_selection, parse_table_reference},
+    Identifier, Issue, QualifiedName, Span, Spanned, SpansMut, TableReference,
 };
 
 /// Flags for deletion
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum DeleteFlag {
     LowPriority(Span),
     Quick(Span),
@@ -40,6 +40,16 @@ impl Spanned for DeleteFlag {
     }
 }
 
+impl SpansMut for DeleteFlag {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            DeleteFlag::LowPriority(v) => v.spans_mut(f),
+            DeleteFlag::Quick(v) => v.spans_mut(f),
+            DeleteFlag::Ignore(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Represent a delete statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Delete, Statement};
@@ -66,7 +76,7 @@ impl Spanned for DeleteFlag {
 ///
 /// # assert!(issues.is_empty());
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Delete<'a> {
     /// Span of "DELETE"
     pub delete_span: Span,
@@ -78,6 +88,8 @@ pub struct Delete<'a> {
     pub tables: Vec<QualifiedName<'a>>,
     /// Table to use in where clause in multi table delete
     pub using: Vec<TableReference<'a>>,
+    /// Span of "PARTITION" and names of the partitions to delete from, if specified
+    pub partition: Option<(Span, Vec<Identifier<'a>>)>,
     /// Where expression and Span of "WHERE" if specified
     pub where_: Option<(Expression<'a>, Span)>,
 }
@@ -89,10 +101,23 @@ impl<'a> Spanned for Delete<'a> {
             .join_span(&self.from_span)
             .join_span(&self.tables)
             .join_span(&self.using)
+            .join_span(&self.partition)
             .join_span(&self.where_)
     }
 }
 
+impl<'a> SpansMut for Delete<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.delete_span.spans_mut(f);
+        self.flags.spans_mut(f);
+        self.from_span.spans_mut(f);
+        self.tables.spans_mut(f);
+        self.using.spans_mut(f);
+        self.partition.spans_mut(f);
+        self.where_.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_delete<'a>(parser: &mut Parser<'a, '_>) -> Result<Delete<'a>, ParseError> {
     let delete_span = parser.consume_keyword(Keyword::DELETE)?;
     let mut flags = Vec::new();
@@ -139,7 +164,15 @@ pub(crate) fn parse_delete<'a>(parser: &mut Parser<'a, '_>) -> Result<Delete<'a>
         from_span
     };
 
-    //TODO [PARTITION (partition_list)]
+    let partition = parse_partition_selection(parser)?;
+    if let Some((span, _)) = &partition {
+        if tables.len() != 1 || !using.is_empty() {
+            parser.issues.push(Issue::err(
+                "PARTITION is only allowed when deleting from a single table",
+                span,
+            ));
+        }
+    }
     //TODO [FOR PORTION OF period FROM expr1 TO expr2]
 
     if let Some(using_span) = parser.skip_keyword(Keyword::USING) {
@@ -172,6 +205,7 @@ pub(crate) fn parse_delete<'a>(parser: &mut Parser<'a, '_>) -> Result<Delete<'a>
         delete_span,
         tables,
         using,
+        partition,
         from_span,
         where_,
     })
@@ -15,9 +15,9 @@ use alloc::vec::Vec;
 use crate::{
     keywords::Keyword,
     lexer::Token,
-    parser::{ParseError, Parser},
+    parser::{parse_if_exists, ParseError, Parser},
     qualified_name::parse_qualified_name,
-    Identifier, QualifiedName, Span, Spanned, Statement,
+    Identifier, QualifiedName, Span, Spanned, SpansMut, Statement,
 };
 
 /// Represent a drop table statement
@@ -39,7 +39,7 @@ use crate::{
 ///
 /// assert!(delete.tables.get(0).unwrap().identifier.as_str() == "Employees");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropTable<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -65,6 +65,17 @@ impl<'a> Spanned for DropTable<'a> {
     }
 }
 
+impl<'a> SpansMut for DropTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.temporary.spans_mut(f);
+        self.table_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.tables.spans_mut(f);
+        self.cascade.spans_mut(f);
+    }
+}
+
 /// Represent a drop view statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DropView, Statement};
@@ -84,7 +95,7 @@ impl<'a> Spanned for DropTable<'a> {
 ///
 /// assert!(delete.views.get(0).unwrap().identifier.as_str() == "Employees");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropView<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -108,6 +119,16 @@ impl<'a> Spanned for DropView<'a> {
     }
 }
 
+impl<'a> SpansMut for DropView<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.temporary.spans_mut(f);
+        self.view_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.views.spans_mut(f);
+    }
+}
+
 /// Represent a drop database statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DropDatabase, Statement};
@@ -127,7 +148,7 @@ impl<'a> Spanned for DropView<'a> {
 ///
 /// assert!(s.database.as_str() == "mydb");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropDatabase<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -148,6 +169,15 @@ impl<'a> Spanned for DropDatabase<'a> {
     }
 }
 
+impl<'a> SpansMut for DropDatabase<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.database_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.database.spans_mut(f);
+    }
+}
+
 /// Represent a drop event statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DropEvent, Statement};
@@ -167,7 +197,7 @@ impl<'a> Spanned for DropDatabase<'a> {
 ///
 /// assert!(s.event.identifier.as_str() == "myevent");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropEvent<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -188,6 +218,15 @@ impl<'a> Spanned for DropEvent<'a> {
     }
 }
 
+impl<'a> SpansMut for DropEvent<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.event_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.event.spans_mut(f);
+    }
+}
+
 /// Represent a drop function statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DropFunction, Statement};
@@ -207,7 +246,7 @@ impl<'a> Spanned for DropEvent<'a> {
 ///
 /// assert!(s.function.identifier.as_str() == "myfunc");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropFunction<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -228,6 +267,15 @@ impl<'a> Spanned for DropFunction<'a> {
     }
 }
 
+impl<'a> SpansMut for DropFunction<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.function_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.function.spans_mut(f);
+    }
+}
+
 /// Represent a drop procedure statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DropProcedure, Statement};
@@ -247,7 +295,7 @@ impl<'a> Spanned for DropFunction<'a> {
 ///
 /// assert!(s.procedure.identifier.as_str() == "myproc");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropProcedure<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -268,6 +316,15 @@ impl<'a> Spanned for DropProcedure<'a> {
     }
 }
 
+impl<'a> SpansMut for DropProcedure<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.procedure_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.procedure.spans_mut(f);
+    }
+}
+
 /// Represent a drop server statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DropServer, Statement};
@@ -287,7 +344,7 @@ impl<'a> Spanned for DropProcedure<'a> {
 ///
 /// assert!(s.server.as_str() == "myserver");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropServer<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -308,6 +365,15 @@ impl<'a> Spanned for DropServer<'a> {
     }
 }
 
+impl<'a> SpansMut for DropServer<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.server_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.server.spans_mut(f);
+    }
+}
+
 /// Represent a drop trigger statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DropTrigger, Statement};
@@ -327,7 +393,7 @@ impl<'a> Spanned for DropServer<'a> {
 ///
 /// assert!(s.identifier.identifier.as_str() == "mytrigger");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropTrigger<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -348,17 +414,22 @@ impl<'a> Spanned for DropTrigger<'a> {
     }
 }
 
+impl<'a> SpansMut for DropTrigger<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.trigger_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.identifier.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
     let drop_span = parser.consume_keyword(Keyword::DROP)?;
     let temporary = parser.skip_keyword(Keyword::TEMPORARY);
     match &parser.token {
         Token::Ident(_, Keyword::TABLE) => {
             let table_span = parser.consume_keyword(Keyword::TABLE)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let mut tables = Vec::new();
             loop {
                 tables.push(parse_qualified_name(parser)?);
@@ -384,11 +455,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
             // TODO complain about temporary
             let kw = *kw;
             let database_span = parser.consume_keyword(kw)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let database = parser.consume_plain_identifier()?;
             Ok(Statement::DropDatabase(DropDatabase {
                 drop_span,
@@ -400,11 +467,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
         Token::Ident(_, Keyword::EVENT) => {
             // TODO complain about temporary
             let event_span = parser.consume_keyword(Keyword::EVENT)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let event = parse_qualified_name(parser)?;
             Ok(Statement::DropEvent(DropEvent {
                 drop_span,
@@ -416,11 +479,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
         Token::Ident(_, Keyword::FUNCTION) => {
             // TODO complain about temporary
             let function_span = parser.consume_keyword(Keyword::FUNCTION)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let function = parse_qualified_name(parser)?;
             Ok(Statement::DropFunction(DropFunction {
                 drop_span,
@@ -432,11 +491,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
         Token::Ident(_, Keyword::INDEX) => {
             // DROP INDEX [IF EXISTS] index_name ON tbl_name
             let index_span = parser.consume_keyword(Keyword::INDEX)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let index_name = parser.consume_plain_identifier()?;
             let on_span = parser.consume_keyword(Keyword::ON)?;
             let table_name = parse_qualified_name(parser)?;
@@ -452,11 +507,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
         Token::Ident(_, Keyword::PROCEDURE) => {
             // TODO complain about temporary
             let procedure_span = parser.consume_keyword(Keyword::PROCEDURE)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let procedure = parse_qualified_name(parser)?;
             Ok(Statement::DropProcedure(DropProcedure {
                 drop_span,
@@ -472,11 +523,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
         Token::Ident(_, Keyword::SERVER) => {
             // TODO complain about temporary
             let server_span = parser.consume_keyword(Keyword::SERVER)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let server = parser.consume_plain_identifier()?;
             Ok(Statement::DropServer(DropServer {
                 drop_span,
@@ -487,11 +534,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
         }
         Token::Ident(_, Keyword::TRIGGER) => {
             let trigger_span = parser.consume_keyword(Keyword::TRIGGER)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let identifier = parse_qualified_name(parser)?;
             Ok(Statement::DropTrigger(DropTrigger {
                 drop_span,
@@ -502,11 +545,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
         }
         Token::Ident(_, Keyword::VIEW) => {
             let view_span = parser.consume_keyword(Keyword::VIEW)?;
-            let if_exists = if let Some(span) = parser.skip_keyword(Keyword::IF) {
-                Some(parser.consume_keyword(Keyword::EXISTS)?.join_span(&span))
-            } else {
-                None
-            };
+            let if_exists = parse_if_exists(parser)?;
             let mut views = Vec::new();
             loop {
                 views.push(parse_qualified_name(parser)?);
@@ -531,7 +570,7 @@ pub(crate) fn parse_drop<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropIndex<'a> {
     /// Span of "DROP"
     pub drop_span: Span,
@@ -554,3 +593,14 @@ impl<'a> Spanned for DropIndex<'a> {
             .join_span(&self.table_name)
     }
 }
+
+impl<'a> SpansMut for DropIndex<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.drop_span.spans_mut(f);
+        self.index_span.spans_mut(f);
+        self.if_exists.spans_mut(f);
+        self.index_name.spans_mut(f);
+        self.on_span.spans_mut(f);
+        self.table_name.spans_mut(f);
+    }
+}
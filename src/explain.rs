@@ -0,0 +1,245 @@
+use alloc::boxed::Box;
+
+use crate::{
+    keywords::Keyword,
+    lexer::Token,
+    parser::{ParseError, Parser},
+    qualified_name::parse_qualified_name,
+    statement::parse_statement,
+    Identifier, QualifiedName, SString, Span, Spanned, SpansMut, Statement,
+};
+
+/// Modifier after "EXPLAIN"
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExplainModifier {
+    /// Span of "EXTENDED"
+    Extended(Span),
+    /// Span of "PARTITIONS"
+    Partitions(Span),
+    /// Span of "ANALYZE"
+    Analyze(Span),
+}
+
+impl Spanned for ExplainModifier {
+    fn span(&self) -> Span {
+        match &self {
+            ExplainModifier::Extended(v) => v.span(),
+            ExplainModifier::Partitions(v) => v.span(),
+            ExplainModifier::Analyze(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for ExplainModifier {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            ExplainModifier::Extended(v) => v.spans_mut(f),
+            ExplainModifier::Partitions(v) => v.spans_mut(f),
+            ExplainModifier::Analyze(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Represent an explain statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Explain, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "EXPLAIN FORMAT=JSON SELECT `a` FROM `t1`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let explain: Explain = match stmts.pop() {
+///     Some(Statement::Explain(e)) => e,
+///     _ => panic!("We should get an explain statement")
+/// };
+///
+/// assert!(explain.format.unwrap().1.as_str() == "JSON");
+/// assert!(matches!(*explain.inner, Statement::Select(_)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explain<'a> {
+    /// Span of "EXPLAIN", "DESCRIBE" or "DESC"
+    pub explain_span: Span,
+    /// "EXTENDED", "PARTITIONS" or "ANALYZE" if specified
+    pub modifier: Option<ExplainModifier>,
+    /// Span of "FORMAT =" and the output format if specified
+    pub format: Option<(Span, Identifier<'a>)>,
+    /// The statement to explain
+    pub inner: Box<Statement<'a>>,
+}
+
+impl<'a> Spanned for Explain<'a> {
+    fn span(&self) -> Span {
+        self.explain_span
+            .join_span(&self.modifier)
+            .join_span(&self.format)
+            .join_span(&self.inner)
+    }
+}
+
+impl<'a> SpansMut for Explain<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.explain_span.spans_mut(f);
+        self.modifier.spans_mut(f);
+        self.format.spans_mut(f);
+        self.inner.spans_mut(f);
+    }
+}
+
+/// Columns to show in a describe statement
+#[derive(Clone, Debug, PartialEq)]
+pub enum DescribeColumn<'a> {
+    /// Name of a single column
+    Name(Identifier<'a>),
+    /// Pattern with "%" and "_" wildcards matching column names
+    Pattern(SString<'a>),
+}
+
+impl<'a> Spanned for DescribeColumn<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            DescribeColumn::Name(v) => v.span(),
+            DescribeColumn::Pattern(v) => v.span(),
+        }
+    }
+}
+
+impl<'a> SpansMut for DescribeColumn<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            DescribeColumn::Name(v) => v.spans_mut(f),
+            DescribeColumn::Pattern(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Represent a describe statement, showing the columns of a table
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Describe, DescribeColumn, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "DESCRIBE `db`.`t1` `a`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let describe: Describe = match stmts.pop() {
+///     Some(Statement::Describe(d)) => d,
+///     _ => panic!("We should get a describe statement")
+/// };
+///
+/// assert!(describe.table.identifier.as_str() == "t1");
+/// assert!(matches!(describe.column, Some(DescribeColumn::Name(c)) if c.as_str() == "a"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Describe<'a> {
+    /// Span of "DESCRIBE", "DESC" or "EXPLAIN"
+    pub describe_span: Span,
+    /// Table to describe
+    pub table: QualifiedName<'a>,
+    /// Only describe this column, or the columns matching a pattern, if specified
+    pub column: Option<DescribeColumn<'a>>,
+}
+
+impl<'a> Spanned for Describe<'a> {
+    fn span(&self) -> Span {
+        self.describe_span
+            .join_span(&self.table)
+            .join_span(&self.column)
+    }
+}
+
+impl<'a> SpansMut for Describe<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.describe_span.spans_mut(f);
+        self.table.spans_mut(f);
+        self.column.spans_mut(f);
+    }
+}
+
+/// Can the token start a statement that is explained, rather than follow a table name
+fn starts_explainable(token: &Token<'_>) -> bool {
+    matches!(
+        token,
+        Token::LParen
+            | Token::Ident(
+                _,
+                Keyword::SELECT
+                    | Keyword::WITH
+                    | Keyword::INSERT
+                    | Keyword::REPLACE
+                    | Keyword::UPDATE
+                    | Keyword::DELETE
+                    | Keyword::TABLE
+                    | Keyword::VALUES
+                    | Keyword::FORMAT
+            )
+    )
+}
+
+pub(crate) fn parse_explain<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
+    let explain_span = match &parser.token {
+        Token::Ident(_, Keyword::EXPLAIN | Keyword::DESCRIBE | Keyword::DESC) => parser.consume(),
+        _ => parser.expected_failure("'EXPLAIN' or 'DESCRIBE'")?,
+    };
+
+    // A modifier is only taken as such when a statement follows it, as it
+    // may also name a table to describe
+    let modifier = if starts_explainable(&parser.peek_n(1)) {
+        match &parser.token {
+            Token::Ident(_, Keyword::EXTENDED) => Some(ExplainModifier::Extended(parser.consume())),
+            Token::Ident(_, Keyword::PARTITIONS) => {
+                Some(ExplainModifier::Partitions(parser.consume()))
+            }
+            Token::Ident(_, Keyword::ANALYZE) => Some(ExplainModifier::Analyze(parser.consume())),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    // A name rather than a statement means the columns of a table are described
+    let describe = modifier.is_none()
+        && match &parser.token {
+            Token::Ident(_, Keyword::FORMAT) => parser.peek_n(1) != Token::Eq,
+            Token::Ident(_, k) => *k == Keyword::QUOTED_IDENTIFIER || !k.reserved(),
+            _ => false,
+        };
+    if describe {
+        let table = parse_qualified_name(parser)?;
+        let column = match &parser.token {
+            Token::Ident(_, _) => Some(DescribeColumn::Name(parser.consume_plain_identifier()?)),
+            Token::SingleQuotedString(_) | Token::DoubleQuotedString(_) => {
+                Some(DescribeColumn::Pattern(parser.consume_string()?))
+            }
+            _ => None,
+        };
+        return Ok(Statement::Describe(Describe {
+            describe_span: explain_span,
+            table,
+            column,
+        }));
+    }
+
+    let format = match parser.skip_keyword(Keyword::FORMAT) {
+        Some(format_span) => {
+            let span = parser.consume_token(Token::Eq)?.join_span(&format_span);
+            Some((span, parser.consume_plain_identifier()?))
+        }
+        None => None,
+    };
+    let inner = match parse_statement(parser)? {
+        Some(v) => Box::new(v),
+        None => parser.expected_failure("statement")?,
+    };
+    Ok(Statement::Explain(Explain {
+        explain_span,
+        modifier,
+        format,
+        inner,
+    }))
+}
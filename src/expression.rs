@@ -11,21 +11,21 @@
 // limitations under the License.
 
 use crate::{
-    data_type::parse_data_type,
+    data_type::{parse_data_type, parse_data_type_without_properties},
     keywords::Keyword,
     lexer::Token,
     parser::{ParseError, Parser},
-    select::{parse_select, OrderFlag},
+    select::{parse_order_by, parse_select, NullsOrder, OrderFlag, OrderKey},
     span::OptSpanned,
     statement::parse_compound_query,
-    DataType, Identifier, SString, Span, Spanned, Statement,
+    DataType, Identifier, QualifiedName, SQLDialect, SString, Span, Spanned, SpansMut, Statement,
 };
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::{boxed::Box, vec::Vec};
 
 /// Function to execute
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Function<'a> {
     Abs,
     Acos,
@@ -36,17 +36,23 @@ pub enum Function<'a> {
     Asin,
     Atan,
     Atan2,
+    Avg,
     Bin,
+    BitAnd,
     BitLength,
+    BitOr,
+    BitXor,
     Ceil,
     CharacterLength,
     Chr,
+    Coalesce,
     Concat,
     ConcatWs,
     Conv,
     ConvertTs,
     Cos,
     Cot,
+    Count,
     Crc32,
     Crc32c,
     CurDate,
@@ -167,6 +173,10 @@ pub enum Function<'a> {
     SoundEx,
     Space,
     Sqrt,
+    Std,
+    StdDev,
+    StdDevPop,
+    StdDevSamp,
     StrCmp,
     StrToDate,
     SubDate,
@@ -198,23 +208,227 @@ pub enum Function<'a> {
     UtcTime,
     UtcTimeStamp,
     Value,
+    Variance,
+    VarPop,
+    VarSamp,
     Week,
     Weekday,
     WeekOfYear,
     Lead,
     Lag,
+    /// Unrecognized unqualified function, typically a stored function
     Other(&'a str),
+    /// Stored function called with a schema qualified name "db.func(...)"
+    Qualified(QualifiedName<'a>),
+}
+
+impl<'a> SpansMut for Function<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Function::Qualified(v) => v.spans_mut(f),
+            Function::Other(_)
+            | Function::Abs
+            | Function::Acos
+            | Function::AddDate
+            | Function::AddMonths
+            | Function::AddTime
+            | Function::Ascii
+            | Function::Asin
+            | Function::Atan
+            | Function::Atan2
+            | Function::Avg
+            | Function::Bin
+            | Function::BitAnd
+            | Function::BitLength
+            | Function::BitOr
+            | Function::BitXor
+            | Function::Ceil
+            | Function::CharacterLength
+            | Function::Chr
+            | Function::Coalesce
+            | Function::Concat
+            | Function::ConcatWs
+            | Function::Conv
+            | Function::ConvertTs
+            | Function::Cos
+            | Function::Cot
+            | Function::Count
+            | Function::Crc32
+            | Function::Crc32c
+            | Function::CurDate
+            | Function::CurrentTimestamp
+            | Function::CurTime
+            | Function::Date
+            | Function::DateAdd
+            | Function::DateDiff
+            | Function::DateFormat
+            | Function::DateSub
+            | Function::DayName
+            | Function::DayOfMonth
+            | Function::DayOfWeek
+            | Function::DayOfYear
+            | Function::Degrees
+            | Function::Elt
+            | Function::Exists
+            | Function::Exp
+            | Function::ExportSet
+            | Function::ExtractValue
+            | Function::Field
+            | Function::FindInSet
+            | Function::Floor
+            | Function::Format
+            | Function::FromBase64
+            | Function::FromDays
+            | Function::FromUnixTime
+            | Function::Greatest
+            | Function::Hex
+            | Function::If
+            | Function::IfNull
+            | Function::Insert
+            | Function::InStr
+            | Function::JsonArray
+            | Function::JsonArrayAgg
+            | Function::JsonArrayAppend
+            | Function::JsonArrayInsert
+            | Function::JsonCompact
+            | Function::JsonContains
+            | Function::JsonContainsPath
+            | Function::JsonDepth
+            | Function::JsonDetailed
+            | Function::JsonEquals
+            | Function::JsonExists
+            | Function::JsonExtract
+            | Function::JsonInsert
+            | Function::JsonKeys
+            | Function::JsonLength
+            | Function::JsonLoose
+            | Function::JsonMerge
+            | Function::JsonMergePath
+            | Function::JsonMergePerserve
+            | Function::JsonNormalize
+            | Function::JsonObject
+            | Function::JsonObjectAgg
+            | Function::JsonQoute
+            | Function::JsonQuery
+            | Function::JsonRemove
+            | Function::JsonReplace
+            | Function::JsonSearch
+            | Function::JsonSet
+            | Function::JsonTable
+            | Function::JsonType
+            | Function::JsonUnquote
+            | Function::JsonValid
+            | Function::JsonValue
+            | Function::LCase
+            | Function::Least
+            | Function::Left
+            | Function::Length
+            | Function::LengthB
+            | Function::Ln
+            | Function::LoadFile
+            | Function::Locate
+            | Function::Log
+            | Function::Log10
+            | Function::Log2
+            | Function::Lower
+            | Function::LPad
+            | Function::LTrim
+            | Function::MakeDate
+            | Function::MakeSet
+            | Function::MakeTime
+            | Function::Max
+            | Function::MicroSecond
+            | Function::Mid
+            | Function::Min
+            | Function::Minute
+            | Function::MonthName
+            | Function::NaturalSortkey
+            | Function::Now
+            | Function::NullIf
+            | Function::NVL2
+            | Function::Oct
+            | Function::OctetLength
+            | Function::Ord
+            | Function::PeriodAdd
+            | Function::PeriodDiff
+            | Function::Pi
+            | Function::Position
+            | Function::Pow
+            | Function::Quarter
+            | Function::Quote
+            | Function::Radians
+            | Function::Rand
+            | Function::Repeat
+            | Function::Replace
+            | Function::Reverse
+            | Function::Right
+            | Function::Round
+            | Function::RPad
+            | Function::RTrim
+            | Function::Second
+            | Function::SecToTime
+            | Function::SFormat
+            | Function::Sign
+            | Function::Sin
+            | Function::SoundEx
+            | Function::Space
+            | Function::Sqrt
+            | Function::Std
+            | Function::StdDev
+            | Function::StdDevPop
+            | Function::StdDevSamp
+            | Function::StrCmp
+            | Function::StrToDate
+            | Function::SubDate
+            | Function::SubStr
+            | Function::SubStringIndex
+            | Function::SubTime
+            | Function::Sum
+            | Function::Tan
+            | Function::Time
+            | Function::TimeDiff
+            | Function::TimeFormat
+            | Function::Timestamp
+            | Function::TimestampAdd
+            | Function::TimestampDiff
+            | Function::TimeToSec
+            | Function::ToBase64
+            | Function::ToChar
+            | Function::ToDays
+            | Function::ToSeconds
+            | Function::Truncate
+            | Function::UCase
+            | Function::UncompressedLength
+            | Function::UnHex
+            | Function::UnixTimestamp
+            | Function::Unknown
+            | Function::UpdateXml
+            | Function::Upper
+            | Function::UtcDate
+            | Function::UtcTime
+            | Function::UtcTimeStamp
+            | Function::Value
+            | Function::Variance
+            | Function::VarPop
+            | Function::VarSamp
+            | Function::Week
+            | Function::Weekday
+            | Function::WeekOfYear
+            | Function::Lead
+            | Function::Lag => (),
+        }
+    }
 }
 
 /// Function to execute
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Variable<'a> {
     TimeZone,
     Other(&'a str),
 }
 
 /// Binary operator to apply
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BinaryOperator {
     Or,
     Xor,
@@ -241,8 +455,12 @@ pub enum BinaryOperator {
     NotLike,
 }
 
+impl SpansMut for BinaryOperator {
+    fn spans_mut(&mut self, _: &mut dyn FnMut(&mut Span)) {}
+}
+
 /// Type of is expression
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Is {
     Null,
     NotNull,
@@ -254,20 +472,31 @@ pub enum Is {
     NotUnknown,
 }
 
+impl SpansMut for Is {
+    fn spans_mut(&mut self, _: &mut dyn FnMut(&mut Span)) {}
+}
+
 /// Unary operator to apply
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum UnaryOperator {
+    /// Prefix "BINARY" casting the operand to a binary string
     Binary,
-    Collate,
     LogicalNot,
     Minus,
+    Plus,
     Not,
 }
 
+impl SpansMut for UnaryOperator {
+    fn spans_mut(&mut self, _: &mut dyn FnMut(&mut Span)) {}
+}
+
 /// Part of a full identifier
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum IdentifierPart<'a> {
+    /// Named part
     Name(Identifier<'a>),
+    /// Span of the wildcard "*", as in "SELECT *" or "SELECT t.*"
     Star(Span),
 }
 
@@ -280,8 +509,132 @@ impl<'a> Spanned for IdentifierPart<'a> {
     }
 }
 
+impl<'a> SpansMut for IdentifierPart<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            IdentifierPart::Name(v) => v.spans_mut(f),
+            IdentifierPart::Star(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Which end of the string to remove characters from in TRIM
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrimDirection {
+    /// Span of "BOTH"
+    Both(Span),
+    /// Span of "LEADING"
+    Leading(Span),
+    /// Span of "TRAILING"
+    Trailing(Span),
+}
+
+impl Spanned for TrimDirection {
+    fn span(&self) -> Span {
+        match &self {
+            TrimDirection::Both(v) => v.span(),
+            TrimDirection::Leading(v) => v.span(),
+            TrimDirection::Trailing(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for TrimDirection {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TrimDirection::Both(v) => v.spans_mut(f),
+            TrimDirection::Leading(v) => v.spans_mut(f),
+            TrimDirection::Trailing(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Unit of an "INTERVAL" expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalUnit {
+    Microsecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    SecondMicrosecond,
+    MinuteMicrosecond,
+    MinuteSecond,
+    HourMicrosecond,
+    HourSecond,
+    HourMinute,
+    DayMicrosecond,
+    DaySecond,
+    DayMinute,
+    DayHour,
+    YearMonth,
+}
+
+impl SpansMut for IntervalUnit {
+    fn spans_mut(&mut self, _: &mut dyn FnMut(&mut Span)) {}
+}
+
+impl IntervalUnit {
+    /// The keyword naming the unit
+    pub fn name(&self) -> &'static str {
+        match self {
+            IntervalUnit::Microsecond => "MICROSECOND",
+            IntervalUnit::Second => "SECOND",
+            IntervalUnit::Minute => "MINUTE",
+            IntervalUnit::Hour => "HOUR",
+            IntervalUnit::Day => "DAY",
+            IntervalUnit::Week => "WEEK",
+            IntervalUnit::Month => "MONTH",
+            IntervalUnit::Quarter => "QUARTER",
+            IntervalUnit::Year => "YEAR",
+            IntervalUnit::SecondMicrosecond => "SECOND_MICROSECOND",
+            IntervalUnit::MinuteMicrosecond => "MINUTE_MICROSECOND",
+            IntervalUnit::MinuteSecond => "MINUTE_SECOND",
+            IntervalUnit::HourMicrosecond => "HOUR_MICROSECOND",
+            IntervalUnit::HourSecond => "HOUR_SECOND",
+            IntervalUnit::HourMinute => "HOUR_MINUTE",
+            IntervalUnit::DayMicrosecond => "DAY_MICROSECOND",
+            IntervalUnit::DaySecond => "DAY_SECOND",
+            IntervalUnit::DayMinute => "DAY_MINUTE",
+            IntervalUnit::DayHour => "DAY_HOUR",
+            IntervalUnit::YearMonth => "YEAR_MONTH",
+        }
+    }
+}
+
+fn parse_interval_unit(parser: &mut Parser<'_, '_>) -> Result<(IntervalUnit, Span), ParseError> {
+    let unit = match &parser.token {
+        Token::Ident(_, Keyword::MICROSECOND) => IntervalUnit::Microsecond,
+        Token::Ident(_, Keyword::SECOND) => IntervalUnit::Second,
+        Token::Ident(_, Keyword::MINUTE) => IntervalUnit::Minute,
+        Token::Ident(_, Keyword::HOUR) => IntervalUnit::Hour,
+        Token::Ident(_, Keyword::DAY) => IntervalUnit::Day,
+        Token::Ident(_, Keyword::WEEK) => IntervalUnit::Week,
+        Token::Ident(_, Keyword::MONTH) => IntervalUnit::Month,
+        Token::Ident(_, Keyword::QUARTER) => IntervalUnit::Quarter,
+        Token::Ident(_, Keyword::YEAR) => IntervalUnit::Year,
+        Token::Ident(_, Keyword::SECOND_MICROSECOND) => IntervalUnit::SecondMicrosecond,
+        Token::Ident(_, Keyword::MINUTE_MICROSECOND) => IntervalUnit::MinuteMicrosecond,
+        Token::Ident(_, Keyword::MINUTE_SECOND) => IntervalUnit::MinuteSecond,
+        Token::Ident(_, Keyword::HOUR_MICROSECOND) => IntervalUnit::HourMicrosecond,
+        Token::Ident(_, Keyword::HOUR_SECOND) => IntervalUnit::HourSecond,
+        Token::Ident(_, Keyword::HOUR_MINUTE) => IntervalUnit::HourMinute,
+        Token::Ident(_, Keyword::DAY_MICROSECOND) => IntervalUnit::DayMicrosecond,
+        Token::Ident(_, Keyword::DAY_SECOND) => IntervalUnit::DaySecond,
+        Token::Ident(_, Keyword::DAY_MINUTE) => IntervalUnit::DayMinute,
+        Token::Ident(_, Keyword::DAY_HOUR) => IntervalUnit::DayHour,
+        Token::Ident(_, Keyword::YEAR_MONTH) => IntervalUnit::YearMonth,
+        _ => parser.expected_failure("interval unit")?,
+    };
+    Ok((unit, parser.consume()))
+}
+
 /// When part of CASE
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct When<'a> {
     /// Span of WHEN
     pub when_span: Span,
@@ -302,11 +655,20 @@ impl<'a> Spanned for When<'a> {
     }
 }
 
+impl<'a> SpansMut for When<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.when_span.spans_mut(f);
+        self.when.spans_mut(f);
+        self.then_span.spans_mut(f);
+        self.then.spans_mut(f);
+    }
+}
+
 /// When part of CASE
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct WindowSpec<'a> {
     /// Span of "ORDER BY" and list of order expression and directions, if specified
-    pub order_by: (Span, Vec<(Expression<'a>, OrderFlag)>),
+    pub order_by: (Span, Vec<OrderKey<'a>>),
 }
 
 impl<'a> Spanned for WindowSpec<'a> {
@@ -315,8 +677,154 @@ impl<'a> Spanned for WindowSpec<'a> {
     }
 }
 
+impl<'a> SpansMut for WindowSpec<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.order_by.spans_mut(f);
+    }
+}
+
+/// Search modifier of a full-text "MATCH (...) AGAINST (...)" expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchModifier {
+    /// Span of "IN NATURAL LANGUAGE MODE"
+    NaturalLanguageMode(Span),
+    /// Span of "IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION"
+    NaturalLanguageModeWithQueryExpansion(Span),
+    /// Span of "IN BOOLEAN MODE"
+    BooleanMode(Span),
+    /// Span of "WITH QUERY EXPANSION"
+    WithQueryExpansion(Span),
+}
+
+impl Spanned for MatchModifier {
+    fn span(&self) -> Span {
+        match &self {
+            MatchModifier::NaturalLanguageMode(v) => v.span(),
+            MatchModifier::NaturalLanguageModeWithQueryExpansion(v) => v.span(),
+            MatchModifier::BooleanMode(v) => v.span(),
+            MatchModifier::WithQueryExpansion(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for MatchModifier {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            MatchModifier::NaturalLanguageMode(v) => v.spans_mut(f),
+            MatchModifier::NaturalLanguageModeWithQueryExpansion(v) => v.spans_mut(f),
+            MatchModifier::BooleanMode(v) => v.spans_mut(f),
+            MatchModifier::WithQueryExpansion(v) => v.spans_mut(f),
+        }
+    }
+}
+
+fn parse_match_modifier(parser: &mut Parser<'_, '_>) -> Result<Option<MatchModifier>, ParseError> {
+    match &parser.token {
+        Token::Ident(_, Keyword::IN) => {
+            let in_span = parser.consume_keyword(Keyword::IN)?;
+            match &parser.token {
+                Token::Ident(_, Keyword::BOOLEAN) => Ok(Some(MatchModifier::BooleanMode(
+                    parser
+                        .consume_keywords(&[Keyword::BOOLEAN, Keyword::MODE])?
+                        .join_span(&in_span),
+                ))),
+                Token::Ident(_, Keyword::NATURAL) => {
+                    let span = parser
+                        .consume_keywords(&[Keyword::NATURAL, Keyword::LANGUAGE, Keyword::MODE])?
+                        .join_span(&in_span);
+                    match parser.skip_keyword(Keyword::WITH) {
+                        Some(with_span) => {
+                            Ok(Some(MatchModifier::NaturalLanguageModeWithQueryExpansion(
+                                parser
+                                    .consume_keywords(&[Keyword::QUERY, Keyword::EXPANSION])?
+                                    .join_span(&with_span)
+                                    .join_span(&span),
+                            )))
+                        }
+                        None => Ok(Some(MatchModifier::NaturalLanguageMode(span))),
+                    }
+                }
+                _ => parser.expected_failure("'BOOLEAN' or 'NATURAL'"),
+            }
+        }
+        Token::Ident(_, Keyword::WITH) => Ok(Some(MatchModifier::WithQueryExpansion(
+            parser.consume_keywords(&[Keyword::WITH, Keyword::QUERY, Keyword::EXPANSION])?,
+        ))),
+        _ => Ok(None),
+    }
+}
+
+/// Modifier given before the arguments of an aggregate function
+#[derive(Debug, Clone, PartialEq)]
+pub enum AggregateModifier {
+    /// Span of "DISTINCT"
+    Distinct(Span),
+    /// Span of "ALL"
+    All(Span),
+}
+
+impl Spanned for AggregateModifier {
+    fn span(&self) -> Span {
+        match &self {
+            AggregateModifier::Distinct(v) => v.span(),
+            AggregateModifier::All(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for AggregateModifier {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            AggregateModifier::Distinct(v) => v.spans_mut(f),
+            AggregateModifier::All(v) => v.spans_mut(f),
+        }
+    }
+}
+
+fn parse_aggregate_modifier(parser: &mut Parser<'_, '_>) -> Option<AggregateModifier> {
+    match &parser.token {
+        Token::Ident(_, Keyword::DISTINCT) => Some(AggregateModifier::Distinct(parser.consume())),
+        Token::Ident(_, Keyword::ALL) => Some(AggregateModifier::All(parser.consume())),
+        _ => None,
+    }
+}
+
+/// Response of "JSON_VALUE" when the path matches nothing or on error
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonOnResponse<'a> {
+    /// Span of "NULL"
+    Null(Span),
+    /// Span of "ERROR"
+    Error(Span),
+    /// Span of "DEFAULT" and the value to return
+    Default(Span, Box<Expression<'a>>),
+}
+
+impl<'a> Spanned for JsonOnResponse<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            JsonOnResponse::Null(v) => v.span(),
+            JsonOnResponse::Error(v) => v.span(),
+            JsonOnResponse::Default(s, v) => s.join_span(v),
+        }
+    }
+}
+
+impl<'a> SpansMut for JsonOnResponse<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            JsonOnResponse::Null(v) => v.spans_mut(f),
+            JsonOnResponse::Error(v) => v.spans_mut(f),
+            JsonOnResponse::Default(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+        }
+    }
+}
+
 /// Representation of an expression
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Expression<'a> {
     /// Expression with binary operator
     Binary {
@@ -342,8 +850,11 @@ pub enum Expression<'a> {
     Subquery(Box<Statement<'a>>),
     /// Literal NULL expression
     Null(Span),
-    /// Literal bool expression "TRUE" or "FALSE"
-    Bool(bool, Span),
+    /// Literal bool expression "TRUE", "FALSE" or "UNKNOWN", where "UNKNOWN" is None
+    ///
+    /// UNKNOWN is not reserved, so outside of "IS [NOT] UNKNOWN", which is
+    /// parsed as [Expression::Is], it is read as a column name
+    Bool(Option<bool>, Span),
     /// Literal string expression, the SString contains the represented string
     /// with escaping removed
     String(SString<'a>),
@@ -355,6 +866,15 @@ pub enum Expression<'a> {
     Float((f64, Span)),
     /// Function call expression,
     Function(Function<'a>, Vec<Expression<'a>>, Span),
+    /// Aggregate function call expression such as "SUM(DISTINCT a)"
+    AggregateFunction {
+        function: Function<'a>,
+        /// Span of the function name
+        function_span: Span,
+        /// "DISTINCT" or "ALL" if specified
+        modifier: Option<AggregateModifier>,
+        args: Vec<Expression<'a>>,
+    },
     /// A window function call expression
     WindowFunction {
         function: Function<'a>,
@@ -363,7 +883,8 @@ pub enum Expression<'a> {
         over_span: Span,
         window_spec: WindowSpec<'a>,
     },
-    /// Identifier pointing to column
+    /// Identifier pointing to column, "col", "table.col" or "db.table.col",
+    /// where the last part may be "*"
     Identifier(Vec<IdentifierPart<'a>>),
     /// Input argument to query, the first argument is the occurrence number of the argumnet
     Arg((usize, Span)),
@@ -408,12 +929,25 @@ pub enum Expression<'a> {
         /// Type to cast to
         type_: DataType<'a>,
     },
+    /// Trim expression "TRIM([BOTH | LEADING | TRAILING] [remove] FROM expr)"
+    Trim {
+        /// Span of "TRIM"
+        trim_span: Span,
+        /// Which end to trim if specified
+        direction: Option<TrimDirection>,
+        /// The characters to remove if specified, defaults to space
+        remove: Option<Box<Expression<'a>>>,
+        /// Span of "FROM" if specified
+        from_span: Option<Span>,
+        /// The string to trim
+        expr: Box<Expression<'a>>,
+    },
     /// Count expression
     Count {
         /// Span of "COUNT"
         count_span: Span,
-        /// Span of "DISTINCT" if specified
-        distinct_span: Option<Span>,
+        /// "DISTINCT" or "ALL" if specified
+        modifier: Option<AggregateModifier>,
         /// Expression to count
         expr: Box<Expression<'a>>,
     },
@@ -425,6 +959,10 @@ pub enum Expression<'a> {
         distinct_span: Option<Span>,
         /// Expression to count
         expr: Box<Expression<'a>>,
+        /// Span of "ORDER BY" and list of order expression and directions, if specified
+        order_by: Option<(Span, Vec<OrderKey<'a>>)>,
+        /// Span of "SEPARATOR" and the separator string, if specified
+        separator: Option<(Span, SString<'a>)>,
     },
     /// Variable expression
     Variable {
@@ -439,6 +977,94 @@ pub enum Expression<'a> {
         // Span of variable
         variable_span: Span,
     },
+    /// Collate expression "expr COLLATE collation"
+    Collate {
+        /// Expression to apply the collation to
+        expr: Box<Expression<'a>>,
+        /// Span of "COLLATE"
+        collate_span: Span,
+        /// Name of the collation
+        collation: Identifier<'a>,
+    },
+    /// JSON extraction "target->'path'" or unquoted extraction "target->>'path'"
+    JsonExtract {
+        /// Span of "->" or "->>"
+        arrow_span: Span,
+        /// True for "->>" where the extracted value is unquoted
+        unquote: bool,
+        /// Expression to extract from, typically a column
+        target: Box<Expression<'a>>,
+        /// The JSON path to extract
+        path: SString<'a>,
+    },
+    /// String literal with a character set introducer "_charset'value'"
+    CharsetString {
+        /// Name of the character set, without the leading underscore
+        charset: Identifier<'a>,
+        /// The string literal
+        value: SString<'a>,
+    },
+    /// Character set conversion "CONVERT(expr USING charset)"
+    Convert {
+        /// Span of "CONVERT"
+        convert_span: Span,
+        /// Expression to convert
+        expr: Box<Expression<'a>>,
+        /// Span of "USING"
+        using_span: Span,
+        /// Character set to convert to
+        charset: Identifier<'a>,
+    },
+    /// Interval expression "INTERVAL expr unit" as used in date arithmetic
+    Interval {
+        /// Span of "INTERVAL"
+        interval_span: Span,
+        /// Number of units
+        expr: Box<Expression<'a>>,
+        /// Unit and its span
+        unit: (IntervalUnit, Span),
+    },
+    /// JSON value extraction
+    /// "JSON_VALUE(doc, path [RETURNING type] [response ON EMPTY] [response ON ERROR])",
+    /// or the same form of "JSON_QUERY"
+    JsonValue {
+        /// True for "JSON_QUERY"
+        query: bool,
+        /// Span of "JSON_VALUE" or "JSON_QUERY"
+        function_span: Span,
+        /// The JSON document
+        doc: Box<Expression<'a>>,
+        /// The JSON path to extract
+        path: Box<Expression<'a>>,
+        /// Span of "RETURNING" and the type to return, if specified
+        returning: Option<(Span, Box<DataType<'a>>)>,
+        /// Response when the path matches nothing and span of "ON EMPTY", if specified
+        on_empty: Option<(JsonOnResponse<'a>, Span)>,
+        /// Response on error and span of "ON ERROR", if specified
+        on_error: Option<(JsonOnResponse<'a>, Span)>,
+    },
+    /// Full-text search "MATCH (col, ...) AGAINST (expr [modifier])"
+    Match {
+        /// Span of "MATCH"
+        match_span: Span,
+        /// Columns to search
+        columns: Vec<Expression<'a>>,
+        /// Span of "AGAINST"
+        against_span: Span,
+        /// The search string
+        against: Box<Expression<'a>>,
+        /// Search modifier if specified
+        modifier: Option<MatchModifier>,
+    },
+    /// Row constructor "(a, b)" or "ROW(a, b)"
+    Row {
+        /// Span of "ROW" if specified
+        row_span: Option<Span>,
+        /// Span from "(" to ")"
+        paren_span: Span,
+        /// Values of the row, at least two unless "ROW" is specified
+        values: Vec<Expression<'a>>,
+    },
 }
 
 impl<'a> Spanned for Expression<'a> {
@@ -458,6 +1084,26 @@ impl<'a> Spanned for Expression<'a> {
             Expression::Float(v) => v.span(),
             Expression::ListHack((_, s)) => s.span(),
             Expression::Function(_, b, c) => c.join_span(b),
+            Expression::AggregateFunction {
+                function_span,
+                modifier,
+                args,
+                ..
+            } => function_span.join_span(modifier).join_span(args),
+            Expression::JsonValue {
+                function_span,
+                doc,
+                path,
+                returning,
+                on_empty,
+                on_error,
+                ..
+            } => function_span
+                .join_span(doc)
+                .join_span(path)
+                .join_span(returning)
+                .join_span(on_empty)
+                .join_span(on_error),
             Expression::Identifier(v) => v.opt_span().expect("Span of identifier parts"),
             Expression::Arg(v) => v.span(),
             Expression::Exists(v) => v.span(),
@@ -486,16 +1132,33 @@ impl<'a> Spanned for Expression<'a> {
                 .join_span(expr)
                 .join_span(as_span)
                 .join_span(type_),
+            Expression::Trim {
+                trim_span,
+                direction,
+                remove,
+                from_span,
+                expr,
+            } => trim_span
+                .join_span(direction)
+                .join_span(remove)
+                .join_span(from_span)
+                .join_span(expr),
             Expression::Count {
                 count_span,
-                distinct_span,
+                modifier,
                 expr,
-            } => count_span.join_span(distinct_span).join_span(expr),
+            } => count_span.join_span(modifier).join_span(expr),
             Expression::GroupConcat {
                 group_concat_span,
                 distinct_span,
                 expr,
-            } => group_concat_span.join_span(distinct_span).join_span(expr),
+                order_by,
+                separator,
+            } => group_concat_span
+                .join_span(distinct_span)
+                .join_span(expr)
+                .join_span(order_by)
+                .join_span(separator),
             Expression::Variable {
                 global,
                 session,
@@ -516,16 +1179,346 @@ impl<'a> Spanned for Expression<'a> {
                 .join_span(args)
                 .join_span(over_span)
                 .join_span(window_spec),
+            Expression::Collate {
+                expr,
+                collate_span,
+                collation,
+            } => collate_span.join_span(expr).join_span(collation),
+            Expression::JsonExtract {
+                arrow_span,
+                target,
+                path,
+                ..
+            } => arrow_span.join_span(target).join_span(path),
+            Expression::CharsetString { charset, value } => charset.join_span(value),
+            Expression::Convert {
+                convert_span,
+                expr,
+                using_span,
+                charset,
+            } => convert_span
+                .join_span(expr)
+                .join_span(using_span)
+                .join_span(charset),
+            Expression::Interval {
+                interval_span,
+                expr,
+                unit,
+            } => interval_span.join_span(expr).join_span(&unit.1),
+            Expression::Match {
+                match_span,
+                columns,
+                against_span,
+                against,
+                modifier,
+            } => match_span
+                .join_span(columns)
+                .join_span(against_span)
+                .join_span(against)
+                .join_span(modifier),
+            Expression::Row {
+                row_span,
+                paren_span,
+                values,
+            } => paren_span.join_span(row_span).join_span(values),
         }
     }
 }
 
+impl<'a> SpansMut for Expression<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Expression::Binary {
+                op,
+                op_span,
+                lhs,
+                rhs,
+            } => {
+                op.spans_mut(f);
+                op_span.spans_mut(f);
+                lhs.spans_mut(f);
+                rhs.spans_mut(f);
+            }
+            Expression::Unary {
+                op,
+                op_span,
+                operand,
+            } => {
+                op.spans_mut(f);
+                op_span.spans_mut(f);
+                operand.spans_mut(f);
+            }
+            Expression::Subquery(v) => v.spans_mut(f),
+            Expression::Null(v) => v.spans_mut(f),
+            Expression::Bool(_, v) => v.spans_mut(f),
+            Expression::String(v) => v.spans_mut(f),
+            Expression::Integer(v) => v.spans_mut(f),
+            Expression::ListHack(v) => v.spans_mut(f),
+            Expression::Float(v) => v.spans_mut(f),
+            Expression::Function(v0, v1, v2) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+                v2.spans_mut(f);
+            }
+            Expression::AggregateFunction {
+                function,
+                function_span,
+                modifier,
+                args,
+            } => {
+                function.spans_mut(f);
+                function_span.spans_mut(f);
+                modifier.spans_mut(f);
+                args.spans_mut(f);
+            }
+            Expression::WindowFunction {
+                function,
+                args,
+                function_span,
+                over_span,
+                window_spec,
+            } => {
+                function.spans_mut(f);
+                args.spans_mut(f);
+                function_span.spans_mut(f);
+                over_span.spans_mut(f);
+                window_spec.spans_mut(f);
+            }
+            Expression::Identifier(v) => v.spans_mut(f),
+            Expression::Arg(v) => v.spans_mut(f),
+            Expression::Exists(v) => v.spans_mut(f),
+            Expression::In {
+                lhs,
+                rhs,
+                in_span,
+                not_in: _,
+            } => {
+                lhs.spans_mut(f);
+                rhs.spans_mut(f);
+                in_span.spans_mut(f);
+            }
+            Expression::Is(v0, v1, v2) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+                v2.spans_mut(f);
+            }
+            Expression::Invalid(v) => v.spans_mut(f),
+            Expression::Case {
+                case_span,
+                value,
+                whens,
+                else_,
+                end_span,
+            } => {
+                case_span.spans_mut(f);
+                value.spans_mut(f);
+                whens.spans_mut(f);
+                else_.spans_mut(f);
+                end_span.spans_mut(f);
+            }
+            Expression::Cast {
+                cast_span,
+                expr,
+                as_span,
+                type_,
+            } => {
+                cast_span.spans_mut(f);
+                expr.spans_mut(f);
+                as_span.spans_mut(f);
+                type_.spans_mut(f);
+            }
+            Expression::Trim {
+                trim_span,
+                direction,
+                remove,
+                from_span,
+                expr,
+            } => {
+                trim_span.spans_mut(f);
+                direction.spans_mut(f);
+                remove.spans_mut(f);
+                from_span.spans_mut(f);
+                expr.spans_mut(f);
+            }
+            Expression::Count {
+                count_span,
+                modifier,
+                expr,
+            } => {
+                count_span.spans_mut(f);
+                modifier.spans_mut(f);
+                expr.spans_mut(f);
+            }
+            Expression::GroupConcat {
+                group_concat_span,
+                distinct_span,
+                expr,
+                order_by,
+                separator,
+            } => {
+                group_concat_span.spans_mut(f);
+                distinct_span.spans_mut(f);
+                expr.spans_mut(f);
+                order_by.spans_mut(f);
+                separator.spans_mut(f);
+            }
+            Expression::Variable {
+                global,
+                session,
+                dot,
+                variable: _,
+                variable_span,
+            } => {
+                global.spans_mut(f);
+                session.spans_mut(f);
+                dot.spans_mut(f);
+                variable_span.spans_mut(f);
+            }
+            Expression::Collate {
+                expr,
+                collate_span,
+                collation,
+            } => {
+                expr.spans_mut(f);
+                collate_span.spans_mut(f);
+                collation.spans_mut(f);
+            }
+            Expression::JsonExtract {
+                arrow_span,
+                unquote: _,
+                target,
+                path,
+            } => {
+                arrow_span.spans_mut(f);
+                target.spans_mut(f);
+                path.spans_mut(f);
+            }
+            Expression::CharsetString { charset, value } => {
+                charset.spans_mut(f);
+                value.spans_mut(f);
+            }
+            Expression::Convert {
+                convert_span,
+                expr,
+                using_span,
+                charset,
+            } => {
+                convert_span.spans_mut(f);
+                expr.spans_mut(f);
+                using_span.spans_mut(f);
+                charset.spans_mut(f);
+            }
+            Expression::Interval {
+                interval_span,
+                expr,
+                unit,
+            } => {
+                interval_span.spans_mut(f);
+                expr.spans_mut(f);
+                unit.spans_mut(f);
+            }
+            Expression::JsonValue {
+                query: _,
+                function_span,
+                doc,
+                path,
+                returning,
+                on_empty,
+                on_error,
+            } => {
+                function_span.spans_mut(f);
+                doc.spans_mut(f);
+                path.spans_mut(f);
+                returning.spans_mut(f);
+                on_empty.spans_mut(f);
+                on_error.spans_mut(f);
+            }
+            Expression::Match {
+                match_span,
+                columns,
+                against_span,
+                against,
+                modifier,
+            } => {
+                match_span.spans_mut(f);
+                columns.spans_mut(f);
+                against_span.spans_mut(f);
+                against.spans_mut(f);
+                modifier.spans_mut(f);
+            }
+            Expression::Row {
+                row_span,
+                paren_span,
+                values,
+            } => {
+                row_span.spans_mut(f);
+                paren_span.spans_mut(f);
+                values.spans_mut(f);
+            }
+        }
+    }
+}
+
+impl<'a> Expression<'a> {
+    /// Fractional seconds precision of a current time function call such as
+    /// "CURRENT_TIMESTAMP", "NOW()" or "CURRENT_TIME(3)"
+    ///
+    /// A call without a precision, with or without "()", has precision 0
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement, DataTypeProperty, CreateDefinition};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let sql = "CREATE TABLE `t1` (
+    ///     `a` DATETIME(6) DEFAULT CURRENT_TIMESTAMP(6),
+    ///     `b` DATETIME DEFAULT NOW(),
+    ///     `c` DATETIME DEFAULT CURRENT_TIMESTAMP)";
+    /// let create = match parse_statement(sql, &mut issues, &options) {
+    ///     Some(Statement::CreateTable(c)) => c,
+    ///     _ => panic!("We should get an create table statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let precisions: Vec<_> = create.create_definitions.iter().map(|d| match d {
+    ///     CreateDefinition::ColumnDefinition { data_type, .. } => match &data_type.properties[0] {
+    ///         DataTypeProperty::Default(e) => e.time_precision(),
+    ///         _ => None,
+    ///     },
+    ///     _ => None,
+    /// }).collect();
+    /// assert_eq!(precisions, [Some(6), Some(0), Some(0)]);
+    /// ```
+    pub fn time_precision(&self) -> Option<u64> {
+        match self {
+            Expression::Function(
+                Function::Now | Function::CurTime | Function::CurrentTimestamp,
+                args,
+                _,
+            ) => match args.as_slice() {
+                [] => Some(0),
+                [Expression::Integer((v, _))] => Some(*v),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Character sets that may be used as an introducer "_charset'value'"
+fn is_charset_name(name: &str) -> bool {
+    const CHARSETS: &[&str] = &[
+        "armscii8", "ascii", "big5", "binary", "cp1250", "cp1251", "cp1256", "cp1257", "cp850",
+        "cp852", "cp866", "cp932", "dec8", "eucjpms", "euckr", "gb18030", "gb2312", "gbk",
+        "geostd8", "greek", "hebrew", "hp8", "keybcs2", "koi8r", "koi8u", "latin1", "latin2",
+        "latin5", "latin7", "macce", "macroman", "sjis", "swe7", "tis620", "ucs2", "ujis", "utf16",
+        "utf16le", "utf32", "utf8", "utf8mb3", "utf8mb4",
+    ];
+    CHARSETS.iter().any(|c| c.eq_ignore_ascii_case(name))
+}
+
 fn parse_function<'a>(
     parser: &mut Parser<'a, '_>,
     t: Token<'a>,
     span: Span,
 ) -> Result<Expression<'a>, ParseError> {
-    parser.consume_token(Token::LParen)?;
     let func = match &t {
         // https://mariadb.com/kb/en/string-functions/
         Token::Ident(_, Keyword::ASCII) => Function::Ascii,
@@ -588,6 +1581,17 @@ fn parse_function<'a>(
         Token::Ident(_, Keyword::MIN) => Function::Min,
         Token::Ident(_, Keyword::MAX) => Function::Max,
         Token::Ident(_, Keyword::SUM) => Function::Sum,
+        Token::Ident(_, Keyword::AVG) => Function::Avg,
+        Token::Ident(_, Keyword::BIT_AND) => Function::BitAnd,
+        Token::Ident(_, Keyword::BIT_OR) => Function::BitOr,
+        Token::Ident(_, Keyword::BIT_XOR) => Function::BitXor,
+        Token::Ident(_, Keyword::STD) => Function::Std,
+        Token::Ident(_, Keyword::STDDEV) => Function::StdDev,
+        Token::Ident(_, Keyword::STDDEV_POP) => Function::StdDevPop,
+        Token::Ident(_, Keyword::STDDEV_SAMP) => Function::StdDevSamp,
+        Token::Ident(_, Keyword::VARIANCE) => Function::Variance,
+        Token::Ident(_, Keyword::VAR_POP) => Function::VarPop,
+        Token::Ident(_, Keyword::VAR_SAMP) => Function::VarSamp,
         Token::Ident(_, Keyword::VALUE) => Function::Value,
         Token::Ident(_, Keyword::VALUES) => Function::Value,
         Token::Ident(_, Keyword::LEAD) => Function::Lead,
@@ -599,6 +1603,7 @@ fn parse_function<'a>(
         Token::Ident(_, Keyword::NVL) => Function::IfNull,
         Token::Ident(_, Keyword::NVL2) => Function::NVL2,
         Token::Ident(_, Keyword::IF) => Function::If,
+        Token::Ident(_, Keyword::COALESCE) => Function::Coalesce,
 
         //https://mariadb.com/kb/en/numeric-functions/
         Token::Ident(_, Keyword::ABS) => Function::Abs,
@@ -722,7 +1727,8 @@ fn parse_function<'a>(
         Token::Ident(_, Keyword::JSON_UNQUOTE) => Function::JsonUnquote,
         Token::Ident(_, Keyword::JSON_VALID) => Function::JsonValid,
         Token::Ident(_, Keyword::JSON_VALUE) => Function::JsonValue,
-        Token::Ident(v, k) if !k.reserved() => Function::Other(v),
+        // Any other keyword followed by "(" is taken to name a function
+        Token::Ident(v, _) => Function::Other(v),
         _ => {
             parser
                 .issues
@@ -730,7 +1736,37 @@ fn parse_function<'a>(
             Function::Unknown
         }
     };
+    parse_function_call(parser, func, span)
+}
 
+fn parse_function_call<'a>(
+    parser: &mut Parser<'a, '_>,
+    func: Function<'a>,
+    span: Span,
+) -> Result<Expression<'a>, ParseError> {
+    parser.consume_token(Token::LParen)?;
+    let aggregate = matches!(
+        func,
+        Function::Min
+            | Function::Max
+            | Function::Sum
+            | Function::Avg
+            | Function::BitAnd
+            | Function::BitOr
+            | Function::BitXor
+            | Function::Std
+            | Function::StdDev
+            | Function::StdDevPop
+            | Function::StdDevSamp
+            | Function::Variance
+            | Function::VarPop
+            | Function::VarSamp
+    );
+    let modifier = if aggregate {
+        parse_aggregate_modifier(parser)
+    } else {
+        None
+    };
     let mut args = Vec::new();
     if !matches!(parser.token, Token::RParen) {
         loop {
@@ -739,6 +1775,16 @@ fn parse_function<'a>(
                 &|t| matches!(t, Token::RParen | Token::Comma),
                 |parser| {
                     args.push(parse_expression_outer(parser)?);
+                    // SUBSTRING(str FROM pos [FOR len]) is the same as SUBSTRING(str, pos [, len])
+                    if matches!(func, Function::SubStr)
+                        && args.len() == 1
+                        && parser.skip_keyword(Keyword::FROM).is_some()
+                    {
+                        args.push(parse_expression_outer(parser)?);
+                        if parser.skip_keyword(Keyword::FOR).is_some() {
+                            args.push(parse_expression_outer(parser)?);
+                        }
+                    }
                     Ok(())
                 },
             )?;
@@ -749,38 +1795,123 @@ fn parse_function<'a>(
     }
     parser.consume_token(Token::RParen)?;
 
+    if matches!(
+        func,
+        Function::Now | Function::CurTime | Function::CurrentTimestamp
+    ) {
+        match args.as_slice() {
+            [] | [Expression::Arg(_)] => (),
+            [Expression::Integer((v, _))] if *v <= 6 => (),
+            [a, ..] => parser.issues.push(crate::Issue::err(
+                "Expected fractional seconds precision between 0 and 6",
+                a,
+            )),
+        }
+    }
+
     if let Some(over_span) = parser.skip_keyword(Keyword::OVER) {
-        parser.consume_token(Token::LParen)?;
-        let order_span = parser.consume_keywords(&[Keyword::ORDER, Keyword::BY])?;
-        let mut order = Vec::new();
-        loop {
-            let e = parse_expression(parser, false)?;
-            let f = match &parser.token {
-                Token::Ident(_, Keyword::ASC) => OrderFlag::Asc(parser.consume()),
-                Token::Ident(_, Keyword::DESC) => OrderFlag::Desc(parser.consume()),
-                _ => OrderFlag::None,
-            };
-            order.push((e, f));
-            if parser.skip_token(Token::Comma).is_none() {
-                break;
-            }
+        if let Some(AggregateModifier::Distinct(span)) = &modifier {
+            parser.issues.push(crate::Issue::err(
+                "DISTINCT is not supported in window functions",
+                span,
+            ));
         }
+        parser.consume_token(Token::LParen)?;
+        let order_by = parse_order_by(parser)?;
         parser.consume_token(Token::RParen)?;
         Ok(Expression::WindowFunction {
             function: func,
             args,
             function_span: span,
             over_span,
-            window_spec: WindowSpec {
-                order_by: (order_span, order),
-            },
+            window_spec: WindowSpec { order_by },
+        })
+    } else if aggregate {
+        Ok(Expression::AggregateFunction {
+            function: func,
+            function_span: span,
+            modifier,
+            args,
         })
     } else {
         Ok(Expression::Function(func, args, span))
     }
 }
 
+fn parse_json_value<'a>(parser: &mut Parser<'a, '_>) -> Result<Expression<'a>, ParseError> {
+    let query = matches!(parser.token, Token::Ident(_, Keyword::JSON_QUERY));
+    let function_span = parser.consume();
+    parser.consume_token(Token::LParen)?;
+    let json = parser.recovered("')'", &|t| matches!(t, Token::RParen), |parser| {
+        let doc = parse_expression_outer(parser)?;
+        parser.consume_token(Token::Comma)?;
+        let path = parse_expression_outer(parser)?;
+        let returning = match parser.skip_keyword(Keyword::RETURNING) {
+            Some(span) => Some((span, Box::new(parse_data_type_without_properties(parser)?))),
+            None => None,
+        };
+        let mut on_empty: Option<(JsonOnResponse, Span)> = None;
+        let mut on_error: Option<(JsonOnResponse, Span)> = None;
+        loop {
+            let response = match &parser.token {
+                Token::Ident(_, Keyword::NULL) => JsonOnResponse::Null(parser.consume()),
+                Token::Ident(_, Keyword::ERROR) => JsonOnResponse::Error(parser.consume()),
+                Token::Ident(_, Keyword::DEFAULT) => {
+                    let default_span = parser.consume();
+                    JsonOnResponse::Default(default_span, Box::new(parse_expression(parser, true)?))
+                }
+                _ => break,
+            };
+            let on_span = parser.consume_keyword(Keyword::ON)?;
+            match &parser.token {
+                Token::Ident(_, Keyword::EMPTY) => {
+                    let span = on_span.join_span(&parser.consume());
+                    if let Some((_, prev)) = &on_empty {
+                        parser.issues.push(
+                            crate::Issue::err("ON EMPTY specified more than once", &span)
+                                .frag("Previously specified here", prev),
+                        );
+                    } else if let Some((_, error_span)) = &on_error {
+                        parser.issues.push(
+                            crate::Issue::err("ON EMPTY must be specified before ON ERROR", &span)
+                                .frag("ON ERROR specified here", error_span),
+                        );
+                    }
+                    on_empty = Some((response, span));
+                }
+                Token::Ident(_, Keyword::ERROR) => {
+                    let span = on_span.join_span(&parser.consume());
+                    if let Some((_, prev)) = &on_error {
+                        parser.issues.push(
+                            crate::Issue::err("ON ERROR specified more than once", &span)
+                                .frag("Previously specified here", prev),
+                        );
+                    }
+                    on_error = Some((response, span));
+                }
+                _ => parser.expected_failure("'EMPTY' or 'ERROR'")?,
+            }
+        }
+        Ok(Some((doc, path, returning, on_empty, on_error)))
+    })?;
+    parser.consume_token(Token::RParen)?;
+    Ok(match json {
+        Some((doc, path, returning, on_empty, on_error)) => Expression::JsonValue {
+            query,
+            function_span,
+            doc: Box::new(doc),
+            path: Box::new(path),
+            returning,
+            on_empty,
+            on_error,
+        },
+        None => Expression::Invalid(function_span),
+    })
+}
+
+const JSON_EXTRACT_PRIORITY: usize = 5;
 //const INTERVAL_PRIORITY: usize = 10;
+const COLLATE_PRIORITY: usize = 20;
 const IN_PRIORITY: usize = 110;
 
 trait Priority {
@@ -790,9 +1921,9 @@ trait Priority {
 impl Priority for BinaryOperator {
     fn priority(&self) -> usize {
         match self {
-            BinaryOperator::Or => 140,
+            BinaryOperator::Or => 160,
             BinaryOperator::Xor => 150,
-            BinaryOperator::And => 160,
+            BinaryOperator::And => 140,
             BinaryOperator::Eq => 110,
             BinaryOperator::NullSafeEq => 110,
             BinaryOperator::GtEq => 110,
@@ -821,9 +1952,9 @@ impl Priority for UnaryOperator {
     fn priority(&self) -> usize {
         match self {
             UnaryOperator::Binary => 20,
-            UnaryOperator::Collate => 20,
             UnaryOperator::LogicalNot => 30,
             UnaryOperator::Minus => 40,
+            UnaryOperator::Plus => 40,
             UnaryOperator::Not => 130,
         }
     }
@@ -836,8 +1967,26 @@ enum ReduceMember<'a> {
     Unary(UnaryOperator, Span),
 }
 
+impl BinaryOperator {
+    fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            BinaryOperator::Eq
+                | BinaryOperator::NullSafeEq
+                | BinaryOperator::GtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Lt
+                | BinaryOperator::Neq
+        )
+    }
+}
+
 struct Reducer<'a> {
     stack: Vec<ReduceMember<'a>>,
+    /// Spans of the previous and current operator of unparenthesized chained
+    /// comparisons such as "a = b = c"
+    chained_comparisons: Vec<(Span, Span)>,
 }
 
 impl<'a> Reducer<'a> {
@@ -887,6 +2036,24 @@ impl<'a> Reducer<'a> {
     }
 
     fn shift_binop(&mut self, span: Span, op: BinaryOperator) -> Result<(), &'static str> {
+        if op.is_comparison() {
+            // Look past operands and operators binding tighter for an earlier comparison
+            for member in self.stack.iter().rev() {
+                match member {
+                    ReduceMember::Expression(_) => (),
+                    ReduceMember::Binary(prev, _) if prev.priority() < op.priority() => (),
+                    ReduceMember::Unary(prev, _) if prev.priority() < op.priority() => (),
+                    ReduceMember::Binary(prev, prev_span) => {
+                        if prev.is_comparison() {
+                            self.chained_comparisons
+                                .push((prev_span.clone(), span.clone()));
+                        }
+                        break;
+                    }
+                    ReduceMember::Unary(_, _) => break,
+                }
+            }
+        }
         self.reduce(op.priority())?;
         self.stack.push(ReduceMember::Binary(op, span));
         Ok(())
@@ -914,7 +2081,10 @@ pub(crate) fn parse_expression<'a>(
     parser: &mut Parser<'a, '_>,
     inner: bool,
 ) -> Result<Expression<'a>, ParseError> {
-    let mut r = Reducer { stack: Vec::new() };
+    let mut r = Reducer {
+        stack: Vec::new(),
+        chained_comparisons: Vec::new(),
+    };
     loop {
         let e = match &parser.token {
             Token::Ident(_, Keyword::OR) | Token::DoublePipe if !inner => {
@@ -947,7 +2117,38 @@ pub(crate) fn parse_expression<'a>(
                 r.shift_unary(parser.consume(), UnaryOperator::Binary)
             }
             Token::Ident(_, Keyword::COLLATE) if !inner => {
-                r.shift_unary(parser.consume(), UnaryOperator::Collate)
+                if let Err(e) = r.reduce(COLLATE_PRIORITY) {
+                    parser.error(e)?;
+                }
+                let expr = match r.stack.pop() {
+                    Some(ReduceMember::Expression(e)) => e,
+                    _ => parser.error("Expected expression before here 5")?,
+                };
+                let collate_span = parser.consume_keyword(Keyword::COLLATE)?;
+                let collation = parser.consume_plain_identifier()?;
+                r.shift_expr(Expression::Collate {
+                    expr: Box::new(expr),
+                    collate_span,
+                    collation,
+                })
+            }
+            Token::Arrow | Token::LongArrow if !inner => {
+                if let Err(e) = r.reduce(JSON_EXTRACT_PRIORITY) {
+                    parser.error(e)?;
+                }
+                let target = match r.stack.pop() {
+                    Some(ReduceMember::Expression(e)) => e,
+                    _ => parser.error("Expected expression before here")?,
+                };
+                let unquote = matches!(parser.token, Token::LongArrow);
+                let arrow_span = parser.consume();
+                let path = parser.consume_string()?;
+                r.shift_expr(Expression::JsonExtract {
+                    arrow_span,
+                    unquote,
+                    target: Box::new(target),
+                    path,
+                })
             }
             Token::ExclamationMark if !inner => {
                 r.shift_unary(parser.consume(), UnaryOperator::LogicalNot)
@@ -955,6 +2156,9 @@ pub(crate) fn parse_expression<'a>(
             Token::Minus if !matches!(r.stack.last(), Some(ReduceMember::Expression(_))) => {
                 r.shift_unary(parser.consume(), UnaryOperator::Minus)
             }
+            Token::Plus if !matches!(r.stack.last(), Some(ReduceMember::Expression(_))) => {
+                r.shift_unary(parser.consume(), UnaryOperator::Plus)
+            }
             Token::Minus
                 if !inner && matches!(r.stack.last(), Some(ReduceMember::Expression(_))) =>
             {
@@ -1096,11 +2300,11 @@ pub(crate) fn parse_expression<'a>(
                 r.shift_binop(parser.consume(), BinaryOperator::Mult)
             }
             Token::Ident(_, Keyword::TRUE) => r.shift_expr(Expression::Bool(
-                true,
+                Some(true),
                 parser.consume_keyword(Keyword::TRUE)?,
             )),
             Token::Ident(_, Keyword::FALSE) => r.shift_expr(Expression::Bool(
-                false,
+                Some(false),
                 parser.consume_keyword(Keyword::FALSE)?,
             )),
             Token::Ident(_, Keyword::NULL) => {
@@ -1117,6 +2321,17 @@ pub(crate) fn parse_expression<'a>(
             Token::SingleQuotedString(_) | Token::DoubleQuotedString(_) => {
                 r.shift_expr(Expression::String(parser.consume_string()?))
             }
+            Token::Ident(v, k)
+                if k != &Keyword::QUOTED_IDENTIFIER
+                    && v.strip_prefix('_').is_some_and(is_charset_name)
+                    && matches!(parser.peek_n(1), Token::SingleQuotedString(_)) =>
+            {
+                let v = *v;
+                let span = parser.consume();
+                let charset = Identifier::new(&v[1..], span.start + 1..span.end);
+                let value = parser.consume_string()?;
+                r.shift_expr(Expression::CharsetString { charset, value })
+            }
             Token::Integer(_) => r.shift_expr(Expression::Integer(parser.consume_int()?)),
             Token::Float(_) => r.shift_expr(Expression::Float(parser.consume_float()?)),
 
@@ -1141,19 +2356,112 @@ pub(crate) fn parse_expression<'a>(
                     r.shift_expr(Expression::Invalid(cast_span))
                 }
             }
+            Token::Ident(_, Keyword::JSON_VALUE | Keyword::JSON_QUERY)
+                if matches!(parser.peek_n(1), Token::LParen) =>
+            {
+                r.shift_expr(parse_json_value(parser)?)
+            }
+            Token::Ident(_, Keyword::CONVERT) if matches!(parser.peek_n(1), Token::LParen) => {
+                let convert_span = parser.consume_keyword(Keyword::CONVERT)?;
+                parser.consume_token(Token::LParen)?;
+                let convert =
+                    parser.recovered("')'", &|t| matches!(t, Token::RParen), |parser| {
+                        let expr = parse_expression_outer(parser)?;
+                        let using_span = parser.consume_keyword(Keyword::USING)?;
+                        let charset = parser.consume_plain_identifier()?;
+                        Ok(Some((expr, using_span, charset)))
+                    })?;
+                parser.consume_token(Token::RParen)?;
+                if let Some((expr, using_span, charset)) = convert {
+                    r.shift_expr(Expression::Convert {
+                        convert_span,
+                        expr: Box::new(expr),
+                        using_span,
+                        charset,
+                    })
+                } else {
+                    r.shift_expr(Expression::Invalid(convert_span))
+                }
+            }
+            Token::Ident(_, Keyword::TRIM) if matches!(parser.peek_n(1), Token::LParen) => {
+                let trim_span = parser.consume_keyword(Keyword::TRIM)?;
+                parser.consume_token(Token::LParen)?;
+                let trim = parser.recovered("')'", &|t| matches!(t, Token::RParen), |parser| {
+                    let direction = match &parser.token {
+                        Token::Ident(_, Keyword::BOTH) => {
+                            Some(TrimDirection::Both(parser.consume()))
+                        }
+                        Token::Ident(_, Keyword::LEADING) => {
+                            Some(TrimDirection::Leading(parser.consume()))
+                        }
+                        Token::Ident(_, Keyword::TRAILING) => {
+                            Some(TrimDirection::Trailing(parser.consume()))
+                        }
+                        _ => None,
+                    };
+                    if direction.is_some() {
+                        if let Some(from_span) = parser.skip_keyword(Keyword::FROM) {
+                            let expr = parse_expression_outer(parser)?;
+                            return Ok(Some((direction, None, Some(from_span), expr)));
+                        }
+                    }
+                    let first = parse_expression_outer(parser)?;
+                    if let Some(from_span) = parser.skip_keyword(Keyword::FROM) {
+                        let expr = parse_expression_outer(parser)?;
+                        Ok(Some((direction, Some(first), Some(from_span), expr)))
+                    } else if direction.is_some() {
+                        parser.expected_failure("'FROM'")
+                    } else {
+                        Ok(Some((None, None, None, first)))
+                    }
+                })?;
+                parser.consume_token(Token::RParen)?;
+                if let Some((direction, remove, from_span, expr)) = trim {
+                    r.shift_expr(Expression::Trim {
+                        trim_span,
+                        direction,
+                        remove: remove.map(Box::new),
+                        from_span,
+                        expr: Box::new(expr),
+                    })
+                } else {
+                    r.shift_expr(Expression::Invalid(trim_span))
+                }
+            }
             Token::Ident(_, Keyword::COUNT) => {
                 let count_span = parser.consume_keyword(Keyword::COUNT)?;
                 parser.consume_token(Token::LParen)?;
-                let distinct_span = parser.skip_keyword(Keyword::DISTINCT);
-                let expr = parser.recovered("')'", &|t| matches!(t, Token::RParen), |parser| {
-                    let expr = parse_expression_outer(parser)?;
-                    Ok(Some(expr))
-                })?;
+                let modifier = parse_aggregate_modifier(parser);
+                let mut args =
+                    parser.recovered("')'", &|t| matches!(t, Token::RParen), |parser| {
+                        let mut args = Vec::new();
+                        loop {
+                            args.push(parse_expression_outer(parser)?);
+                            if parser.skip_token(Token::Comma).is_none() {
+                                break;
+                            }
+                        }
+                        Ok(args)
+                    })?;
                 parser.consume_token(Token::RParen)?;
-                if let Some(expr) = expr {
+                if args.len() > 1 {
+                    // COUNT(DISTINCT expr, [expr...]) counts distinct combinations
+                    if !matches!(modifier, Some(AggregateModifier::Distinct(_))) {
+                        parser.issues.push(crate::Issue::err(
+                            "COUNT only accepts multiple arguments after DISTINCT",
+                            &args[1],
+                        ));
+                    }
+                    r.shift_expr(Expression::AggregateFunction {
+                        function: Function::Count,
+                        function_span: count_span,
+                        modifier,
+                        args,
+                    })
+                } else if let Some(expr) = args.pop() {
                     r.shift_expr(Expression::Count {
                         count_span,
-                        distinct_span,
+                        modifier,
                         expr: Box::new(expr),
                     })
                 } else {
@@ -1164,27 +2472,92 @@ pub(crate) fn parse_expression<'a>(
                 let group_concat_span = parser.consume_keyword(Keyword::GROUP_CONCAT)?;
                 parser.consume_token(Token::LParen)?;
                 let distinct_span = parser.skip_keyword(Keyword::DISTINCT);
+                let mut order_by = None;
+                let mut separator = None;
                 let expr = parser.recovered("')'", &|t| matches!(t, Token::RParen), |parser| {
                     let expr = parse_expression_outer(parser)?;
+                    if matches!(parser.token, Token::Ident(_, Keyword::ORDER)) {
+                        order_by = Some(parse_order_by(parser)?);
+                    }
+                    if let Some(span) = parser.skip_keyword(Keyword::SEPARATOR) {
+                        separator = Some((span, parser.consume_string()?));
+                    }
+                    // TODO [LIMIT {[offset,] row_count | row_count OFFSET offset}]
                     Ok(Some(expr))
                 })?;
-                // TODO
-                // [ORDER BY {unsigned_integer | col_name | expr}
-                //     [ASC | DESC] [,col_name ...]]
-                // [SEPARATOR str_val]
-                // [LIMIT {[offset,] row_count | row_count OFFSET offset}])
                 parser.consume_token(Token::RParen)?;
                 if let Some(expr) = expr {
                     r.shift_expr(Expression::GroupConcat {
                         group_concat_span,
                         distinct_span,
                         expr: Box::new(expr),
+                        order_by,
+                        separator,
                     })
                 } else {
                     r.shift_expr(Expression::Invalid(group_concat_span))
                 }
             }
-            Token::Ident(_, k) if k.expr_ident() => {
+            Token::Ident(_, Keyword::INTERVAL)
+                if !matches!(r.stack.last(), Some(ReduceMember::Expression(_))) =>
+            {
+                let interval_span = parser.consume_keyword(Keyword::INTERVAL)?;
+                let expr = parse_expression(parser, false)?;
+                let unit = parse_interval_unit(parser)?;
+                r.shift_expr(Expression::Interval {
+                    interval_span,
+                    expr: Box::new(expr),
+                    unit,
+                })
+            }
+            Token::Ident(_, Keyword::MATCH) if matches!(parser.peek_n(1), Token::LParen) => {
+                let match_span = parser.consume_keyword(Keyword::MATCH)?;
+                parser.consume_token(Token::LParen)?;
+                let mut columns = Vec::new();
+                loop {
+                    columns.push(parse_expression(parser, true)?);
+                    if parser.skip_token(Token::Comma).is_none() {
+                        break;
+                    }
+                }
+                parser.consume_token(Token::RParen)?;
+                let against_span = parser.consume_keyword(Keyword::AGAINST)?;
+                parser.consume_token(Token::LParen)?;
+                // The search string is parsed as an inner expression so that
+                // "IN BOOLEAN MODE" is not taken for an IN expression
+                let against = parse_expression(parser, true)?;
+                let modifier = parse_match_modifier(parser)?;
+                parser.consume_token(Token::RParen)?;
+                r.shift_expr(Expression::Match {
+                    match_span,
+                    columns,
+                    against_span,
+                    against: Box::new(against),
+                    modifier,
+                })
+            }
+            Token::Ident(_, Keyword::ROW) if matches!(parser.peek_n(1), Token::LParen) => {
+                let row_span = parser.consume_keyword(Keyword::ROW)?;
+                let lparen_span = parser.consume_token(Token::LParen)?;
+                let mut values = Vec::new();
+                loop {
+                    values.push(parse_expression_paren(parser)?);
+                    if parser.skip_token(Token::Comma).is_none() {
+                        break;
+                    }
+                }
+                let paren_span = lparen_span.join_span(&parser.consume_token(Token::RParen)?);
+                r.shift_expr(Expression::Row {
+                    row_span: Some(row_span),
+                    paren_span,
+                    values,
+                })
+            }
+            // An identifier directly after an expression ends it, e.g. a bare alias
+            Token::Ident(_, k)
+                if k.expr_ident()
+                    && !matches!(r.stack.last(), Some(ReduceMember::Expression(_))) =>
+            {
                 let i = parser.token.clone();
                 let s = parser.span.clone();
                 parser.consume();
@@ -1195,6 +2568,11 @@ pub(crate) fn parse_expression<'a>(
                         Token::Ident(_, Keyword::CURRENT_TIMESTAMP) => {
                             Some(Function::CurrentTimestamp)
                         }
+                        Token::Ident(_, Keyword::LOCALTIME | Keyword::LOCALTIMESTAMP) => {
+                            Some(Function::Now)
+                        }
+                        Token::Ident(_, Keyword::CURRENT_TIME) => Some(Function::CurTime),
+                        Token::Ident(_, Keyword::CURRENT_DATE) => Some(Function::CurDate),
                         _ => None,
                     };
                     if let Some(f) = f {
@@ -1203,10 +2581,9 @@ pub(crate) fn parse_expression<'a>(
                         let mut parts = vec![IdentifierPart::Name(
                             parser.token_to_plain_identifier(&i, s)?,
                         )];
-                        loop {
-                            if parser.skip_token(Token::Period).is_none() {
-                                break;
-                            }
+                        let mut dots = Vec::new();
+                        while let Some(dot) = parser.skip_token(Token::Period) {
+                            dots.push(dot);
                             match &parser.token {
                                 Token::Mul => parts
                                     .push(IdentifierPart::Star(parser.consume_token(Token::Mul)?)),
@@ -1215,7 +2592,40 @@ pub(crate) fn parse_expression<'a>(
                                 _ => parser.expected_failure("Identifier or '*'")?,
                             }
                         }
-                        r.shift_expr(Expression::Identifier(parts))
+                        if parts.len() > 3 {
+                            parser.issues.push(crate::Issue::err(
+                                "Column references can have at most three parts",
+                                &parts.opt_span().expect("Span of identifier parts"),
+                            ));
+                        }
+                        for part in &parts[..parts.len() - 1] {
+                            if let IdentifierPart::Star(span) = part {
+                                parser.issues.push(crate::Issue::err(
+                                    "'*' must be the last part of a column reference",
+                                    span,
+                                ));
+                            }
+                        }
+                        match (&parser.token, parts.as_slice(), dots.as_slice()) {
+                            (
+                                Token::LParen,
+                                [IdentifierPart::Name(schema), IdentifierPart::Name(identifier)],
+                                [dot],
+                            ) => {
+                                // Call of a stored function in another schema
+                                let name = QualifiedName {
+                                    prefix: vec![(schema.clone(), dot.clone())],
+                                    identifier: identifier.clone(),
+                                };
+                                let span = name.span();
+                                r.shift_expr(parse_function_call(
+                                    parser,
+                                    Function::Qualified(name),
+                                    span,
+                                )?)
+                            }
+                            _ => r.shift_expr(Expression::Identifier(parts)),
+                        }
                     }
                 }
             }
@@ -1243,10 +2653,24 @@ pub(crate) fn parse_expression<'a>(
                 r.shift_expr(Expression::Arg((arg - 1, parser.consume())))
             }
             Token::LParen => {
-                parser.consume_token(Token::LParen)?;
+                let lparen_span = parser.consume_token(Token::LParen)?;
                 let ans = parse_expression_paren(parser)?;
-                parser.consume_token(Token::RParen)?;
-                r.shift_expr(ans)
+                // A parenthesized list of more than one value is a row constructor
+                if matches!(parser.token, Token::Comma) {
+                    let mut values = vec![ans];
+                    while parser.skip_token(Token::Comma).is_some() {
+                        values.push(parse_expression_paren(parser)?);
+                    }
+                    let paren_span = lparen_span.join_span(&parser.consume_token(Token::RParen)?);
+                    r.shift_expr(Expression::Row {
+                        row_span: None,
+                        paren_span,
+                        values,
+                    })
+                } else {
+                    parser.consume_token(Token::RParen)?;
+                    r.shift_expr(ans)
+                }
             }
             Token::Ident(_, Keyword::EXISTS) => {
                 parser.consume_keyword(Keyword::EXISTS)?;
@@ -1298,6 +2722,15 @@ pub(crate) fn parse_expression<'a>(
                     end_span,
                 })
             }
+            // Any other keyword followed by "(" is a function call, such as "LEFT(a, 2)"
+            Token::Ident(_, _)
+                if !matches!(r.stack.last(), Some(ReduceMember::Expression(_)))
+                    && matches!(parser.peek_n(1), Token::LParen) =>
+            {
+                let i = parser.token.clone();
+                let s = parser.consume();
+                r.shift_expr(parse_function(parser, i, s)?)
+            }
             Token::AtAtGlobal | Token::AtAtSession => {
                 let global = parser.skip_token(Token::AtAtGlobal);
                 let session = if global.is_none() {
@@ -1326,6 +2759,12 @@ pub(crate) fn parse_expression<'a>(
             parser.error(e.to_string())?;
         }
     }
+    for (prev_span, span) in r.chained_comparisons.drain(..) {
+        parser.issues.push(
+            crate::Issue::warn("Chained comparison, did you mean to use AND?", &span)
+                .frag("Compared with the result of this comparison", &prev_span),
+        );
+    }
     if r.reduce(99999).is_err() {
         parser.error("Expected expression")
     } else if r.stack.len() != 1 {
@@ -1361,6 +2800,709 @@ pub(crate) fn parse_expression_paren<'a>(
     }
 }
 
+impl<'a> Expression<'a> {
+    /// Render the expression as SQL for the given dialect
+    ///
+    /// Parentheses are only inserted where a sub expression binds looser than
+    /// the surrounding operator. Identifiers are only quoted if they were quoted in
+    /// the source or cannot be written bare. Returns None if
+    /// the expression contains parts that cannot be rendered, such as
+    /// subqueries, casts or invalid expressions
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let select = match parse_statement("SELECT (a + b) * c, a + b * c", &mut issues, &options) {
+    ///     Some(Statement::Select(s)) => s,
+    ///     _ => panic!("We should get a select statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let sql: Vec<_> = select.select_exprs.iter()
+    ///     .map(|e| e.expr.to_sql(&SQLDialect::MariaDB).unwrap())
+    ///     .collect();
+    /// assert_eq!(sql, ["(a + b) * c", "a + b * c"]);
+    ///
+    /// # let options = ParseOptions::new().dialect(SQLDialect::PostgreSQL);
+    /// let select = match parse_statement("SELECT Foo + 1, Foo_Bar * 2", &mut issues, &options) {
+    ///     Some(Statement::Select(s)) => s,
+    ///     _ => panic!("We should get a select statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let sql: Vec<_> = select.select_exprs.iter()
+    ///     .map(|e| e.expr.to_sql(&SQLDialect::PostgreSQL).unwrap())
+    ///     .collect();
+    /// assert_eq!(sql, ["Foo + 1", "Foo_Bar * 2"]);
+    /// ```
+    pub fn to_sql(&self, dialect: &SQLDialect) -> Option<String> {
+        let mut out = String::new();
+        self.write_sql(dialect, &mut out)?;
+        Some(out)
+    }
+
+    fn sql_priority(&self) -> usize {
+        match self {
+            Expression::Binary { op, .. } => op.priority(),
+            Expression::Unary { op, .. } => op.priority(),
+            Expression::In { .. } | Expression::Is(..) => IN_PRIORITY,
+            Expression::Collate { .. } => COLLATE_PRIORITY,
+            Expression::JsonExtract { .. } => JSON_EXTRACT_PRIORITY,
+            _ => 0,
+        }
+    }
+
+    /// Write the expression, adding parentheses if it binds looser than priority
+    fn write_sql_operand(
+        &self,
+        priority: usize,
+        strict: bool,
+        dialect: &SQLDialect,
+        out: &mut String,
+    ) -> Option<()> {
+        let p = self.sql_priority();
+        if p > priority || (strict && p == priority && p != 0) {
+            out.push('(');
+            self.write_sql(dialect, out)?;
+            out.push(')');
+            Some(())
+        } else {
+            self.write_sql(dialect, out)
+        }
+    }
+
+    fn write_sql(&self, dialect: &SQLDialect, out: &mut String) -> Option<()> {
+        match self {
+            Expression::Binary { op, lhs, rhs, .. } => {
+                lhs.write_sql_operand(op.priority(), false, dialect, out)?;
+                out.push(' ');
+                out.push_str(binary_operator_sql(op));
+                out.push(' ');
+                rhs.write_sql_operand(op.priority(), true, dialect, out)?;
+            }
+            Expression::Unary { op, operand, .. } => {
+                out.push_str(match op {
+                    UnaryOperator::Binary => "BINARY ",
+                    UnaryOperator::LogicalNot => "!",
+                    UnaryOperator::Minus => "-",
+                    UnaryOperator::Plus => "+",
+                    UnaryOperator::Not => "NOT ",
+                });
+                let start = out.len();
+                operand.write_sql_operand(op.priority(), false, dialect, out)?;
+                // "--" would start a comment
+                if matches!(op, UnaryOperator::Minus) && out[start..].starts_with('-') {
+                    out.insert(start, ' ');
+                }
+            }
+            Expression::Null(_) => out.push_str("NULL"),
+            Expression::Bool(Some(true), _) => out.push_str("TRUE"),
+            Expression::Bool(Some(false), _) => out.push_str("FALSE"),
+            Expression::Bool(None, _) => out.push_str("UNKNOWN"),
+            Expression::String(s) => write_string_sql(s, dialect, out),
+            Expression::Integer((v, _)) => out.push_str(&v.to_string()),
+            Expression::ListHack(_) => out.push_str("_LIST_"),
+            // Debug formatting keeps a fractional part or an exponent, so 1.0
+            // is not turned into the integer 1
+            Expression::Float((v, _)) => out.push_str(&alloc::format!("{:?}", v)),
+            Expression::Function(Function::Qualified(name), args, _) => {
+                for (part, _) in &name.prefix {
+                    write_identifier_sql(part, dialect, out);
+                    out.push('.');
+                }
+                write_identifier_sql(&name.identifier, dialect, out);
+                write_sql_list(args, dialect, out)?;
+            }
+            Expression::Function(function, args, _) => {
+                out.push_str(function_name(function)?);
+                write_sql_list(args, dialect, out)?;
+            }
+            Expression::AggregateFunction {
+                function,
+                modifier,
+                args,
+                ..
+            } => {
+                out.push_str(function_name(function)?);
+                out.push('(');
+                write_aggregate_modifier(modifier, out);
+                for (i, arg) in args.iter().enumerate() {
+                    if i != 0 {
+                        out.push_str(", ");
+                    }
+                    arg.write_sql(dialect, out)?;
+                }
+                out.push(')');
+            }
+            Expression::WindowFunction {
+                function,
+                args,
+                window_spec,
+                ..
+            } => {
+                out.push_str(function_name(function)?);
+                write_sql_list(args, dialect, out)?;
+                out.push_str(" OVER (ORDER BY ");
+                write_order_keys(&window_spec.order_by.1, dialect, out)?;
+                out.push(')');
+            }
+            Expression::Identifier(parts) => {
+                for (i, part) in parts.iter().enumerate() {
+                    if i != 0 {
+                        out.push('.');
+                    }
+                    match part {
+                        IdentifierPart::Name(v) => write_identifier_sql(v, dialect, out),
+                        IdentifierPart::Star(_) => out.push('*'),
+                    }
+                }
+            }
+            Expression::Arg((i, _)) => {
+                if dialect.is_postgresql() {
+                    out.push('$');
+                    out.push_str(&(i + 1).to_string());
+                } else {
+                    out.push('?');
+                }
+            }
+            Expression::In {
+                lhs, rhs, not_in, ..
+            } => {
+                lhs.write_sql_operand(IN_PRIORITY, false, dialect, out)?;
+                out.push_str(if *not_in { " NOT IN " } else { " IN " });
+                write_sql_list(rhs, dialect, out)?;
+            }
+            Expression::Is(e, is, _) => {
+                e.write_sql_operand(IN_PRIORITY, false, dialect, out)?;
+                out.push_str(match is {
+                    Is::Null => " IS NULL",
+                    Is::NotNull => " IS NOT NULL",
+                    Is::True => " IS TRUE",
+                    Is::NotTrue => " IS NOT TRUE",
+                    Is::False => " IS FALSE",
+                    Is::NotFalse => " IS NOT FALSE",
+                    Is::Unknown => " IS UNKNOWN",
+                    Is::NotUnknown => " IS NOT UNKNOWN",
+                });
+            }
+            Expression::Case {
+                value,
+                whens,
+                else_,
+                ..
+            } => {
+                out.push_str("CASE");
+                if let Some(value) = value {
+                    out.push(' ');
+                    value.write_sql(dialect, out)?;
+                }
+                for when in whens {
+                    out.push_str(" WHEN ");
+                    when.when.write_sql(dialect, out)?;
+                    out.push_str(" THEN ");
+                    when.then.write_sql(dialect, out)?;
+                }
+                if let Some((_, else_)) = else_ {
+                    out.push_str(" ELSE ");
+                    else_.write_sql(dialect, out)?;
+                }
+                out.push_str(" END");
+            }
+            Expression::Count { modifier, expr, .. } => {
+                out.push_str("COUNT(");
+                write_aggregate_modifier(modifier, out);
+                expr.write_sql(dialect, out)?;
+                out.push(')');
+            }
+            Expression::GroupConcat {
+                distinct_span,
+                expr,
+                order_by,
+                separator,
+                ..
+            } => {
+                out.push_str("GROUP_CONCAT(");
+                if distinct_span.is_some() {
+                    out.push_str("DISTINCT ");
+                }
+                expr.write_sql(dialect, out)?;
+                if let Some((_, order)) = order_by {
+                    out.push_str(" ORDER BY ");
+                    write_order_keys(order, dialect, out)?;
+                }
+                if let Some((_, value)) = separator {
+                    out.push_str(" SEPARATOR ");
+                    write_string_sql(value, dialect, out);
+                }
+                out.push(')');
+            }
+            Expression::Variable {
+                global, variable, ..
+            } => {
+                out.push_str(if global.is_some() {
+                    "@@GLOBAL."
+                } else {
+                    "@@SESSION."
+                });
+                out.push_str(match variable {
+                    Variable::TimeZone => "time_zone",
+                    Variable::Other(v) => v,
+                });
+            }
+            Expression::Collate {
+                expr, collation, ..
+            } => {
+                expr.write_sql_operand(COLLATE_PRIORITY, false, dialect, out)?;
+                out.push_str(" COLLATE ");
+                write_identifier_sql(collation, dialect, out);
+            }
+            Expression::JsonExtract {
+                unquote,
+                target,
+                path,
+                ..
+            } => {
+                target.write_sql_operand(JSON_EXTRACT_PRIORITY, false, dialect, out)?;
+                out.push_str(if *unquote { "->>" } else { "->" });
+                write_string_sql(path, dialect, out);
+            }
+            Expression::Trim {
+                direction,
+                remove,
+                from_span,
+                expr,
+                ..
+            } => {
+                out.push_str("TRIM(");
+                match direction {
+                    Some(TrimDirection::Both(_)) => out.push_str("BOTH "),
+                    Some(TrimDirection::Leading(_)) => out.push_str("LEADING "),
+                    Some(TrimDirection::Trailing(_)) => out.push_str("TRAILING "),
+                    None => (),
+                }
+                if let Some(remove) = remove {
+                    remove.write_sql(dialect, out)?;
+                    out.push(' ');
+                }
+                if from_span.is_some() {
+                    out.push_str("FROM ");
+                }
+                expr.write_sql(dialect, out)?;
+                out.push(')');
+            }
+            Expression::CharsetString { charset, value } => {
+                out.push('_');
+                out.push_str(charset.as_str());
+                write_string_sql(value, dialect, out);
+            }
+            Expression::Convert { expr, charset, .. } => {
+                out.push_str("CONVERT(");
+                expr.write_sql(dialect, out)?;
+                out.push_str(" USING ");
+                out.push_str(charset.as_str());
+                out.push(')');
+            }
+            Expression::Interval { expr, unit, .. } => {
+                out.push_str("INTERVAL ");
+                expr.write_sql_operand(0, false, dialect, out)?;
+                out.push(' ');
+                out.push_str(unit.0.name());
+            }
+            Expression::JsonValue {
+                query,
+                doc,
+                path,
+                returning: None,
+                on_empty,
+                on_error,
+                ..
+            } => {
+                out.push_str(if *query { "JSON_QUERY(" } else { "JSON_VALUE(" });
+                doc.write_sql(dialect, out)?;
+                out.push_str(", ");
+                path.write_sql(dialect, out)?;
+                if let Some((response, _)) = on_empty {
+                    write_json_on_response(response, dialect, out)?;
+                    out.push_str(" ON EMPTY");
+                }
+                if let Some((response, _)) = on_error {
+                    write_json_on_response(response, dialect, out)?;
+                    out.push_str(" ON ERROR");
+                }
+                out.push(')');
+            }
+            Expression::Match {
+                columns,
+                against,
+                modifier,
+                ..
+            } => {
+                out.push_str("MATCH ");
+                write_sql_list(columns, dialect, out)?;
+                out.push_str(" AGAINST (");
+                against.write_sql(dialect, out)?;
+                out.push_str(match modifier {
+                    Some(MatchModifier::NaturalLanguageMode(_)) => " IN NATURAL LANGUAGE MODE",
+                    Some(MatchModifier::NaturalLanguageModeWithQueryExpansion(_)) => {
+                        " IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION"
+                    }
+                    Some(MatchModifier::BooleanMode(_)) => " IN BOOLEAN MODE",
+                    Some(MatchModifier::WithQueryExpansion(_)) => " WITH QUERY EXPANSION",
+                    None => "",
+                });
+                out.push(')');
+            }
+            Expression::Row {
+                row_span, values, ..
+            } => {
+                if row_span.is_some() {
+                    out.push_str("ROW");
+                }
+                write_sql_list(values, dialect, out)?;
+            }
+            Expression::Subquery(_)
+            | Expression::Exists(_)
+            | Expression::Cast { .. }
+            | Expression::JsonValue { .. }
+            | Expression::Invalid(_) => return None,
+        }
+        Some(())
+    }
+}
+
+fn write_order_keys(keys: &[OrderKey<'_>], dialect: &SQLDialect, out: &mut String) -> Option<()> {
+    for (i, key) in keys.iter().enumerate() {
+        if i != 0 {
+            out.push_str(", ");
+        }
+        key.expr.write_sql(dialect, out)?;
+        if let Some((_, collation)) = &key.collate {
+            out.push_str(" COLLATE ");
+            write_identifier_sql(collation, dialect, out);
+        }
+        match &key.direction {
+            OrderFlag::Asc(_) => out.push_str(" ASC"),
+            OrderFlag::Desc(_) => out.push_str(" DESC"),
+            OrderFlag::None => (),
+        }
+        match &key.nulls {
+            Some(NullsOrder::First(_)) => out.push_str(" NULLS FIRST"),
+            Some(NullsOrder::Last(_)) => out.push_str(" NULLS LAST"),
+            None => (),
+        }
+    }
+    Some(())
+}
+
+fn write_json_on_response(
+    response: &JsonOnResponse<'_>,
+    dialect: &SQLDialect,
+    out: &mut String,
+) -> Option<()> {
+    match response {
+        JsonOnResponse::Null(_) => out.push_str(" NULL"),
+        JsonOnResponse::Error(_) => out.push_str(" ERROR"),
+        JsonOnResponse::Default(_, value) => {
+            out.push_str(" DEFAULT ");
+            value.write_sql(dialect, out)?;
+        }
+    }
+    Some(())
+}
+
+fn write_aggregate_modifier(modifier: &Option<AggregateModifier>, out: &mut String) {
+    match modifier {
+        Some(AggregateModifier::Distinct(_)) => out.push_str("DISTINCT "),
+        Some(AggregateModifier::All(_)) => out.push_str("ALL "),
+        None => (),
+    }
+}
+
+fn write_string_sql(s: &SString<'_>, dialect: &SQLDialect, out: &mut String) {
+    // The value keeps backslash escapes as written, so in MariaDB an escape
+    // sequence is copied verbatim, and only bare quotes need to be doubled
+    out.push('\'');
+    let mut chars = s.as_str().chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => out.push_str("''"),
+            '\\' if dialect.is_maria() => {
+                out.push('\\');
+                if let Some(c) = chars.next() {
+                    out.push(c);
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+}
+
+fn write_sql_list(args: &[Expression<'_>], dialect: &SQLDialect, out: &mut String) -> Option<()> {
+    out.push('(');
+    for (i, arg) in args.iter().enumerate() {
+        if i != 0 {
+            out.push_str(", ");
+        }
+        arg.write_sql(dialect, out)?;
+    }
+    out.push(')');
+    Some(())
+}
+
+fn write_identifier_sql(identifier: &Identifier<'_>, dialect: &SQLDialect, out: &mut String) {
+    let name = identifier.as_str();
+    let quote = if dialect.is_postgresql() { '"' } else { '`' };
+    if !identifier.is_quoted() && !identifier_needs_quoting(name) {
+        out.push_str(name);
+        return;
+    }
+    out.push(quote);
+    for c in name.chars() {
+        if c == quote {
+            out.push(quote);
+        }
+        out.push(c);
+    }
+    out.push(quote);
+}
+
+/// Can the name not be written as a bare identifier
+fn identifier_needs_quoting(name: &str) -> bool {
+    let Some(first) = name.chars().next() else {
+        return true;
+    };
+    if first.is_ascii_digit()
+        || !name
+            .chars()
+            .all(|c| c == '_' || c.is_ascii_digit() || c.is_ascii_alphabetic())
+    {
+        return true;
+    }
+    Keyword::from(name.to_ascii_uppercase().as_str()) != Keyword::NOT_A_KEYWORD
+}
+
+fn binary_operator_sql(op: &BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::Or => "OR",
+        BinaryOperator::Xor => "XOR",
+        BinaryOperator::And => "AND",
+        BinaryOperator::Eq => "=",
+        BinaryOperator::NullSafeEq => "<=>",
+        BinaryOperator::GtEq => ">=",
+        BinaryOperator::Gt => ">",
+        BinaryOperator::LtEq => "<=",
+        BinaryOperator::Lt => "<",
+        BinaryOperator::Neq => "!=",
+        BinaryOperator::ShiftLeft => "<<",
+        BinaryOperator::ShiftRight => ">>",
+        BinaryOperator::BitAnd => "&",
+        BinaryOperator::BitOr => "|",
+        BinaryOperator::BitXor => "^",
+        BinaryOperator::Add => "+",
+        BinaryOperator::Subtract => "-",
+        BinaryOperator::Divide => "/",
+        BinaryOperator::Div => "DIV",
+        BinaryOperator::Mod => "%",
+        BinaryOperator::Mult => "*",
+        BinaryOperator::Like => "LIKE",
+        BinaryOperator::NotLike => "NOT LIKE",
+    }
+}
+
+fn function_name<'a>(function: &Function<'a>) -> Option<&'a str> {
+    Some(match function {
+        Function::Ascii => "ASCII",
+        Function::Bin => "BIN",
+        Function::BitLength => "BIT_LENGTH",
+        Function::CharacterLength => "CHAR_LENGTH",
+        Function::Chr => "CHR",
+        Function::Coalesce => "COALESCE",
+        Function::Concat => "CONCAT",
+        Function::ConcatWs => "CONCAT_WS",
+        Function::Elt => "ELT",
+        Function::ExportSet => "EXPORT_SET",
+        Function::ExtractValue => "EXTRACTVALUE",
+        Function::Field => "FIELD",
+        Function::FindInSet => "FIND_IN_SET",
+        Function::Format => "FORMAT",
+        Function::FromBase64 => "FROM_BASE64",
+        Function::Hex => "HEX",
+        Function::Insert => "INSERT",
+        Function::InStr => "INSTR",
+        Function::LCase => "LCASE",
+        Function::Left => "LEFT",
+        Function::Length => "LENGTH",
+        Function::LengthB => "LENGTHB",
+        Function::LoadFile => "LOAD_FILE",
+        Function::Locate => "LOCATE",
+        Function::Lower => "LOWER",
+        Function::LPad => "LPAD",
+        Function::LTrim => "LTRIM",
+        Function::MakeSet => "MAKE_SET",
+        Function::Mid => "MID",
+        Function::NaturalSortkey => "NATURAL_SORT_KEY",
+        Function::OctetLength => "OCTET_LENGTH",
+        Function::Ord => "ORD",
+        Function::Position => "POSITION",
+        Function::Quote => "QUOTE",
+        Function::Repeat => "REPEAT",
+        Function::Replace => "REPLACE",
+        Function::Reverse => "REVERSE",
+        Function::Right => "RIGHT",
+        Function::RPad => "RPAD",
+        Function::RTrim => "RTRIM",
+        Function::SoundEx => "SOUNDEX",
+        Function::Space => "SPACE",
+        Function::StrCmp => "STRCMP",
+        Function::SubStr => "SUBSTR",
+        Function::SubStringIndex => "SUBSTRING_INDEX",
+        Function::ToBase64 => "TO_BASE64",
+        Function::ToChar => "TO_CHAR",
+        Function::UCase => "UCASE",
+        Function::UncompressedLength => "UNCOMPRESSED_LENGTH",
+        Function::UnHex => "UNHEX",
+        Function::UpdateXml => "UPDATEXML",
+        Function::Upper => "UPPER",
+        Function::SFormat => "SFORMAT",
+        Function::Exists => "EXISTS",
+        Function::Min => "MIN",
+        Function::Max => "MAX",
+        Function::Sum => "SUM",
+        Function::Avg => "AVG",
+        Function::BitAnd => "BIT_AND",
+        Function::BitOr => "BIT_OR",
+        Function::BitXor => "BIT_XOR",
+        Function::Count => "COUNT",
+        Function::Std => "STD",
+        Function::StdDev => "STDDEV",
+        Function::StdDevPop => "STDDEV_POP",
+        Function::StdDevSamp => "STDDEV_SAMP",
+        Function::Variance => "VARIANCE",
+        Function::VarPop => "VAR_POP",
+        Function::VarSamp => "VAR_SAMP",
+        Function::Value => "VALUE",
+        Function::Lead => "LEAD",
+        Function::Lag => "LAG",
+        Function::IfNull => "IFNULL",
+        Function::NullIf => "NULLIF",
+        Function::NVL2 => "NVL2",
+        Function::If => "IF",
+        Function::Abs => "ABS",
+        Function::Acos => "ACOS",
+        Function::Asin => "ASIN",
+        Function::Atan => "ATAN",
+        Function::Atan2 => "ATAN2",
+        Function::Ceil => "CEILING",
+        Function::Conv => "CONV",
+        Function::Cos => "COS",
+        Function::Cot => "COT",
+        Function::Crc32 => "CRC32",
+        Function::Degrees => "DEGREES",
+        Function::Exp => "EXP",
+        Function::Floor => "FLOOR",
+        Function::Greatest => "GREATEST",
+        Function::Ln => "LN",
+        Function::Log => "LOG",
+        Function::Log10 => "LOG10",
+        Function::Log2 => "LOG2",
+        Function::Oct => "OCT",
+        Function::Pi => "PI",
+        Function::Pow => "POWER",
+        Function::Radians => "RADIANS",
+        Function::Rand => "RAND",
+        Function::Round => "ROUND",
+        Function::Sign => "SIGN",
+        Function::Sin => "SIN",
+        Function::Sqrt => "SQRT",
+        Function::Tan => "TAN",
+        Function::Truncate => "TRUNCATE",
+        Function::Crc32c => "CRC32C",
+        Function::Least => "LEAST",
+        Function::AddDate => "ADDDATE",
+        Function::AddTime => "ADDTIME",
+        Function::ConvertTs => "CONVERT_TS",
+        Function::CurDate => "CURDATE",
+        Function::CurTime => "CURRENT_TIME",
+        Function::Date => "DATE",
+        Function::DateDiff => "DATEDIFF",
+        Function::DateAdd => "DATE_ADD",
+        Function::DateFormat => "DATE_FORMAT",
+        Function::DateSub => "DATE_SUB",
+        Function::DayOfMonth => "DAYOFMONTH",
+        Function::DayName => "DAYNAME",
+        Function::DayOfWeek => "DAYOFWEEK",
+        Function::DayOfYear => "DAYOFYEAR",
+        Function::FromDays => "FROM_DAYS",
+        Function::MakeDate => "MAKEDATE",
+        Function::MakeTime => "MAKETIME",
+        Function::MicroSecond => "MICROSECOND",
+        Function::Minute => "MINUTE",
+        Function::MonthName => "MONTHNAME",
+        Function::Now => "NOW",
+        Function::PeriodAdd => "PERIOD_ADD",
+        Function::PeriodDiff => "PERIOD_DIFF",
+        Function::Quarter => "QUARTER",
+        Function::Second => "SECOND",
+        Function::SecToTime => "SEC_TO_TIME",
+        Function::StrToDate => "STR_TO_DATE",
+        Function::SubDate => "SUBDATE",
+        Function::SubTime => "SUBTIME",
+        Function::Time => "TIME",
+        Function::TimeDiff => "TIMEDIFF",
+        Function::Timestamp => "TIMESTAMP",
+        Function::TimestampAdd => "TIMESTAMPADD",
+        Function::TimestampDiff => "TIMESTAMPDIFF",
+        Function::TimeFormat => "TIME_FORMAT",
+        Function::TimeToSec => "TIME_TO_SEC",
+        Function::ToDays => "TO_DAYS",
+        Function::ToSeconds => "TO_SECONDS",
+        Function::UnixTimestamp => "UNIX_TIMESTAMP",
+        Function::UtcDate => "UTC_DATE",
+        Function::UtcTime => "UTC_TIME",
+        Function::UtcTimeStamp => "UTC_TIMESTAMP",
+        Function::Week => "WEEK",
+        Function::Weekday => "WEEKDAY",
+        Function::WeekOfYear => "WEEKOFYEAR",
+        Function::AddMonths => "ADD_MONTHS",
+        Function::FromUnixTime => "FROM_UNIXTIME",
+        Function::JsonArray => "JSON_ARRAY",
+        Function::JsonArrayAgg => "JSON_ARRAYAGG",
+        Function::JsonArrayAppend => "JSON_ARRAY_APPEND",
+        Function::JsonArrayInsert => "JSON_ARRAY_INSERT",
+        Function::JsonCompact => "JSON_COMPACT",
+        Function::JsonContains => "JSON_CONTAINS",
+        Function::JsonContainsPath => "JSON_CONTAINS_PATH",
+        Function::JsonDepth => "JSON_DEPTH",
+        Function::JsonDetailed => "JSON_DETAILED",
+        Function::JsonEquals => "JSON_EQUALS",
+        Function::JsonExists => "JSON_EXISTS",
+        Function::JsonExtract => "JSON_EXTRACT",
+        Function::JsonInsert => "JSON_INSERT",
+        Function::JsonKeys => "JSON_KEYS",
+        Function::JsonLength => "JSON_LENGTH",
+        Function::JsonLoose => "JSON_LOOSE",
+        Function::JsonMerge => "JSON_MERGE",
+        Function::JsonMergePath => "JSON_MERGE_PATCH",
+        Function::JsonMergePerserve => "JSON_MERGE_PRESERVE",
+        Function::JsonNormalize => "JSON_NORMALIZE",
+        Function::JsonObject => "JSON_OBJECT",
+        Function::JsonObjectAgg => "JSON_OBJECTAGG",
+        Function::JsonQuery => "JSON_QUERY",
+        Function::JsonQoute => "JSON_QUOTE",
+        Function::JsonRemove => "JSON_REMOVE",
+        Function::JsonReplace => "JSON_REPLACE",
+        Function::JsonSearch => "JSON_SEARCH",
+        Function::JsonSet => "JSON_SET",
+        Function::JsonTable => "JSON_TABLE",
+        Function::JsonType => "JSON_TYPE",
+        Function::JsonUnquote => "JSON_UNQUOTE",
+        Function::JsonValid => "JSON_VALID",
+        Function::JsonValue => "JSON_VALUE",
+        Function::CurrentTimestamp => "CURRENT_TIMESTAMP",
+        Function::Other(v) => v,
+        Function::Qualified(_) | Function::Unknown => return None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use core::ops::Deref;
@@ -1372,7 +3514,7 @@ mod tests {
     };
 
     use crate::{
-        expression::{BinaryOperator, Expression},
+        expression::{BinaryOperator, Expression, UnaryOperator},
         parser::Parser,
         ParseOptions, SQLDialect,
     };
@@ -1444,4 +3586,139 @@ mod tests {
             Ok(())
         });
     }
+
+    #[test]
+    fn binary_operator() {
+        test_expr("CAST(`a` AS BINARY) = BINARY `b`", |e| {
+            match e {
+                Expression::Binary {
+                    op: BinaryOperator::Eq,
+                    lhs,
+                    rhs,
+                    ..
+                } => {
+                    match lhs.as_ref() {
+                        Expression::Cast { expr, .. } => test_ident(expr, "a")?,
+                        _ => return Err("Lhs".to_string()),
+                    }
+                    match rhs.as_ref() {
+                        Expression::Unary {
+                            op: UnaryOperator::Binary,
+                            operand,
+                            ..
+                        } => test_ident(operand, "b")?,
+                        _ => return Err("Rhs".to_string()),
+                    }
+                }
+                _ => return Err("Outer".to_string()),
+            }
+            Ok(())
+        });
+    }
+
+    fn test_to_sql(src: &'static str, expected: &str) {
+        let mut issues = Vec::new();
+        let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+        let mut parser = Parser::new(src, &mut issues, &options);
+        let res = parse_expression(&mut parser, false).expect("Expression in test to_sql");
+        assert!(issues.is_empty(), "Issues: {:#?}", issues);
+        assert_eq!(
+            res.to_sql(&SQLDialect::MariaDB).as_deref(),
+            Some(expected),
+            "Rendering {}",
+            src
+        );
+    }
+
+    #[test]
+    fn to_sql() {
+        test_to_sql("(a + b) * c", "(a + b) * c");
+        test_to_sql("a + b * c", "a + b * c");
+        test_to_sql("a + (b * c)", "a + b * c");
+        test_to_sql("a - (b - c)", "a - (b - c)");
+        test_to_sql("(a - b) - c", "a - b - c");
+        test_to_sql("NOT (a = b) AND c", "NOT a = b AND c");
+        test_to_sql("(NOT a) = b", "(NOT a) = b");
+        test_to_sql("-(a + b)", "-(a + b)");
+        test_to_sql("-1 - -2.5 + +3", "-1 - -2.5 + +3");
+        test_to_sql("1.0 + 2.50 * 100.0", "1.0 + 2.5 * 100.0");
+        test_to_sql("1e30 + 2.5E-3", "1e30 + 0.0025");
+        test_to_sql("- -a", "- -a");
+        test_to_sql("(a OR b) AND c", "(a OR b) AND c");
+        test_to_sql("a OR (b AND c)", "a OR b AND c");
+        test_to_sql(
+            "name = 'x' COLLATE utf8_bin",
+            "`name` = 'x' COLLATE utf8_bin",
+        );
+        test_to_sql("(a + b) COLLATE utf8_bin", "(a + b) COLLATE utf8_bin");
+        test_to_sql("BINARY a = b", "BINARY a = b");
+        test_to_sql("a = BINARY (b + c)", "a = BINARY (b + c)");
+        test_to_sql(
+            "t.a IN (1, 'x''y') AND b IS NOT NULL",
+            "t.a IN (1, 'x''y') AND b IS NOT NULL",
+        );
+        test_to_sql(
+            "CASE WHEN a THEN CONCAT(b, 2) ELSE COUNT(*) END",
+            "CASE WHEN a THEN CONCAT(b, 2) ELSE COUNT(*) END",
+        );
+        test_to_sql("doc->'$.a' = 1", "doc->'$.a' = 1");
+        test_to_sql("db.my_func(a, 1) + 2", "db.my_func(a, 1) + 2");
+        test_to_sql("SUBSTRING(a FROM 2 FOR 3)", "SUBSTR(a, 2, 3)");
+        test_to_sql("TRIM(a)", "TRIM(a)");
+        test_to_sql("TRIM('x' FROM a)", "TRIM('x' FROM a)");
+        test_to_sql("TRIM(LEADING FROM a)", "TRIM(LEADING FROM a)");
+        test_to_sql("TRIM(BOTH 'x' FROM a)", "TRIM(BOTH 'x' FROM a)");
+        test_to_sql("trim + 1", "`trim` + 1");
+        test_to_sql("-t.doc->>'$.b'", "-t.doc->>'$.b'");
+        test_to_sql("a = TRUE OR b <> false", "a = TRUE OR b != FALSE");
+        test_to_sql("a IS NOT UNKNOWN = unknown", "a IS NOT UNKNOWN = `unknown`");
+        test_to_sql("unknown.a", "`unknown`.a");
+        test_to_sql("`a-b` + `c`", "`a-b` + `c`");
+        test_to_sql(r"a = 'x\'y\\z''w'", r"a = 'x\'y\\z''w'");
+        test_to_sql(r"a LIKE 'x\%'", r"a LIKE 'x\%'");
+        test_to_sql(r"'a\nb'", r"'a\nb'");
+        test_to_sql("_utf8mb4'x' = a", "_utf8mb4'x' = a");
+        test_to_sql(
+            "_latin1 'x' COLLATE latin1_bin",
+            "_latin1'x' COLLATE latin1_bin",
+        );
+        test_to_sql(
+            "CONVERT(a USING utf8mb4) = b",
+            "CONVERT(a USING utf8mb4) = b",
+        );
+        test_to_sql(
+            "GROUP_CONCAT(DISTINCT a ORDER BY b DESC, c SEPARATOR ', ')",
+            "GROUP_CONCAT(DISTINCT a ORDER BY b DESC, c SEPARATOR ', ')",
+        );
+        test_to_sql(
+            "COUNT(DISTINCT a) + COUNT(ALL b)",
+            "COUNT(DISTINCT a) + COUNT(ALL b)",
+        );
+        test_to_sql(
+            "SUM(DISTINCT a) / MAX(ALL b)",
+            "SUM(DISTINCT a) / MAX(ALL b)",
+        );
+        test_to_sql("MIN(a)", "MIN(a)");
+        test_to_sql("AVG(DISTINCT a)", "AVG(DISTINCT a)");
+        test_to_sql("COUNT(DISTINCT a, b)", "COUNT(DISTINCT a, b)");
+        test_to_sql(
+            "BIT_XOR(a) + STDDEV_SAMP(b) + VAR_POP(ALL c)",
+            "BIT_XOR(a) + STDDEV_SAMP(b) + VAR_POP(ALL c)",
+        );
+        test_to_sql("DATE_ADD(d, INTERVAL 1 DAY)", "DATE_ADD(d, INTERVAL 1 DAY)");
+        test_to_sql(
+            "d - INTERVAL (a + 1) HOUR_MINUTE",
+            "d - INTERVAL (a + 1) HOUR_MINUTE",
+        );
+        test_to_sql("LEFT(a, 2)", "LEFT(a, 2)");
+        test_to_sql(
+            "JSON_VALUE(doc, '$.x' DEFAULT 0 ON EMPTY NULL ON ERROR)",
+            "JSON_VALUE(doc, '$.x' DEFAULT 0 ON EMPTY NULL ON ERROR)",
+        );
+        test_to_sql("JSON_QUERY(doc, '$.y')", "JSON_QUERY(doc, '$.y')");
+        test_to_sql(
+            "GROUP_CONCAT(a ORDER BY b COLLATE utf8_bin DESC, c)",
+            "GROUP_CONCAT(a ORDER BY b COLLATE utf8_bin DESC, c)",
+        );
+    }
 }
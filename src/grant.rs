@@ -0,0 +1,410 @@
+use alloc::vec::Vec;
+
+use crate::{
+    alter::parse_cols,
+    create::parse_account_name,
+    keywords::Keyword,
+    lexer::Token,
+    parser::{ParseError, Parser},
+    Identifier, QualifiedName, SString, Span, Spanned, SpansMut,
+};
+
+/// Privilege granted or revoked
+#[derive(Clone, Debug, PartialEq)]
+pub struct Privilege<'a> {
+    /// Words naming the privilege, for instance "SELECT" or "ALTER ROUTINE",
+    /// never empty
+    pub name: Vec<Identifier<'a>>,
+    /// Columns the privilege is restricted to, all columns if empty
+    pub columns: Vec<Identifier<'a>>,
+}
+
+impl<'a> Spanned for Privilege<'a> {
+    fn span(&self) -> Span {
+        self.name[0].join_span(&self.name).join_span(&self.columns)
+    }
+}
+
+impl<'a> SpansMut for Privilege<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.name.spans_mut(f);
+        self.columns.spans_mut(f);
+    }
+}
+
+/// Kind of object specified before the privilege level
+#[derive(Clone, Debug, PartialEq)]
+pub enum GrantObjectType {
+    /// Span of "TABLE"
+    Table(Span),
+    /// Span of "FUNCTION"
+    Function(Span),
+    /// Span of "PROCEDURE"
+    Procedure(Span),
+}
+
+impl Spanned for GrantObjectType {
+    fn span(&self) -> Span {
+        match &self {
+            GrantObjectType::Table(v) => v.span(),
+            GrantObjectType::Function(v) => v.span(),
+            GrantObjectType::Procedure(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for GrantObjectType {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            GrantObjectType::Table(v) => v.spans_mut(f),
+            GrantObjectType::Function(v) => v.spans_mut(f),
+            GrantObjectType::Procedure(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// What the privileges apply to
+#[derive(Clone, Debug, PartialEq)]
+pub enum GrantLevel<'a> {
+    /// Span of "*", all tables of the current database
+    CurrentDatabase(Span),
+    /// Span of "*.*", all databases
+    Global(Span),
+    /// All tables of the given database, and the span of ".*"
+    Database(Identifier<'a>, Span),
+    /// A single table or routine
+    Object(QualifiedName<'a>),
+}
+
+impl<'a> Spanned for GrantLevel<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            GrantLevel::CurrentDatabase(v) => v.span(),
+            GrantLevel::Global(v) => v.span(),
+            GrantLevel::Database(d, v) => d.join_span(v),
+            GrantLevel::Object(v) => v.span(),
+        }
+    }
+}
+
+impl<'a> SpansMut for GrantLevel<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            GrantLevel::CurrentDatabase(v) => v.spans_mut(f),
+            GrantLevel::Global(v) => v.spans_mut(f),
+            GrantLevel::Database(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            GrantLevel::Object(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Account privileges are granted to or revoked from
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grantee<'a> {
+    /// Name of the user
+    pub user: Identifier<'a>,
+    /// Span of "@" and the host if specified
+    pub host: Option<(Span, Identifier<'a>)>,
+    /// Span of "IDENTIFIED BY [PASSWORD]", the password and whether
+    /// "PASSWORD" was given, if specified
+    pub identified_by: Option<(Span, SString<'a>, bool)>,
+}
+
+impl<'a> Spanned for Grantee<'a> {
+    fn span(&self) -> Span {
+        self.user
+            .join_span(&self.host)
+            .join_span(&self.identified_by.as_ref().map(|(s, v, _)| s.join_span(v)))
+    }
+}
+
+impl<'a> SpansMut for Grantee<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.user.spans_mut(f);
+        self.host.spans_mut(f);
+        if let Some((span, password, _)) = &mut self.identified_by {
+            span.spans_mut(f);
+            password.spans_mut(f);
+        }
+    }
+}
+
+/// Represent a grant statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Grant, GrantLevel, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "GRANT SELECT, INSERT (`a`, `b`), ALTER ROUTINE ON `db`.* TO 'u'@'h'
+///     WITH GRANT OPTION;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let grant: Grant = match stmts.pop() {
+///     Some(Statement::Grant(g)) => g,
+///     _ => panic!("We should get a grant statement")
+/// };
+///
+/// assert!(grant.privileges.len() == 3);
+/// assert!(grant.privileges[1].columns.len() == 2);
+/// assert!(grant.privileges[2].name.len() == 2);
+/// assert!(matches!(&grant.level, GrantLevel::Database(d, _) if d.as_str() == "db"));
+/// assert!(grant.grantees[0].user.as_str() == "u");
+/// assert!(grant.grantees[0].host.as_ref().unwrap().1.as_str() == "h");
+/// assert!(grant.with_grant_option.is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grant<'a> {
+    /// Span of "GRANT"
+    pub grant_span: Span,
+    /// Privileges to grant
+    pub privileges: Vec<Privilege<'a>>,
+    /// Span of "ON"
+    pub on_span: Span,
+    /// Kind of object if specified
+    pub object_type: Option<GrantObjectType>,
+    /// What the privileges apply to
+    pub level: GrantLevel<'a>,
+    /// Span of "TO"
+    pub to_span: Span,
+    /// Accounts to grant the privileges to
+    pub grantees: Vec<Grantee<'a>>,
+    /// Span of "WITH GRANT OPTION" if specified
+    pub with_grant_option: Option<Span>,
+}
+
+impl<'a> Spanned for Grant<'a> {
+    fn span(&self) -> Span {
+        self.grant_span
+            .join_span(&self.privileges)
+            .join_span(&self.on_span)
+            .join_span(&self.object_type)
+            .join_span(&self.level)
+            .join_span(&self.to_span)
+            .join_span(&self.grantees)
+            .join_span(&self.with_grant_option)
+    }
+}
+
+impl<'a> SpansMut for Grant<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.grant_span.spans_mut(f);
+        self.privileges.spans_mut(f);
+        self.on_span.spans_mut(f);
+        self.object_type.spans_mut(f);
+        self.level.spans_mut(f);
+        self.to_span.spans_mut(f);
+        self.grantees.spans_mut(f);
+        self.with_grant_option.spans_mut(f);
+    }
+}
+
+/// Represent a revoke statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Revoke, GrantLevel, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "REVOKE ALL PRIVILEGES ON *.* FROM 'u'@'%';";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let revoke: Revoke = match stmts.pop() {
+///     Some(Statement::Revoke(r)) => r,
+///     _ => panic!("We should get a revoke statement")
+/// };
+///
+/// assert!(revoke.privileges[0].name.len() == 2);
+/// assert!(matches!(revoke.level, GrantLevel::Global(_)));
+/// assert!(revoke.grantees[0].host.as_ref().unwrap().1.as_str() == "%");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Revoke<'a> {
+    /// Span of "REVOKE"
+    pub revoke_span: Span,
+    /// Privileges to revoke
+    pub privileges: Vec<Privilege<'a>>,
+    /// Span of "ON"
+    pub on_span: Span,
+    /// Kind of object if specified
+    pub object_type: Option<GrantObjectType>,
+    /// What the privileges apply to
+    pub level: GrantLevel<'a>,
+    /// Span of "FROM"
+    pub from_span: Span,
+    /// Accounts to revoke the privileges from
+    pub grantees: Vec<Grantee<'a>>,
+}
+
+impl<'a> Spanned for Revoke<'a> {
+    fn span(&self) -> Span {
+        self.revoke_span
+            .join_span(&self.privileges)
+            .join_span(&self.on_span)
+            .join_span(&self.object_type)
+            .join_span(&self.level)
+            .join_span(&self.from_span)
+            .join_span(&self.grantees)
+    }
+}
+
+impl<'a> SpansMut for Revoke<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.revoke_span.spans_mut(f);
+        self.privileges.spans_mut(f);
+        self.on_span.spans_mut(f);
+        self.object_type.spans_mut(f);
+        self.level.spans_mut(f);
+        self.from_span.spans_mut(f);
+        self.grantees.spans_mut(f);
+    }
+}
+
+fn parse_privileges<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<Privilege<'a>>, ParseError> {
+    let mut privileges = Vec::new();
+    loop {
+        let mut name = Vec::new();
+        loop {
+            match &parser.token {
+                Token::Ident(_, Keyword::ON) => break,
+                Token::Ident(v, _) => {
+                    let v = *v;
+                    name.push(Identifier::new(v, parser.consume()));
+                }
+                _ => break,
+            }
+        }
+        if name.is_empty() {
+            parser.expected_failure("privilege")?;
+        }
+        let columns = if matches!(parser.token, Token::LParen) {
+            parse_cols(parser)?
+        } else {
+            Vec::new()
+        };
+        privileges.push(Privilege { name, columns });
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
+    }
+    Ok(privileges)
+}
+
+fn parse_object<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<(Span, Option<GrantObjectType>, GrantLevel<'a>), ParseError> {
+    let on_span = parser.consume_keyword(Keyword::ON)?;
+    let object_type = match &parser.token {
+        Token::Ident(_, Keyword::TABLE) => Some(GrantObjectType::Table(parser.consume())),
+        Token::Ident(_, Keyword::FUNCTION) => Some(GrantObjectType::Function(parser.consume())),
+        Token::Ident(_, Keyword::PROCEDURE) => Some(GrantObjectType::Procedure(parser.consume())),
+        _ => None,
+    };
+    let level = if let Some(star_span) = parser.skip_token(Token::Mul) {
+        match parser.skip_token(Token::Period) {
+            Some(_) => GrantLevel::Global(parser.consume_token(Token::Mul)?.join_span(&star_span)),
+            None => GrantLevel::CurrentDatabase(star_span),
+        }
+    } else {
+        let identifier = parser.consume_plain_identifier()?;
+        match parser.skip_token(Token::Period) {
+            Some(period_span) => match parser.skip_token(Token::Mul) {
+                Some(star_span) => {
+                    GrantLevel::Database(identifier, period_span.join_span(&star_span))
+                }
+                None => GrantLevel::Object(QualifiedName {
+                    prefix: alloc::vec![(identifier, period_span)],
+                    identifier: parser.consume_plain_identifier()?,
+                }),
+            },
+            None => GrantLevel::Object(QualifiedName {
+                prefix: Vec::new(),
+                identifier,
+            }),
+        }
+    };
+    Ok((on_span, object_type, level))
+}
+
+fn parse_grantees<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<Grantee<'a>>, ParseError> {
+    let mut grantees = Vec::new();
+    loop {
+        // TODO CURRENT_USER | role
+        let user = parse_account_name(parser)?;
+        let host = match parser.skip_token(Token::At) {
+            Some(at_span) => Some((at_span, parse_account_name(parser)?)),
+            None => None,
+        };
+        let identified_by = match parser.skip_keyword(Keyword::IDENTIFIED) {
+            Some(identified_span) => {
+                let span = parser
+                    .consume_keyword(Keyword::BY)?
+                    .join_span(&identified_span);
+                let password = parser.skip_keyword(Keyword::PASSWORD);
+                Some((
+                    span.join_span(&password),
+                    parser.consume_string()?,
+                    password.is_some(),
+                ))
+            }
+            None => None,
+        };
+        grantees.push(Grantee {
+            user,
+            host,
+            identified_by,
+        });
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
+    }
+    Ok(grantees)
+}
+
+pub(crate) fn parse_grant<'a>(parser: &mut Parser<'a, '_>) -> Result<Grant<'a>, ParseError> {
+    let grant_span = parser.consume_keyword(Keyword::GRANT)?;
+    let privileges = parse_privileges(parser)?;
+    let (on_span, object_type, level) = parse_object(parser)?;
+    let to_span = parser.consume_keyword(Keyword::TO)?;
+    let grantees = parse_grantees(parser)?;
+    let with_grant_option = match parser.skip_keyword(Keyword::WITH) {
+        Some(with_span) => Some(
+            parser
+                .consume_keywords(&[Keyword::GRANT, Keyword::OPTION])?
+                .join_span(&with_span),
+        ),
+        None => None,
+    };
+    Ok(Grant {
+        grant_span,
+        privileges,
+        on_span,
+        object_type,
+        level,
+        to_span,
+        grantees,
+        with_grant_option,
+    })
+}
+
+pub(crate) fn parse_revoke<'a>(parser: &mut Parser<'a, '_>) -> Result<Revoke<'a>, ParseError> {
+    let revoke_span = parser.consume_keyword(Keyword::REVOKE)?;
+    let privileges = parse_privileges(parser)?;
+    let (on_span, object_type, level) = parse_object(parser)?;
+    let from_span = parser.consume_keyword(Keyword::FROM)?;
+    let grantees = parse_grantees(parser)?;
+    Ok(Revoke {
+        revoke_span,
+        privileges,
+        on_span,
+        object_type,
+        level,
+        from_span,
+        grantees,
+    })
+}
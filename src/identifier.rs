@@ -10,14 +10,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Span, Spanned};
+use alloc::borrow::Cow;
+
+use crate::{Span, Spanned, SpansMut};
 
 /// Simple identifier in code
 /// it derefs to its string value
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Identifier<'a> {
-    /// Identifier string
-    pub value: &'a str,
+    /// Identifier string, case folded if requested by
+    /// [crate::ParseOptions::fold_identifier_case]. Only folded or renamed
+    /// identifiers own their string
+    pub value: Cow<'a, str>,
     /// Span of the value
     pub span: Span,
 }
@@ -25,20 +29,61 @@ pub struct Identifier<'a> {
 impl<'a> Identifier<'a> {
     /// Produce new identifier given value and span
     pub fn new(value: &'a str, span: Span) -> Self {
-        Identifier { value, span }
+        Identifier {
+            value: Cow::Borrowed(value),
+            span,
+        }
+    }
+
+    /// Was the identifier quoted in the source, i.e. `name` or "name"
+    ///
+    /// This is derived from the span, which for quoted identifiers also
+    /// covers the quotes, so it is only meaningful for parsed identifiers
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let sql = "DROP TABLE `t1`, t2";
+    /// let stmt = parse_statement(sql, &mut issues, &options);
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let d = match stmt {
+    ///     Some(Statement::DropTable(d)) => d,
+    ///     _ => panic!("We should get a drop table statement")
+    /// };
+    /// assert!(d.tables[0].identifier.is_quoted());
+    /// assert!(!d.tables[1].identifier.is_quoted());
+    /// ```
+    pub fn is_quoted(&self) -> bool {
+        matches!(self.value, Cow::Borrowed(v) if self.span.len() > v.len())
     }
 
     /// Get the string representation of the identifier
-    pub fn as_str(&self) -> &'a str {
-        self.value
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, ParseOptions, IdentifierCase, parse_statement, Statement};
+    /// let options = ParseOptions::new()
+    ///     .dialect(SQLDialect::MariaDB)
+    ///     .fold_identifier_case(IdentifierCase::Lower);
+    /// # let mut issues = Vec::new();
+    /// let sql = "DROP TABLE Users";
+    /// let d = match parse_statement(sql, &mut issues, &options) {
+    ///     Some(Statement::DropTable(d)) => d,
+    ///     _ => panic!("We should get a drop table statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// assert_eq!(d.tables[0].identifier.as_str(), "users");
+    /// assert_eq!(&sql[d.tables[0].identifier.span.clone()], "Users");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.value
     }
 }
 
 impl<'a> core::ops::Deref for Identifier<'a> {
     type Target = str;
 
-    fn deref(&self) -> &'a Self::Target {
-        self.value
+    fn deref(&self) -> &Self::Target {
+        &self.value
     }
 }
 
@@ -47,3 +92,9 @@ impl<'a> Spanned for Identifier<'a> {
         self.span.span()
     }
 }
+
+impl<'a> SpansMut for Identifier<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.span.spans_mut(f);
+    }
+}
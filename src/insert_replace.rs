@@ -18,11 +18,11 @@ use crate::{
     parser::{ParseError, Parser},
     qualified_name::parse_qualified_name,
     select::{parse_select, parse_select_expr, Select, SelectExpr},
-    Identifier, Issue, OptSpanned, QualifiedName, Span, Spanned,
+    Identifier, Issue, OptSpanned, QualifiedName, Span, Spanned, SpansMut,
 };
 
 /// Flags for insert
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum InsertReplaceFlag {
     LowPriority(Span),
     HighPriority(Span),
@@ -41,7 +41,18 @@ impl Spanned for InsertReplaceFlag {
     }
 }
 
-#[derive(Clone, Debug)]
+impl SpansMut for InsertReplaceFlag {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            InsertReplaceFlag::LowPriority(v) => v.spans_mut(f),
+            InsertReplaceFlag::HighPriority(v) => v.spans_mut(f),
+            InsertReplaceFlag::Delayed(v) => v.spans_mut(f),
+            InsertReplaceFlag::Ignore(v) => v.spans_mut(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum InsertReplaceType {
     Insert(Span),
     Replace(Span),
@@ -56,7 +67,16 @@ impl Spanned for InsertReplaceType {
     }
 }
 
-#[derive(Clone, Debug)]
+impl SpansMut for InsertReplaceType {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            InsertReplaceType::Insert(v) => v.spans_mut(f),
+            InsertReplaceType::Replace(v) => v.spans_mut(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum OnConflictTarget<'a> {
     Column {
         name: Identifier<'a>,
@@ -68,6 +88,22 @@ pub enum OnConflictTarget<'a> {
     None,
 }
 
+impl<'a> SpansMut for OnConflictTarget<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            OnConflictTarget::Column { name } => name.spans_mut(f),
+            OnConflictTarget::OnConstraint {
+                on_constraint_span,
+                name,
+            } => {
+                on_constraint_span.spans_mut(f);
+                name.spans_mut(f);
+            }
+            OnConflictTarget::None => (),
+        }
+    }
+}
+
 impl<'a> OptSpanned for OnConflictTarget<'a> {
     fn opt_span(&self) -> Option<Span> {
         match self {
@@ -81,7 +117,7 @@ impl<'a> OptSpanned for OnConflictTarget<'a> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum OnConflictAction<'a> {
     DoNothing(Span),
     DoUpdateSet {
@@ -104,7 +140,24 @@ impl<'a> Spanned for OnConflictAction<'a> {
     }
 }
 
-#[derive(Clone, Debug)]
+impl<'a> SpansMut for OnConflictAction<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            OnConflictAction::DoNothing(v) => v.spans_mut(f),
+            OnConflictAction::DoUpdateSet {
+                do_update_set_span,
+                sets,
+                where_,
+            } => {
+                do_update_set_span.spans_mut(f);
+                sets.spans_mut(f);
+                where_.spans_mut(f);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct OnConflict<'a> {
     pub on_conflict_span: Span,
     pub target: OnConflictTarget<'a>,
@@ -119,7 +172,15 @@ impl<'a> Spanned for OnConflict<'a> {
     }
 }
 
-#[derive(Clone, Debug)]
+impl<'a> SpansMut for OnConflict<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.on_conflict_span.spans_mut(f);
+        self.target.spans_mut(f);
+        self.action.spans_mut(f);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct InsertReplaceSetPair<'a> {
     pub column: Identifier<'a>,
     pub equal_span: Span,
@@ -134,7 +195,15 @@ impl<'a> Spanned for InsertReplaceSetPair<'a> {
     }
 }
 
-#[derive(Clone, Debug)]
+impl<'a> SpansMut for InsertReplaceSetPair<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.column.spans_mut(f);
+        self.equal_span.spans_mut(f);
+        self.value.spans_mut(f);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct InsertReplaceSet<'a> {
     pub set_span: Span,
     pub pairs: Vec<InsertReplaceSetPair<'a>>,
@@ -146,7 +215,14 @@ impl<'a> Spanned for InsertReplaceSet<'a> {
     }
 }
 
-#[derive(Clone, Debug)]
+impl<'a> SpansMut for InsertReplaceSet<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.set_span.spans_mut(f);
+        self.pairs.spans_mut(f);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct InsertReplaceOnDuplicateKeyUpdate<'a> {
     pub on_duplicate_key_update_span: Span,
     pub pairs: Vec<InsertReplaceSetPair<'a>>,
@@ -158,6 +234,85 @@ impl<'a> Spanned for InsertReplaceOnDuplicateKeyUpdate<'a> {
     }
 }
 
+impl<'a> SpansMut for InsertReplaceOnDuplicateKeyUpdate<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.on_duplicate_key_update_span.spans_mut(f);
+        self.pairs.spans_mut(f);
+    }
+}
+
+// Expressions are large, so only two are kept inline. A row then takes about
+// the memory of a vector of two values and its allocation, while a larger
+// inline capacity would waste most of every short row
+#[cfg(feature = "smallvec")]
+type RowValues<'a> = smallvec::SmallVec<[Expression<'a>; 2]>;
+#[cfg(not(feature = "smallvec"))]
+type RowValues<'a> = Vec<Expression<'a>>;
+
+/// Tuple of values in "VALUES"
+///
+/// With the "smallvec" feature enabled rows of up to two values are stored
+/// inline, avoiding an allocation per row when parsing bulk inserts
+///
+/// ```
+/// # use sql_parse::{Expression, Row};
+/// let row: Row = vec![Expression::Integer((1, 0..1))].into();
+/// assert_eq!(row.len(), 1);
+/// let row: Row = (0..3).map(|i| Expression::Integer((i, 0..1))).collect();
+/// assert_eq!(row.len(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Row<'a>(RowValues<'a>);
+
+impl<'a> From<Vec<Expression<'a>>> for Row<'a> {
+    fn from(values: Vec<Expression<'a>>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<'a> FromIterator<Expression<'a>> for Row<'a> {
+    fn from_iter<T: IntoIterator<Item = Expression<'a>>>(iter: T) -> Self {
+        Row(iter.into_iter().collect())
+    }
+}
+
+impl<'a> SpansMut for Row<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        for value in self.0.iter_mut() {
+            value.spans_mut(f);
+        }
+    }
+}
+
+impl<'a> core::ops::Deref for Row<'a> {
+    type Target = [Expression<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> core::ops::DerefMut for Row<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b Row<'a> {
+    type Item = &'b Expression<'a>;
+    type IntoIter = core::slice::Iter<'b, Expression<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a> OptSpanned for Row<'a> {
+    fn opt_span(&self) -> Option<Span> {
+        (**self).opt_span()
+    }
+}
+
 /// Representation of Insert or Replace Statement
 ///
 /// ```
@@ -197,7 +352,8 @@ impl<'a> Spanned for InsertReplaceOnDuplicateKeyUpdate<'a> {
 /// };
 ///
 /// assert!(r.table.identifier.as_str() == "t2");
-/// println!("{:#?}", r.values.unwrap());
+/// let (_, rows) = r.values.unwrap();
+/// assert!(rows.len() == 2 && rows[1].len() == 2);
 /// ```
 ///
 /// PostgreSQL
@@ -215,7 +371,7 @@ impl<'a> Spanned for InsertReplaceOnDuplicateKeyUpdate<'a> {
 /// }
 /// # assert!(issues.is_empty());
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct InsertReplace<'a> {
     /// Span of "INSERT" or "REPLACE"
     pub type_: InsertReplaceType,
@@ -228,7 +384,7 @@ pub struct InsertReplace<'a> {
     /// List of columns to set
     pub columns: Vec<Identifier<'a>>,
     /// Span of values "VALUES" and list of tuples to insert if specified
-    pub values: Option<(Span, Vec<Vec<Expression<'a>>>)>,
+    pub values: Option<(Span, Vec<Row<'a>>)>,
     /// Select statement to insert if specified
     pub select: Option<Select<'a>>,
     /// Span of "SET" and list of key, value pairs to set if specified
@@ -239,6 +395,8 @@ pub struct InsertReplace<'a> {
     pub on_conflict: Option<OnConflict<'a>>,
     /// Span of "RETURNING" and select expressions after "RETURNING", if "RETURNING" is present
     pub returning: Option<(Span, Vec<SelectExpr<'a>>)>,
+    /// Span of the last token of the statement, see [crate::Statement::outer_span]
+    pub end_span: Span,
 }
 
 impl<'a> Spanned for InsertReplace<'a> {
@@ -253,6 +411,54 @@ impl<'a> Spanned for InsertReplace<'a> {
             .join_span(&self.on_duplicate_key_update)
             .join_span(&self.on_conflict)
             .join_span(&self.returning)
+            .join_span(&self.end_span)
+    }
+}
+
+impl<'a> SpansMut for InsertReplace<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.type_.spans_mut(f);
+        self.flags.spans_mut(f);
+        self.into_span.spans_mut(f);
+        self.table.spans_mut(f);
+        self.columns.spans_mut(f);
+        self.values.spans_mut(f);
+        self.select.spans_mut(f);
+        self.set.spans_mut(f);
+        self.on_duplicate_key_update.spans_mut(f);
+        self.on_conflict.spans_mut(f);
+        self.returning.spans_mut(f);
+        self.end_span.spans_mut(f);
+    }
+}
+
+impl<'a> InsertReplace<'a> {
+    /// Columns written by the statement
+    ///
+    /// These are the explicit column list or the targets of "SET". Returns None
+    /// when values or a select are given without a column list, as the written
+    /// columns then depend on the table definition
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// let columns = |sql| match parse_statement(sql, &mut Vec::new(), &options) {
+    ///     Some(Statement::InsertReplace(i)) => i
+    ///         .written_columns()
+    ///         .map(|c| c.iter().map(|c| c.as_str().to_string()).collect::<Vec<_>>()),
+    ///     _ => panic!("We should get a replace statement")
+    /// };
+    /// assert_eq!(columns("REPLACE INTO `t1` (`a`, `b`) VALUES (1, 2)").unwrap(), ["a", "b"]);
+    /// assert_eq!(columns("REPLACE `t1` SET `c` = 3").unwrap(), ["c"]);
+    /// assert!(columns("REPLACE INTO `t1` VALUES (1, 2)").is_none());
+    /// ```
+    pub fn written_columns(&self) -> Option<Vec<&Identifier<'a>>> {
+        if let Some(set) = &self.set {
+            Some(set.pairs.iter().map(|pair| &pair.column).collect())
+        } else if !self.columns.is_empty() {
+            Some(self.columns.iter().collect())
+        } else {
+            None
+        }
     }
 }
 
@@ -336,7 +542,7 @@ pub(crate) fn parse_insert_replace<'a>(
             let values_span = parser.consume();
             let mut values_items = Vec::new();
             loop {
-                let mut vals = Vec::new();
+                let mut vals = RowValues::new();
                 parser.consume_token(Token::LParen)?;
                 parser.recovered(")", &|t| t == &Token::RParen, |parser| {
                     loop {
@@ -348,7 +554,7 @@ pub(crate) fn parse_insert_replace<'a>(
                     Ok(())
                 })?;
                 parser.consume_token(Token::RParen)?;
-                values_items.push(vals);
+                values_items.push(Row(vals));
                 if parser.skip_token(Token::Comma).is_none() {
                     break;
                 }
@@ -528,5 +734,6 @@ pub(crate) fn parse_insert_replace<'a>(
         on_duplicate_key_update,
         on_conflict,
         returning,
+        end_span: parser.last_span.clone(),
     })
 }
@@ -10,10 +10,54 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use alloc::{string::String, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::Display;
 
 use crate::{Span, Spanned};
 
+/// Message of an issue
+///
+/// Messages that are known at compile time are stored without allocating,
+/// use the [Display] implementation to obtain the text
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Message {
+    /// A static message
+    Static(&'static str),
+    /// A message built at runtime
+    Owned(String),
+    /// Expected something at the span of the issue, but found something else
+    Expected {
+        /// Description of what was expected
+        expected: &'static str,
+        /// Name of the token that was found instead
+        found: &'static str,
+    },
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Message::Static(v) => f.write_str(v),
+            Message::Owned(v) => f.write_str(v),
+            Message::Expected { expected, found } => {
+                write!(f, "Expected '{}' here, found '{}'", expected, found)
+            }
+        }
+    }
+}
+
+impl From<&'static str> for Message {
+    fn from(v: &'static str) -> Self {
+        Message::Static(v)
+    }
+}
+
+impl From<String> for Message {
+    fn from(v: String) -> Self {
+        Message::Owned(v)
+    }
+}
+
 /// Level of an issues
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Level {
@@ -27,16 +71,16 @@ pub struct Issue {
     /// The level of the issue
     pub level: Level,
     /// The primary message of the issue
-    pub message: String,
+    pub message: Message,
     /// The span to attach the primary message to
     pub span: Span,
     /// List of secondary messages and spans
-    pub fragments: Vec<(String, Span)>,
+    pub fragments: Vec<(Message, Span)>,
 }
 
 impl Issue {
     /// Construct an error with given message and span
-    pub fn err(message: impl Into<String>, span: &impl Spanned) -> Self {
+    pub fn err(message: impl Into<Message>, span: &impl Spanned) -> Self {
         Issue {
             level: Level::Error,
             message: message.into(),
@@ -46,7 +90,7 @@ impl Issue {
     }
 
     /// Construct a warning with given message and span
-    pub fn warn(message: impl Into<String>, span: &impl Spanned) -> Self {
+    pub fn warn(message: impl Into<Message>, span: &impl Spanned) -> Self {
         Issue {
             level: Level::Warning,
             message: message.into(),
@@ -56,8 +100,85 @@ impl Issue {
     }
 
     /// Add a fragment with the given message and span
-    pub fn frag(mut self, message: impl Into<String>, span: &impl Spanned) -> Self {
+    pub fn frag(mut self, message: impl Into<Message>, span: &impl Spanned) -> Self {
         self.fragments.push((message.into(), span.span()));
         self
     }
 }
+
+/// Helpers for inspecting a list of issues by level
+///
+/// ```
+/// # use sql_parse::{SQLDialect, ParseOptions, parse_statement, Issues};
+/// let options = ParseOptions::new()
+///     .dialect(SQLDialect::MariaDB)
+///     .warn_unquoted_identifiers(true);
+/// let mut issues = Vec::new();
+/// parse_statement("SELECT a FROM `t1`", &mut issues, &options);
+/// assert_eq!(issues.warnings().count(), 1);
+/// assert!(!issues.has_errors());
+///
+/// parse_statement("SELECT FROM", &mut issues, &options);
+/// assert!(issues.has_errors());
+/// ```
+pub trait Issues {
+    /// Iterate over the issues with level [Level::Error]
+    fn errors(&self) -> impl Iterator<Item = &Issue>;
+
+    /// Iterate over the issues with level [Level::Warning]
+    fn warnings(&self) -> impl Iterator<Item = &Issue>;
+
+    /// Are there any issues with level [Level::Error]
+    fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+}
+
+impl Issues for [Issue] {
+    fn errors(&self) -> impl Iterator<Item = &Issue> {
+        self.iter().filter(|i| i.level == Level::Error)
+    }
+
+    fn warnings(&self) -> impl Iterator<Item = &Issue> {
+        self.iter().filter(|i| i.level == Level::Warning)
+    }
+}
+
+/// Collapse issues with identical message and span into the first of them
+///
+/// Recovery on malformed input can report the same problem more than once, the
+/// order of the remaining issues is kept and a collapsed issue keeps the highest level
+///
+/// ```
+/// # use sql_parse::{dedupe_issues, Issue, Level};
+/// let mut issues = vec![
+///     Issue::warn("Unknown delimiter", &(0..1)),
+///     Issue::err("Expected ';'", &(4..5)),
+///     Issue::err("Unknown delimiter", &(0..1)),
+///     Issue::err("Expected ';'", &(6..7)),
+/// ];
+/// dedupe_issues(&mut issues);
+/// assert_eq!(issues.len(), 3);
+/// assert_eq!(issues[0].level, Level::Error);
+/// assert_eq!(issues[2].span, 6..7);
+/// ```
+pub fn dedupe_issues(issues: &mut Vec<Issue>) {
+    let mut by_span: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+    let mut kept: Vec<Issue> = Vec::with_capacity(issues.len());
+    for issue in issues.drain(..) {
+        let same_span = by_span
+            .entry((issue.span.start, issue.span.end))
+            .or_default();
+        match same_span
+            .iter()
+            .find(|&&i| kept[i].message == issue.message)
+        {
+            Some(&i) => kept[i].level = kept[i].level.max(issue.level),
+            None => {
+                same_span.push(kept.len());
+                kept.push(issue);
+            }
+        }
+    }
+    *issues = kept;
+}
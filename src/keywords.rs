@@ -121,7 +121,10 @@ BIN
 BINARY
 BINLOG
 BIT
+BIT_AND
 BIT_LENGTH
+BIT_OR
+BIT_XOR
 BLOB
 BLOCK
 BODY
@@ -176,6 +179,7 @@ COMMITTED
 COMPACT
 COMPLETION
 COMPRESSED
+COMPRESSION
 CONCAT
 CONCAT_WS
 CONCURRENT
@@ -275,10 +279,12 @@ ELT
 EMPTY
 ENABLE
 ENCLOSED
+ENCRYPTION
 END
 ENDS
 ENGINE
 ENGINES
+ENGINE_ATTRIBUTE
 ENUM
 ERROR
 ERRORS
@@ -292,6 +298,7 @@ EXCEPT
 EXCEPTION
 EXCHANGE
 EXCLUDE
+EXCLUSIVE
 EXECUTE
 EXISTS
 EXIT
@@ -381,10 +388,12 @@ INFILE
 INITIAL_SIZE
 INNER
 INOUT
+INPLACE
 INSENSITIVE
 INSERT
 INSERT_METHOD
 INSTALL
+INSTANT
 INSTR
 INT
 INT1
@@ -565,6 +574,7 @@ NEW
 NEXT
 NEXTVAL
 NO
+NOCOPY
 NO_WAIT
 NO_WRITE_TO_BINLOG
 NOCACHE
@@ -581,6 +591,7 @@ NOW
 NOWAIT
 NULL
 NULLIF
+NULLS
 NUMBER
 NUMERIC
 NVARCHAR
@@ -731,6 +742,7 @@ SCHEDULE
 SCHEMA
 SCHEMA_NAME
 SCHEMAS
+SECONDARY_ENGINE_ATTRIBUTE
 SEC_TO_TIME
 SECOND
 SECOND_MICROSECOND
@@ -747,6 +759,7 @@ SET
 SETVAL
 SFORMAT
 SHARE
+SHARED
 SHOW
 SHUTDOWN
 SIGN
@@ -801,6 +814,10 @@ STATS_AUTO_RECALC
 STATS_PERSISTENT
 STATS_SAMPLE_PAGES
 STATUS
+STD
+STDDEV
+STDDEV_POP
+STDDEV_SAMP
 STDIN
 STOP
 STORAGE
@@ -862,6 +879,7 @@ TRANSACTION
 TRANSACTIONAL
 TRIGGER
 TRIGGERS
+TRIM
 TRUE
 TRUNCATE
 TYPE
@@ -899,11 +917,14 @@ UTC_TIME
 UTC_TIMESTAMP
 VALUE
 VALUES
+VAR_POP
+VAR_SAMP
 VARBINARY
 VARCHAR
 VARCHAR2
 VARCHARACTER
 VARIABLES
+VARIANCE
 VARYING
 VERSIONING
 VIA
@@ -10,12 +10,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use alloc::vec::Vec;
+
 use crate::{keywords::Keyword, Span};
 
 /// SQL Token enumeration
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum Token<'a> {
     Ampersand,
+    Arrow,
     At,
     Backslash,
     Caret,
@@ -37,6 +40,7 @@ pub(crate) enum Token<'a> {
     Invalid,
     LBrace,
     LBracket,
+    LongArrow,
     LParen,
     Lt,
     LtEq,
@@ -71,6 +75,7 @@ impl<'a> Token<'a> {
     pub(crate) fn name(&self) -> &'static str {
         match self {
             Token::Ampersand => "'&'",
+            Token::Arrow => "'->'",
             Token::At => "'@'",
             Token::Backslash => "'\\'",
             Token::Caret => "'^'",
@@ -93,6 +98,7 @@ impl<'a> Token<'a> {
             Token::Invalid => "Invalid",
             Token::LBrace => "'{'",
             Token::LBracket => "'['",
+            Token::LongArrow => "'->>'",
             Token::LParen => "'('",
             Token::Lt => "'<'",
             Token::LtEq => "'<='",
@@ -134,23 +140,71 @@ impl<'a> Token<'a> {
         }
     }
 }
+#[derive(Clone)]
 pub(crate) struct Lexer<'a> {
     src: &'a str,
     chars: core::iter::Peekable<core::str::CharIndices<'a>>,
+    /// Span and trimmed content of "/*+ ... */" hint comments skipped since last taken
+    hints: Vec<(Span, &'a str)>,
+    /// Should hint comments be recorded in hints
+    keep_hints: bool,
 }
 
 impl<'a> Lexer<'a> {
-    pub fn new(src: &'a str) -> Self {
+    pub fn new(src: &'a str, keep_hints: bool) -> Self {
         Self {
             src,
             chars: src.char_indices().peekable(),
+            hints: Vec::new(),
+            keep_hints,
         }
     }
 
+    /// Take the hint comments skipped since the last call
+    pub fn take_hints(&mut self) -> Vec<(Span, &'a str)> {
+        core::mem::take(&mut self.hints)
+    }
+
     fn s(&self, span: Span) -> &'a str {
         core::str::from_utf8(&self.src.as_bytes()[span]).unwrap()
     }
 
+    /// Byte offset of the next character
+    fn offset(&mut self) -> usize {
+        match self.chars.peek() {
+            Some((i, _)) => *i,
+            None => self.src.len(),
+        }
+    }
+
+    fn skip_digits(&mut self) {
+        while matches!(self.chars.peek(), Some((_, '0'..='9'))) {
+            self.chars.next();
+        }
+    }
+
+    /// Consume an exponent like "e10" or "E-3" following the digits of a
+    /// number, returns false and consumes nothing if there is none
+    fn skip_exponent(&mut self) -> bool {
+        let mut chars = self.chars.clone();
+        if !matches!(chars.next(), Some((_, 'e' | 'E'))) {
+            return false;
+        }
+        let mut len = 1;
+        if matches!(chars.peek(), Some((_, '+' | '-'))) {
+            chars.next();
+            len += 1;
+        }
+        if !matches!(chars.peek(), Some((_, '0'..='9'))) {
+            return false;
+        }
+        for _ in 0..len {
+            self.chars.next();
+        }
+        self.skip_digits();
+        true
+    }
+
     fn simple_literal(&mut self, start: usize) -> Token<'a> {
         let end = loop {
             match self.chars.peek() {
@@ -216,7 +270,7 @@ impl<'a> Lexer<'a> {
         }
         // Data ends at EOF without NL '\' '.' [NL].
         let span = start..self.src.len();
-        return (self.s(span.clone()), span);
+        (self.s(span.clone()), span)
     }
 
     pub fn next_token(&mut self) -> (Token<'a>, Span) {
@@ -336,8 +390,9 @@ impl<'a> Lexer<'a> {
                         let mut v = self.chars.peek().unwrap().1.to_digit(10).unwrap() as usize;
                         self.chars.next();
                         while matches!(self.chars.peek(), Some((_, '0'..='9'))) {
-                            v = v * 10
-                                + self.chars.peek().unwrap().1.to_digit(10).unwrap() as usize;
+                            v = v.saturating_mul(10).saturating_add(
+                                self.chars.peek().unwrap().1.to_digit(10).unwrap() as usize,
+                            );
                             self.chars.next();
                         }
                         Token::DollarArg(v)
@@ -406,16 +461,31 @@ impl<'a> Lexer<'a> {
                         while !matches!(self.chars.next(), Some((_, '\r' | '\n')) | None) {}
                         continue;
                     }
+                    Some((_, '>')) => {
+                        self.chars.next();
+                        match self.chars.peek() {
+                            Some((_, '>')) => {
+                                self.chars.next();
+                                Token::LongArrow
+                            }
+                            _ => Token::Arrow,
+                        }
+                    }
                     _ => Token::Minus,
                 },
                 '/' => match self.chars.peek() {
                     Some((_, '*')) => {
                         self.chars.next();
+                        let hint = self.keep_hints && matches!(self.chars.peek(), Some((_, '+')));
                         let ok = loop {
                             match self.chars.next() {
-                                Some((_, '*')) => {
+                                Some((i, '*')) => {
                                     if matches!(self.chars.peek(), Some((_, '/'))) {
                                         self.chars.next();
+                                        if hint && i >= start + 3 {
+                                            self.hints
+                                                .push((start..i + 2, self.s(start + 3..i).trim()));
+                                        }
                                         break true;
                                     }
                                 }
@@ -437,7 +507,10 @@ impl<'a> Lexer<'a> {
                 },
                 'x' | 'X' => match self.chars.peek() {
                     Some((_, '\'')) => {
-                        todo!("Hex literal")
+                        // Hex literals are not supported, skip to the closing quote
+                        self.chars.next();
+                        while !matches!(self.chars.next(), Some((_, '\'')) | None) {}
+                        Token::Invalid
                     }
                     _ => self.simple_literal(start),
                 },
@@ -495,39 +568,28 @@ impl<'a> Lexer<'a> {
                         }
                         Some((_, '.')) => {
                             self.chars.next();
-                            break loop {
-                                match self.chars.peek() {
-                                    Some((_, '0'..='9')) => {
-                                        self.chars.next();
-                                    }
-                                    Some((i, _)) => {
-                                        let i = *i;
-                                        break Token::Float(self.s(start..i));
-                                    }
-                                    None => break Token::Float(self.s(start..self.src.len())),
-                                }
-                            };
+                            self.skip_digits();
+                            self.skip_exponent();
+                            let end = self.offset();
+                            break Token::Float(self.s(start..end));
                         }
-                        Some((i, _)) => {
-                            let i = *i;
-                            break Token::Integer(self.s(start..i));
+                        _ => {
+                            let exponent = self.skip_exponent();
+                            let end = self.offset();
+                            if exponent {
+                                break Token::Float(self.s(start..end));
+                            }
+                            break Token::Integer(self.s(start..end));
                         }
-                        None => break Token::Integer(self.s(start..self.src.len())),
                     }
                 },
                 '.' => match self.chars.peek() {
-                    Some((_, '0'..='9')) => loop {
-                        match self.chars.peek() {
-                            Some((_, '0'..='9')) => {
-                                self.chars.next();
-                            }
-                            Some((i, _)) => {
-                                let i = *i;
-                                break Token::Float(self.s(start..i));
-                            }
-                            None => break Token::Float(self.s(start..self.src.len())),
-                        }
-                    },
+                    Some((_, '0'..='9')) => {
+                        self.skip_digits();
+                        self.skip_exponent();
+                        let end = self.offset();
+                        Token::Float(self.s(start..end))
+                    }
                     _ => Token::Period,
                 },
                 _ => Token::Invalid,
@@ -51,52 +51,76 @@ mod create;
 mod data_type;
 mod delete;
 mod drop;
+mod explain;
 mod expression;
+mod grant;
 mod identifier;
 mod insert_replace;
 mod issue;
 mod keywords;
 mod lexer;
+mod load_data;
+mod lock;
 mod parser;
 mod qualified_name;
 mod rename;
+mod rename_identifier;
 mod select;
+mod show;
 mod span;
 mod sstring;
 mod statement;
+mod table_maintenance;
 mod truncate;
 mod update;
 
-pub use data_type::{DataType, DataTypeProperty, Type};
+pub use data_type::{DataType, DataTypeProperty, Timestamp, Type};
 pub use identifier::Identifier;
-pub use issue::{Issue, Level};
+pub use issue::{dedupe_issues, Issue, Issues, Level, Message};
 pub use qualified_name::QualifiedName;
-pub use span::{OptSpanned, Span, Spanned};
+pub use span::{BySemantics, NewSpan, OptSpanned, Span, Spanned, SpansMut};
 pub use sstring::SString;
-pub use statement::{Statement, Union, UnionType, UnionWith};
+pub use statement::{
+    Block, Call, DeclareHandler, HandlerAction, HandlerCondition, Loop, ReleaseSavepoint, Repeat,
+    Rollback, Savepoint, Signal, SignalCondition, SignalConditionInformation, Statement,
+    Transaction, Union, UnionType, UnionWith, Use, While,
+};
 
 pub use alter::{
-    AlterSpecification, AlterTable, ForeignKeyOn, ForeignKeyOnAction, ForeignKeyOnType, IndexCol,
-    IndexOption, IndexType,
+    AlterAlgorithm, AlterLock, AlterSpecification, AlterTable, ColumnPosition, ForeignKeyOn,
+    ForeignKeyOnAction, ForeignKeyOnType, IndexCol, IndexKeyword, IndexOption, IndexType,
 };
 pub use create::{
-    CreateAlgorithm, CreateDefinition, CreateFunction, CreateOption, CreateTable, CreateTrigger,
-    CreateView, TableOption,
+    CreateAlgorithm, CreateDatabase, CreateDefinition, CreateFunction, CreateOption, CreateTable,
+    CreateTableBuilder, CreateTableOnConflict, CreateTablespace, CreateTrigger, CreateView,
+    TableOption, TablespaceOption, TriggerEvent, TriggerGranularity, TriggerTime,
 };
 pub use delete::{Delete, DeleteFlag};
 pub use drop::{
     DropDatabase, DropEvent, DropFunction, DropProcedure, DropServer, DropTable, DropTrigger,
     DropView,
 };
+pub use explain::{Describe, DescribeColumn, Explain, ExplainModifier};
 pub use expression::{
-    BinaryOperator, Expression, Function, IdentifierPart, Is, UnaryOperator, Variable, When,
+    AggregateModifier, BinaryOperator, Expression, Function, IdentifierPart, IntervalUnit, Is,
+    JsonOnResponse, MatchModifier, TrimDirection, UnaryOperator, Variable, When,
 };
+pub use grant::{Grant, GrantLevel, GrantObjectType, Grantee, Privilege, Revoke};
 pub use insert_replace::{
     InsertReplace, InsertReplaceFlag, InsertReplaceOnDuplicateKeyUpdate, InsertReplaceSet,
-    InsertReplaceSetPair, InsertReplaceType, OnConflict, OnConflictAction, OnConflictTarget,
+    InsertReplaceSetPair, InsertReplaceType, OnConflict, OnConflictAction, OnConflictTarget, Row,
+};
+pub use load_data::{
+    LoadData, LoadDataFieldsOption, LoadDataFlag, LoadDataLinesOption, LoadDataOnDuplicate,
 };
+pub use lock::{LockMode, LockTable, LockTables};
 pub use rename::{RenameTable, TableToTable};
-pub use select::{JoinSpecification, JoinType, Select, SelectExpr, SelectFlag, TableReference};
+pub use select::{
+    JoinSpecification, JoinType, NullsOrder, OrderFlag, OrderKey, Select, SelectExpr, SelectFlag,
+    TableReference,
+};
+pub use show::{ShowColumns, ShowCreateTable, ShowDatabases, ShowTables};
+pub use table_maintenance::{TableMaintenance, TableMaintenanceKind, TableMaintenanceOption};
 pub use truncate::TruncateTable;
 pub use update::{Update, UpdateFlag};
 
@@ -131,6 +155,28 @@ pub enum SQLArguments {
     Dollar,
 }
 
+/// How to fold the case of unquoted identifiers
+#[derive(Clone, Debug)]
+pub enum IdentifierCase {
+    /// Keep identifiers as written
+    None,
+    /// Convert unquoted identifiers to lower case
+    Lower,
+    /// Convert unquoted identifiers to upper case
+    Upper,
+}
+
+/// Delimiter separating statements
+///
+/// "//" is not supported, as it starts a line comment
+#[derive(Clone, Debug)]
+pub enum Delimiter {
+    /// Statements are separated by ";"
+    SemiColon,
+    /// Statements are separated by "$$"
+    DoubleDollar,
+}
+
 /// Options used when parsing sql
 #[derive(Clone, Debug)]
 pub struct ParseOptions {
@@ -139,6 +185,13 @@ pub struct ParseOptions {
     warn_unquoted_identifiers: bool,
     warn_none_capital_keywords: bool,
     list_hack: bool,
+    warn_double_quoted_strings: bool,
+    keep_hints: bool,
+    fold_identifier_case: IdentifierCase,
+    default_delimiter: Delimiter,
+    max_statements: Option<usize>,
+    max_input_len: Option<usize>,
+    max_issues: Option<usize>,
 }
 
 impl Default for ParseOptions {
@@ -149,6 +202,13 @@ impl Default for ParseOptions {
             warn_none_capital_keywords: false,
             warn_unquoted_identifiers: false,
             list_hack: false,
+            warn_double_quoted_strings: false,
+            keep_hints: false,
+            fold_identifier_case: IdentifierCase::None,
+            default_delimiter: Delimiter::SemiColon,
+            max_statements: None,
+            max_input_len: None,
+            max_issues: None,
         }
     }
 }
@@ -192,6 +252,128 @@ impl ParseOptions {
     pub fn list_hack(self, list_hack: bool) -> Self {
         Self { list_hack, ..self }
     }
+
+    /// Should we warn about double quoted string literals in MariaDB/Mysql,
+    /// as they become identifiers when ANSI_QUOTES is enabled
+    pub fn warn_double_quoted_strings(self, warn_double_quoted_strings: bool) -> Self {
+        Self {
+            warn_double_quoted_strings,
+            ..self
+        }
+    }
+
+    /// Keep optimizer hint comments "/*+ ... */" following "SELECT"
+    pub fn keep_hints(self, keep_hints: bool) -> Self {
+        Self { keep_hints, ..self }
+    }
+
+    /// Fold the case of unquoted identifiers, the span still refers to the original text
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, ParseOptions, IdentifierCase, parse_statement, Statement};
+    /// let options = ParseOptions::new()
+    ///     .dialect(SQLDialect::MariaDB)
+    ///     .fold_identifier_case(IdentifierCase::Lower);
+    /// # let mut issues = Vec::new();
+    /// let sql = "DROP TABLE Users, `Groups`";
+    /// let d = match parse_statement(sql, &mut issues, &options) {
+    ///     Some(Statement::DropTable(d)) => d,
+    ///     _ => panic!("We should get a drop table statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// assert_eq!(d.tables[0].identifier.as_str(), "users");
+    /// assert_eq!(&sql[d.tables[0].identifier.span.clone()], "Users");
+    /// assert_eq!(d.tables[1].identifier.as_str(), "Groups");
+    /// ```
+    pub fn fold_identifier_case(self, fold_identifier_case: IdentifierCase) -> Self {
+        Self {
+            fold_identifier_case,
+            ..self
+        }
+    }
+
+    /// Delimiter separating statements before any "DELIMITER" directive,
+    /// for sources where the delimiter is known up front
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, ParseOptions, Delimiter, parse_statements, Statement};
+    /// let options = ParseOptions::new()
+    ///     .dialect(SQLDialect::MariaDB)
+    ///     .default_delimiter(Delimiter::DoubleDollar);
+    /// # let mut issues = Vec::new();
+    /// let sql = "CREATE FUNCTION `f`(IN `a` INT) RETURNS INT BEGIN SET `a` = 1; END$$
+    ///     DROP TABLE `t1`$$";
+    /// let stmts = parse_statements(sql, &mut issues, &options);
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// assert!(matches!(stmts[0], Statement::CreateFunction(_)));
+    /// assert!(matches!(stmts[1], Statement::DropTable(_)));
+    /// ```
+    pub fn default_delimiter(self, default_delimiter: Delimiter) -> Self {
+        Self {
+            default_delimiter,
+            ..self
+        }
+    }
+
+    /// Stop with an error after parsing this many statements, by default there is no limit
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, ParseOptions, parse_statements};
+    /// let options = ParseOptions::new()
+    ///     .dialect(SQLDialect::MariaDB)
+    ///     .max_statements(2);
+    /// let mut issues = Vec::new();
+    /// let stmts = parse_statements("SELECT 1; SELECT 2; SELECT 3;", &mut issues, &options);
+    /// assert_eq!(stmts.len(), 2);
+    /// assert_eq!(issues.len(), 1);
+    /// ```
+    pub fn max_statements(self, max_statements: usize) -> Self {
+        Self {
+            max_statements: Some(max_statements),
+            ..self
+        }
+    }
+
+    /// Refuse to parse input longer than this many bytes, by default there is no limit
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, ParseOptions, parse_statement};
+    /// let options = ParseOptions::new()
+    ///     .dialect(SQLDialect::MariaDB)
+    ///     .max_input_len(16);
+    /// let mut issues = Vec::new();
+    /// assert!(parse_statement("SELECT `a` FROM `t1`", &mut issues, &options).is_none());
+    /// assert_eq!(issues.len(), 1);
+    /// ```
+    pub fn max_input_len(self, max_input_len: usize) -> Self {
+        Self {
+            max_input_len: Some(max_input_len),
+            ..self
+        }
+    }
+
+    /// Add at most this many issues while parsing, by default there is no limit
+    ///
+    /// When the limit is reached the last issue is replaced by a "Too many issues"
+    /// error, so the issues do not grow without bound on pathological input
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, ParseOptions, parse_statements, Issues};
+    /// let options = ParseOptions::new()
+    ///     .dialect(SQLDialect::MariaDB)
+    ///     .max_issues(2);
+    /// let mut issues = Vec::new();
+    /// parse_statements("SELECT; SELECT; SELECT; SELECT;", &mut issues, &options);
+    /// assert_eq!(issues.len(), 2);
+    /// assert_eq!(issues[1].message.to_string(), "Too many issues");
+    /// assert!(issues.has_errors());
+    /// ```
+    pub fn max_issues(self, max_issues: usize) -> Self {
+        Self {
+            max_issues: Some(max_issues),
+            ..self
+        }
+    }
 }
 
 /// Construct an "Internal compiler error" issue, containing the current file and line
@@ -216,6 +398,20 @@ macro_rules! issue_todo {
     }};
 }
 
+/// Push an error and return false if src is longer than allowed by the options
+fn check_input_len(src: &str, issues: &mut Vec<Issue>, options: &ParseOptions) -> bool {
+    match options.max_input_len {
+        Some(max_input_len) if src.len() > max_input_len => {
+            issues.push(Issue::err(
+                "Input exceeds the maximum length",
+                &(0..src.len()),
+            ));
+            false
+        }
+        _ => true,
+    }
+}
+
 /// Parse multiple statements,
 /// return an Vec of Statements even if there are parse errors.
 /// The statements are free of errors if no Error issues are
@@ -225,6 +421,9 @@ pub fn parse_statements<'a>(
     issues: &mut Vec<Issue>,
     options: &ParseOptions,
 ) -> Vec<Statement<'a>> {
+    if !check_input_len(src, issues, options) {
+        return Vec::new();
+    }
     let mut parser = Parser::new(src, issues, options);
     statement::parse_statements(&mut parser)
 }
@@ -238,6 +437,9 @@ pub fn parse_statement<'a>(
     issues: &mut Vec<Issue>,
     options: &ParseOptions,
 ) -> Option<Statement<'a>> {
+    if !check_input_len(src, issues, options) {
+        return None;
+    }
     let mut parser = Parser::new(src, issues, options);
     match statement::parse_statement(&mut parser) {
         Ok(Some(v)) => {
@@ -254,6 +456,49 @@ pub fn parse_statement<'a>(
     }
 }
 
+/// The statements and issues produced by [parse]
+///
+/// Optimizer hints are kept on the [Select] statements they belong to
+/// when [ParseOptions::keep_hints] is enabled.
+#[derive(Clone, Debug)]
+pub struct ParseResult<'a> {
+    /// The parsed statements, possibly partial if there are errors
+    pub statements: Vec<Statement<'a>>,
+    /// Issues encountered during parsing
+    pub issues: Vec<Issue>,
+}
+
+impl<'a> ParseResult<'a> {
+    /// Are there any issues with level [Level::Error]
+    pub fn has_errors(&self) -> bool {
+        self.issues.has_errors()
+    }
+
+    /// Return the parsed statements, discarding the issues
+    pub fn into_statements(self) -> Vec<Statement<'a>> {
+        self.statements
+    }
+}
+
+/// Parse multiple statements,
+/// returning the statements along with the issues encountered
+///
+/// ```
+/// # use sql_parse::{SQLDialect, ParseOptions, parse};
+/// let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+///
+/// let result = parse("SELECT 1; SELECT 2;", &options);
+/// assert!(!result.has_errors(), "Issues: {:#?}", result.issues);
+/// assert_eq!(result.into_statements().len(), 2);
+///
+/// assert!(parse("SELECT FROM", &options).has_errors());
+/// ```
+pub fn parse<'a>(src: &'a str, options: &ParseOptions) -> ParseResult<'a> {
+    let mut issues = Vec::new();
+    let statements = parse_statements(src, &mut issues, options);
+    ParseResult { statements, issues }
+}
+
 #[test]
 pub fn test_parse_alter_sql() {
     let sql = "ALTER TABLE `test` ADD COLUMN `test1` VARCHAR (128) NULL DEFAULT NULL";
@@ -362,3 +607,2705 @@ pub fn parse_rename_table_sql_with_schema() {
     // assert!(result.is_none(), "result: {:#?}", &result);
     assert!(issues.is_empty(), "Issues: {:#?}", issues);
 }
+
+#[test]
+pub fn parse_begin_transaction_and_block() {
+    let sql = "BEGIN; COMMIT;
+        DELIMITER $$
+        CREATE FUNCTION `f`(IN `a` INT) RETURNS INT BEGIN SET `a` = 1; END$$
+        DELIMITER ;";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let mut issues = Vec::new();
+    let result = parse_statements(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(matches!(
+        result[0],
+        Statement::Transaction(Transaction::Begin(_))
+    ));
+    match &result[2] {
+        Statement::CreateFunction(f) => {
+            assert!(matches!(f.return_.as_deref(), Some(Statement::Block(_))))
+        }
+        s => panic!("Expected create function got {:#?}", s),
+    }
+}
+
+#[test]
+pub fn parse_create_table_index_definitions() {
+    let sql = "CREATE TABLE `t1` (
+        `id` int(11) NOT NULL,
+        `body` text NOT NULL,
+        `pos` blob NOT NULL,
+        PRIMARY KEY (`id`),
+        UNIQUE KEY `body_prefix` (`body`(10)),
+        FULLTEXT KEY `body_text` (`body`) WITH PARSER ngram COMMENT 'search',
+        KEY `body_small` (`body`(2)) KEY_BLOCK_SIZE=4,
+        SPATIAL INDEX (`pos`),
+        CONSTRAINT `pk` UNIQUE (`id`, `body`(4))
+    )";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let mut issues = Vec::new();
+    let result = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let create = match result {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table got {:#?}", s),
+    };
+    let kinds: Vec<_> = create
+        .create_definitions
+        .iter()
+        .filter_map(|d| match d {
+            CreateDefinition::IndexDefinition { index_type, .. } => Some(index_type),
+            _ => None,
+        })
+        .collect();
+    let index_options: Vec<_> = create
+        .create_definitions
+        .iter()
+        .filter_map(|d| match d {
+            CreateDefinition::IndexDefinition { index_options, .. } => Some(index_options),
+            _ => None,
+        })
+        .collect();
+    assert!(matches!(
+        index_options[2].as_slice(),
+        [IndexOption::WithParser(_, _), IndexOption::Comment(_)]
+    ));
+    assert!(matches!(
+        index_options[3].as_slice(),
+        [IndexOption::KeyBlockSize(_, (4, _))]
+    ));
+    assert!(matches!(
+        kinds.as_slice(),
+        [
+            IndexType::Primary(_),
+            IndexType::Unique(..),
+            IndexType::FullText(..),
+            IndexType::Index(_),
+            IndexType::Spatial(..),
+            IndexType::Unique(..)
+        ]
+    ));
+
+    let sql = "CREATE TABLE `t1` (`body` text NOT NULL, UNIQUE FULLTEXT KEY (`body`))";
+    let mut issues = Vec::new();
+    parse_statement(sql, &mut issues, &options);
+    assert!(issues.len() == 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn parse_create_table_inline_references() {
+    let sql = "CREATE TABLE `t2` (
+        `id` int(11) NOT NULL PRIMARY KEY,
+        `t1_id` int(11) NOT NULL REFERENCES `t1` (`id`) ON DELETE CASCADE ON UPDATE NO ACTION
+    )";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let mut issues = Vec::new();
+    let result = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let create = match result {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table got {:#?}", s),
+    };
+    let properties = match &create.create_definitions[1] {
+        CreateDefinition::ColumnDefinition { data_type, .. } => &data_type.properties,
+        d => panic!("Expected column definition got {:#?}", d),
+    };
+    match properties.last() {
+        Some(DataTypeProperty::References {
+            table, cols, ons, ..
+        }) => {
+            assert_eq!(table.as_str(), "t1");
+            assert_eq!(cols.len(), 1);
+            assert!(matches!(
+                ons.as_slice(),
+                [
+                    ForeignKeyOn {
+                        type_: ForeignKeyOnType::Delete(_),
+                        action: ForeignKeyOnAction::Cascade(_)
+                    },
+                    ForeignKeyOn {
+                        type_: ForeignKeyOnType::Update(_),
+                        action: ForeignKeyOnAction::NoAction(_)
+                    }
+                ]
+            ));
+        }
+        p => panic!("Expected references got {:#?}", p),
+    }
+}
+
+#[test]
+pub fn warn_double_quoted_strings() {
+    let sql = "SELECT 'a', \"b\" FROM `t1`";
+    let options = ParseOptions::new()
+        .dialect(SQLDialect::MariaDB)
+        .warn_double_quoted_strings(true);
+
+    let mut issues = Vec::new();
+    parse_statement(sql, &mut issues, &options);
+    assert!(issues.len() == 1, "Issues: {:#?}", issues);
+    assert!(matches!(issues[0].level, Level::Warning));
+    assert_eq!(&sql[issues[0].span.clone()], "\"b\"");
+
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn parse_time_type_precision() {
+    let sql = "CREATE TABLE `t1` (
+        `a` DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+        `b` TIMESTAMP(3) NULL,
+        `c` TIME(2),
+        `d` DATETIME
+    )";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let create = match stmt {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table got {:#?}", s),
+    };
+    let types: Vec<_> = create
+        .create_definitions
+        .iter()
+        .map(|d| match d {
+            CreateDefinition::ColumnDefinition { data_type, .. } => &data_type.type_,
+            d => panic!("Expected column definition got {:#?}", d),
+        })
+        .collect();
+    assert!(matches!(types[0], Type::DateTime(Some((6, _)))));
+    assert!(matches!(
+        types[1],
+        Type::Timestamp(Timestamp {
+            width: Some((3, _)),
+            ..
+        })
+    ));
+    assert!(matches!(types[2], Type::Time(Some((2, _)))));
+    assert!(matches!(types[3], Type::DateTime(None)));
+
+    let mut issues = Vec::new();
+    parse_statement("CREATE TABLE `t1` (`a` DATETIME(7))", &mut issues, &options);
+    assert!(issues.len() == 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn parse_create_trigger_granularity() {
+    let sql = "CREATE TRIGGER `my_trigger` AFTER INSERT ON `t1` FOR EACH STATEMENT
+        WHEN (NEW.`a` > 0)
+        INSERT INTO `log` (`msg`) VALUES ('inserted')";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    match stmt {
+        Some(Statement::CreateTrigger(c)) => {
+            match c.granularity {
+                TriggerGranularity::Statement(span) => {
+                    assert_eq!(&sql[span], "FOR EACH STATEMENT")
+                }
+                g => panic!("Expected statement granularity got {:#?}", g),
+            }
+            let (when_span, when) = c.when.expect("when");
+            assert_eq!(&sql[when_span], "WHEN");
+            assert_eq!(&sql[when.span()], "NEW.`a` > 0");
+        }
+        s => panic!("Expected create trigger got {:#?}", s),
+    }
+}
+
+#[test]
+pub fn parse_mixed_case_keywords() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let upper = parse_statement(
+        "SELECT `a` FROM `t1` WHERE `b` IS NOT NULL",
+        &mut issues,
+        &options,
+    )
+    .expect("statement");
+    for sql in [
+        "select `a` from `t1` where `b` is not null",
+        "Select `a` From `t1` Where `b` Is Not Null",
+        "sElEcT `a` fRoM `t1` wHeRe `b` iS nOt NuLl",
+    ] {
+        let stmt = parse_statement(sql, &mut issues, &options).expect("statement");
+        assert!(issues.is_empty(), "Issues: {:#?}", issues);
+        assert!(upper.semantic_eq(&stmt), "{} differs", sql);
+    }
+
+    // Unquoted identifiers keep their original case
+    let stmt = parse_statement("select Col from Tbl", &mut issues, &options).expect("statement");
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(stmt.referenced_tables()[0].as_str(), "Tbl");
+}
+
+#[test]
+pub fn parse_signed_literals() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(
+        "REPLACE INTO `t1` VALUES (-1, +2.5, 3 - -4)",
+        &mut issues,
+        &options,
+    );
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    match stmt {
+        Some(Statement::InsertReplace(i)) => {
+            let (_, rows) = i.values.expect("values");
+            assert!(matches!(
+                rows[0][0],
+                Expression::Unary {
+                    op: UnaryOperator::Minus,
+                    ..
+                }
+            ));
+            assert!(matches!(
+                rows[0][1],
+                Expression::Unary {
+                    op: UnaryOperator::Plus,
+                    ..
+                }
+            ));
+            assert!(matches!(
+                rows[0][2],
+                Expression::Binary {
+                    op: BinaryOperator::Subtract,
+                    ..
+                }
+            ));
+        }
+        s => panic!("Expected replace got {:#?}", s),
+    }
+
+    parse_statement(
+        "CREATE TABLE `t1` (`a` int DEFAULT -1, `b` double DEFAULT +2.5)",
+        &mut issues,
+        &options,
+    );
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn parser_peek_n() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let parser = Parser::new("FOR EACH ROW", &mut issues, &options);
+    assert!(matches!(
+        parser.peek_n(0),
+        Token::Ident(_, keywords::Keyword::FOR)
+    ));
+    assert!(matches!(
+        parser.peek_n(1),
+        Token::Ident(_, keywords::Keyword::EACH)
+    ));
+    assert!(matches!(
+        parser.peek_n(2),
+        Token::Ident(_, keywords::Keyword::ROW)
+    ));
+    assert!(matches!(parser.peek_n(3), Token::Eof));
+    assert!(matches!(parser.peek_n(10), Token::Eof));
+    assert!(matches!(
+        parser.token,
+        Token::Ident(_, keywords::Keyword::FOR)
+    ));
+
+    let sql = "SHOW CREATE VIEW `v1`";
+    let mut issues = Vec::new();
+    parse_statement(sql, &mut issues, &options);
+    assert!(issues.len() == 1, "Issues: {:#?}", issues);
+    assert_eq!(&sql[issues[0].span.clone()], "CREATE");
+}
+
+#[test]
+pub fn parse_replace_with_and_without_into() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    for (sql, has_into) in [
+        ("REPLACE `t1` VALUES (1, 'a')", false),
+        ("REPLACE INTO `t1` VALUES (1, 'a')", true),
+        (
+            "REPLACE LOW_PRIORITY `db`.`t1` (`id`, `v`) VALUES (1, 'a')",
+            false,
+        ),
+        ("REPLACE DELAYED INTO `db`.`t1` SET `id` = 1", true),
+    ] {
+        let mut issues = Vec::new();
+        let stmt = parse_statement(sql, &mut issues, &options);
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        match stmt {
+            Some(Statement::InsertReplace(r)) => {
+                assert!(matches!(r.type_, InsertReplaceType::Replace(_)));
+                assert_eq!(r.into_span.is_some(), has_into, "{}", sql);
+                if let Some(into_span) = r.into_span {
+                    assert_eq!(&sql[into_span], "INTO");
+                }
+                assert_eq!(r.table.identifier.as_str(), "t1");
+            }
+            s => panic!("Expected replace got {:#?}", s),
+        }
+    }
+}
+
+#[test]
+pub fn parse_select_hints() {
+    let sql = "SELECT /*+ MAX_EXECUTION_TIME(1000) */ /* plain */ /*+ NO_ICP(t1) */ `a` FROM `t1`";
+    let options = ParseOptions::new()
+        .dialect(SQLDialect::MariaDB)
+        .keep_hints(true);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    match stmt {
+        Some(Statement::Select(s)) => {
+            assert_eq!(s.hints.len(), 2);
+            assert_eq!(s.hints[0].1, "MAX_EXECUTION_TIME(1000)");
+            assert_eq!(
+                &sql[s.hints[0].0.clone()],
+                "/*+ MAX_EXECUTION_TIME(1000) */"
+            );
+            assert_eq!(s.hints[1].1, "NO_ICP(t1)");
+        }
+        s => panic!("Expected select got {:#?}", s),
+    }
+
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => assert!(s.hints.is_empty()),
+        s => panic!("Expected select got {:#?}", s),
+    }
+}
+
+#[test]
+pub fn parse_qualified_column_references() {
+    let sql = "SELECT `a`, `t1`.`b`, `db`.`t1`.`c`, `t1`.* FROM `db`.`t1`";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let select = match stmt {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select got {:#?}", s),
+    };
+    let lens: Vec<_> = select
+        .select_exprs
+        .iter()
+        .map(|e| match &e.expr {
+            Expression::Identifier(parts) => parts.len(),
+            e => panic!("Expected identifier got {:#?}", e),
+        })
+        .collect();
+    assert_eq!(lens, [1, 2, 3, 2]);
+    assert_eq!(&sql[select.select_exprs[2].expr.span()], "`db`.`t1`.`c`");
+
+    for sql in ["SELECT `a`.`b`.`c`.`d`", "SELECT `t1`.*.`a`"] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert!(issues.len() == 1, "Issues for {}: {:#?}", sql, issues);
+    }
+}
+
+#[test]
+pub fn parse_select_wildcards() {
+    let sql = "SELECT *, `t1`.*, `a` + 1 AS `extra` FROM `t1`";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let select = match stmt {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select got {:#?}", s),
+    };
+    assert_eq!(select.select_exprs.len(), 3);
+    match &select.select_exprs[0].expr {
+        Expression::Identifier(parts) => {
+            assert!(matches!(parts.as_slice(), [IdentifierPart::Star(_)]))
+        }
+        e => panic!("Expected wildcard got {:#?}", e),
+    }
+    match &select.select_exprs[1].expr {
+        Expression::Identifier(parts) => match parts.as_slice() {
+            [IdentifierPart::Name(qualifier), IdentifierPart::Star(span)] => {
+                assert_eq!(qualifier.as_str(), "t1");
+                assert_eq!(&sql[span.clone()], "*");
+            }
+            p => panic!("Expected qualified wildcard got {:#?}", p),
+        },
+        e => panic!("Expected wildcard got {:#?}", e),
+    }
+    assert_eq!(&sql[select.select_exprs[1].expr.span()], "`t1`.*");
+}
+
+#[test]
+pub fn parse_select_aliases() {
+    let sql = "SELECT `a` + `b` AS `total`, `c` `d`, `e` f, `g` FROM `t1`";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let select = match stmt {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select got {:#?}", s),
+    };
+    let aliases: Vec<_> = select
+        .select_exprs
+        .iter()
+        .map(|e| (e.as_span.is_some(), e.as_.as_ref().map(|v| v.as_str())))
+        .collect();
+    assert_eq!(
+        aliases,
+        [
+            (true, Some("total")),
+            (false, Some("d")),
+            (false, Some("f")),
+            (false, None)
+        ]
+    );
+
+    let mut issues = Vec::new();
+    parse_statement("SELECT `a` `b` `c` FROM `t1`", &mut issues, &options);
+    assert!(!issues.is_empty());
+
+    // Exponents are part of the number, not an alias
+    let sql = "SELECT 1e30, 2.5E-3 e3, .5e+2, 1ex";
+    let mut issues = Vec::new();
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select got {:#?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let exprs: Vec<_> = select
+        .select_exprs
+        .iter()
+        .map(|e| (&sql[e.expr.span()], e.as_.as_ref().map(|v| v.as_str())))
+        .collect();
+    assert_eq!(
+        exprs,
+        [
+            ("1e30", None),
+            ("2.5E-3", Some("e3")),
+            (".5e+2", None),
+            ("1", Some("ex"))
+        ]
+    );
+    assert!(matches!(select.select_exprs[0].expr, Expression::Float((v, _)) if v == 1e30));
+}
+
+#[test]
+pub fn expected_issue_message() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    parse_statement("ALTER TABLE `t1` FROB", &mut issues, &options);
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+    assert_eq!(
+        issues[0].message,
+        Message::Expected {
+            expected: "alter specification",
+            found: "Identifier"
+        }
+    );
+    assert_eq!(
+        alloc::format!("{}", issues[0].message),
+        "Expected 'alter specification' here, found 'Identifier'"
+    );
+}
+
+#[test]
+pub fn parse_json_extract() {
+    let sql = "SELECT `doc`->'$.a', `t1`.`doc`->>'$.b' FROM `t1` WHERE `doc`->>'$.c' = 'x'";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let select = match stmt {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select got {:#?}", s),
+    };
+    let extracts: Vec<_> = select
+        .select_exprs
+        .iter()
+        .map(|e| match &e.expr {
+            Expression::JsonExtract { unquote, path, .. } => (*unquote, path.as_str()),
+            e => panic!("Expected json extract got {:#?}", e),
+        })
+        .collect();
+    assert_eq!(extracts, [(false, "$.a"), (true, "$.b")]);
+    assert_eq!(
+        &sql[select.select_exprs[1].expr.span()],
+        "`t1`.`doc`->>'$.b'"
+    );
+
+    let mut issues = Vec::new();
+    parse_statement("SELECT `doc`->`a` FROM `t1`", &mut issues, &options);
+    assert!(!issues.is_empty());
+}
+
+#[test]
+pub fn parse_create_table_option_order() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let table_options = |sql: &'static str| {
+        let mut issues = Vec::new();
+        let stmt = parse_statement(sql, &mut issues, &options);
+        assert!(issues.is_empty(), "Issues: {:#?}", issues);
+        let mut table_options: Vec<_> = match stmt {
+            Some(Statement::CreateTable(c)) => c
+                .options
+                .iter()
+                .map(|o| match o {
+                    TableOption::Comment { value, .. } => {
+                        ("COMMENT", alloc::string::String::from(value.as_str()))
+                    }
+                    TableOption::Engine { value, .. } => {
+                        ("ENGINE", alloc::string::String::from(value.as_str()))
+                    }
+                    TableOption::DefaultCharSet { value, .. } => {
+                        ("CHARSET", alloc::string::String::from(value.as_str()))
+                    }
+                    o => panic!("Unexpected option {:#?}", o),
+                })
+                .collect(),
+            s => panic!("Expected create table got {:#?}", s),
+        };
+        table_options.sort();
+        table_options
+    };
+    let a = table_options("CREATE TABLE `t1` (`id` int) COMMENT='x' ENGINE=InnoDB");
+    let b = table_options("CREATE TABLE `t1` (`id` int) ENGINE=InnoDB COMMENT='x'");
+    let c = table_options(
+        "CREATE TABLE `t1` (`id` int) ENGINE=InnoDB COMMENT 'x' DEFAULT CHARSET=utf8mb4",
+    );
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 2);
+    assert_eq!(c.len(), 3);
+    assert!(a.iter().all(|o| c.contains(o)));
+}
+
+#[test]
+pub fn parse_qualified_function_call() {
+    let sql = "SELECT `db`.`my_func`(`a`, 1), my_func(`b`), CONCAT(`a`, `b`) FROM `t1`";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let select = match stmt {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select got {:#?}", s),
+    };
+    match &select.select_exprs[0].expr {
+        Expression::Function(Function::Qualified(name), args, span) => {
+            assert_eq!(name.prefix[0].0.as_str(), "db");
+            assert_eq!(name.identifier.as_str(), "my_func");
+            assert_eq!(args.len(), 2);
+            assert_eq!(&sql[span.clone()], "`db`.`my_func`");
+        }
+        e => panic!("Expected qualified function got {:#?}", e),
+    }
+    assert!(matches!(
+        &select.select_exprs[1].expr,
+        Expression::Function(Function::Other("my_func"), _, _)
+    ));
+    assert!(matches!(
+        &select.select_exprs[2].expr,
+        Expression::Function(Function::Concat, _, _)
+    ));
+}
+
+#[test]
+pub fn parse_column_nullable() {
+    let sql = "CREATE TABLE `t1` (
+        `a` int,
+        `b` int NOT NULL,
+        `c` int NULL,
+        `d` int PRIMARY KEY,
+        `e` int,
+        PRIMARY KEY (`E`)
+    )";
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let create = match stmt {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table got {:#?}", s),
+    };
+    let nullable: Vec<_> = create
+        .create_definitions
+        .iter()
+        .filter_map(|d| match d {
+            CreateDefinition::ColumnDefinition { nullable, .. } => Some(*nullable),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(nullable, [true, false, true, false, false]);
+
+    let mut issues = Vec::new();
+    parse_statement(
+        "CREATE TABLE `t1` (`a` int NULL NOT NULL)",
+        &mut issues,
+        &options,
+    );
+    assert_eq!(issues.len(), 1);
+}
+
+#[test]
+pub fn parse_limit_values() {
+    let options = ParseOptions::new()
+        .dialect(SQLDialect::MariaDB)
+        .arguments(SQLArguments::QuestionMark);
+    for sql in [
+        "SELECT `a` FROM `t1` LIMIT 0",
+        "SELECT `a` FROM `t1` LIMIT 5, 10",
+        "SELECT `a` FROM `t1` LIMIT ? OFFSET ?",
+        "(SELECT `a` FROM `t1`) UNION (SELECT `a` FROM `t2`) LIMIT 1",
+    ] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+    }
+    for (sql, message) in [
+        (
+            "SELECT `a` FROM `t1` LIMIT -1",
+            "LIMIT values cannot be negative",
+        ),
+        (
+            "SELECT `a` FROM `t1` LIMIT 'x'",
+            "LIMIT values must be integers",
+        ),
+        (
+            "SELECT `a` FROM `t1` LIMIT 1.5",
+            "LIMIT values must be integers",
+        ),
+        (
+            "SELECT `a` FROM `t1` LIMIT 1 OFFSET -2",
+            "LIMIT values cannot be negative",
+        ),
+    ] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert_eq!(issues.len(), 1, "Issues for {}: {:#?}", sql, issues);
+        assert_eq!(issues[0].message, Message::Static(message));
+    }
+}
+
+#[test]
+pub fn parse_create_table_default_character_set() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    for sql in [
+        "CREATE TABLE `t1` (`id` int) ENGINE=InnoDB DEFAULT CHARACTER SET = utf8",
+        "CREATE TABLE `t1` (`id` int) ENGINE=InnoDB DEFAULT CHARSET=utf8",
+        "CREATE TABLE `t1` (`id` int) ENGINE=InnoDB CHARACTER SET utf8",
+    ] {
+        let mut issues = Vec::new();
+        let stmt = parse_statement(sql, &mut issues, &options);
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        let create = match stmt {
+            Some(Statement::CreateTable(c)) => c,
+            s => panic!("Expected create table got {:#?}", s),
+        };
+        match &create.options[1] {
+            TableOption::DefaultCharSet { value, .. } | TableOption::CharSet { value, .. } => {
+                assert_eq!(value.as_str(), "utf8")
+            }
+            o => panic!("Expected character set got {:#?}", o),
+        }
+    }
+}
+
+#[test]
+pub fn parse_parenthesized_join() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "SELECT `a`.`id` FROM `a` JOIN (`b` JOIN `c` ON `b`.`id` = `c`.`id`) ON `a`.`id` = `b`.`id`";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let select = match stmt {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select got {:#?}", s),
+    };
+    match &select.table_references.as_deref() {
+        Some([TableReference::Join { left, right, .. }]) => {
+            assert!(matches!(left.as_ref(), TableReference::Table { .. }));
+            assert!(matches!(right.as_ref(), TableReference::Join { .. }));
+        }
+        r => panic!("Expected join got {:#?}", r),
+    }
+
+    for sql in [
+        "SELECT `x`.`id` FROM (SELECT `id` FROM `a`) AS `x`",
+        "SELECT `x`.`id` FROM ((SELECT `id` FROM `a`)) AS `x`",
+    ] {
+        let mut issues = Vec::new();
+        let stmt = parse_statement(sql, &mut issues, &options);
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        let select = match stmt {
+            Some(Statement::Select(s)) => s,
+            s => panic!("Expected select got {:#?}", s),
+        };
+        assert!(matches!(
+            select.table_references.as_deref(),
+            Some([TableReference::Query { .. }])
+        ));
+    }
+}
+
+#[test]
+pub fn parse_bool_literals() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "SELECT `id` FROM `t1` WHERE `active` = TRUE";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    match stmt {
+        Some(Statement::Select(Select {
+            where_: Some((Expression::Binary { rhs, .. }, _)),
+            ..
+        })) => assert!(matches!(*rhs, Expression::Bool(Some(true), _))),
+        s => panic!("Expected select got {:#?}", s),
+    }
+
+    let sql = "CREATE TABLE `t1` (`active` tinyint(1) NOT NULL DEFAULT FALSE)";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let create = match stmt {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table got {:#?}", s),
+    };
+    match &create.create_definitions[0] {
+        CreateDefinition::ColumnDefinition { data_type, .. } => {
+            assert!(data_type.properties.iter().any(|p| matches!(
+                p,
+                DataTypeProperty::Default(e) if matches!(**e, Expression::Bool(Some(false), _))
+            )))
+        }
+        d => panic!("Expected column definition got {:#?}", d),
+    }
+}
+
+#[test]
+pub fn parse_null_literal() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "REPLACE INTO `t1` VALUES (NULL, `b` IS NULL)";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let replace = match stmt {
+        Some(Statement::InsertReplace(r)) => r,
+        s => panic!("Expected replace got {:#?}", s),
+    };
+    let (_, rows) = replace.values.expect("values");
+    match &rows[0][0] {
+        Expression::Null(span) => assert_eq!(&sql[span.clone()], "NULL"),
+        e => panic!("Expected null got {:#?}", e),
+    }
+    match &rows[0][1] {
+        Expression::Is(lhs, Is::Null, _) => {
+            assert!(matches!(**lhs, Expression::Identifier(_)))
+        }
+        e => panic!("Expected is null got {:#?}", e),
+    }
+}
+
+#[test]
+pub fn parse_alter_column_position() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "ALTER TABLE `t1` ADD COLUMN `c` INT AFTER `b`, ADD COLUMN `d` INT NOT NULL FIRST,
+        MODIFY `e` VARCHAR(10), MODIFY COLUMN `f` INT AFTER `a`";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let alter = match stmt {
+        Some(Statement::AlterTable(a)) => a,
+        s => panic!("Expected alter table got {:#?}", s),
+    };
+    let specs = &alter.alter_specifications;
+    assert!(matches!(
+        &specs[0],
+        AlterSpecification::AddColumn {
+            position: Some(ColumnPosition::After(_, col)),
+            ..
+        } if col.as_str() == "b"
+    ));
+    assert!(matches!(
+        &specs[1],
+        AlterSpecification::AddColumn {
+            position: Some(ColumnPosition::First(_)),
+            ..
+        }
+    ));
+    assert!(matches!(
+        &specs[2],
+        AlterSpecification::Modify { position: None, .. }
+    ));
+    assert!(matches!(
+        &specs[3],
+        AlterSpecification::Modify {
+            position: Some(ColumnPosition::After(_, col)),
+            ..
+        } if col.as_str() == "a"
+    ));
+}
+
+#[test]
+pub fn parse_function_return_charset() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "DELIMITER $$
+        CREATE FUNCTION `f`(IN `a` INT) RETURNS VARCHAR(64) CHARSET utf8mb4 COLLATE utf8mb4_bin
+        DETERMINISTIC BEGIN SET `a` = 1; END$$
+        DELIMITER ;";
+    let mut issues = Vec::new();
+    let stmts = parse_statements(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let f = match &stmts[0] {
+        Statement::CreateFunction(f) => f,
+        s => panic!("Expected create function got {:#?}", s),
+    };
+    let properties = &f.return_type.properties;
+    assert!(matches!(
+        &properties[0],
+        DataTypeProperty::Charset(c) if c.as_str() == "utf8mb4"
+    ));
+    assert!(matches!(
+        &properties[1],
+        DataTypeProperty::Collate(c) if c.as_str() == "utf8mb4_bin"
+    ));
+}
+
+#[test]
+pub fn parse_rename_identifier() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    for (sql, from, count) in [
+        (
+            "INSERT INTO `t1` (`a`, `b`) VALUES (1, 2) ON DUPLICATE KEY UPDATE `a` = `a` + 1",
+            "a",
+            3,
+        ),
+        ("UPDATE `t1` SET `a` = `b` WHERE `t1`.`a` > 1", "a", 2),
+        (
+            "CREATE TABLE `t1` (`a` int, `b` int AS (`a` + 1), KEY `k` (`a`))",
+            "a",
+            3,
+        ),
+        (
+            "ALTER TABLE `t1` ADD COLUMN `b` INT AFTER `a`, MODIFY `a` INT",
+            "a",
+            2,
+        ),
+        (
+            "DELETE FROM `t1` WHERE `a` IN (SELECT `a` FROM `t2`)",
+            "a",
+            2,
+        ),
+        ("DROP TABLE `db`.`a`, `a`", "a", 2),
+        ("DROP VIEW `db`.`a`, `a`", "a", 2),
+        ("DROP INDEX `i` ON `a`.`t`", "a", 1),
+        ("SHOW COLUMNS FROM `a` FROM `a`", "a", 2),
+        ("SHOW CREATE TABLE `a`.`t`", "a", 1),
+        ("SHOW TABLES FROM `a`", "a", 1),
+        ("SELECT `a`.`f`(`a`) FROM `t1`", "a", 2),
+        ("DROP FUNCTION `a`", "a", 0),
+    ] {
+        let mut issues = Vec::new();
+        let mut stmt = parse_statement(sql, &mut issues, &options).expect("statement");
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        assert_eq!(
+            stmt.rename_identifier(from, "x"),
+            count,
+            "Renaming in {}",
+            sql
+        );
+        assert_eq!(stmt.rename_identifier(from, "x"), 0, "Renaming in {}", sql);
+    }
+}
+
+#[test]
+pub fn parse_create_or_replace_validation() {
+    let maria = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let postgresql = ParseOptions::new().dialect(SQLDialect::PostgreSQL);
+    for (sql, options, errors) in [
+        ("CREATE OR REPLACE TABLE t1 (id int)", &maria, 0),
+        ("CREATE OR REPLACE VIEW v1 AS SELECT 1", &maria, 0),
+        ("CREATE OR REPLACE INDEX i1 ON t1 (id)", &maria, 0),
+        ("CREATE OR REPLACE VIEW v1 AS SELECT 1", &postgresql, 0),
+        ("CREATE OR REPLACE TABLE t1 (id int)", &postgresql, 1),
+        ("CREATE OR REPLACE INDEX i1 ON t1 (id)", &postgresql, 1),
+    ] {
+        let mut issues = Vec::new();
+        let stmt = parse_statement(sql, &mut issues, options);
+        let or_replace: Vec<_> = issues
+            .iter()
+            .filter(|i| i.message == Message::Static("OR REPLACE is not supported here"))
+            .collect();
+        assert_eq!(
+            or_replace.len(),
+            errors,
+            "Issues for {}: {:#?}",
+            sql,
+            issues
+        );
+        assert!(stmt.is_some(), "Statement for {}", sql);
+    }
+}
+
+#[test]
+pub fn parse_alter_algorithm_lock() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "ALTER TABLE `t1` ADD COLUMN `c` INT, ALGORITHM=INPLACE, LOCK NONE";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let alter = match stmt {
+        Some(Statement::AlterTable(a)) => a,
+        s => panic!("Expected alter table got {:#?}", s),
+    };
+    match &alter.alter_specifications[1] {
+        AlterSpecification::Algorithm {
+            algorithm_span,
+            algorithm: AlterAlgorithm::Inplace(_),
+        } => assert_eq!(&sql[algorithm_span.clone()], "ALGORITHM="),
+        s => panic!("Expected algorithm got {:#?}", s),
+    }
+    assert!(matches!(
+        &alter.alter_specifications[2],
+        AlterSpecification::Lock {
+            lock: AlterLock::None(_),
+            ..
+        }
+    ));
+
+    let sql = "ALTER TABLE `t1` LOCK=FAST";
+    let mut issues = Vec::new();
+    parse_statement(sql, &mut issues, &options);
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn validate_create_table() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    for (sql, errors) in [
+        (
+            "CREATE TABLE `t1` (`id` int AUTO_INCREMENT PRIMARY KEY, `name` text, UNIQUE KEY (`name`))",
+            0,
+        ),
+        (
+            "CREATE TABLE `t1` (`id` int AUTO_INCREMENT, `b` int, PRIMARY KEY (`id`, `b`))",
+            0,
+        ),
+        (
+            "CREATE TABLE `t1` (`id` int PRIMARY KEY, `b` int, PRIMARY KEY (`b`))",
+            1,
+        ),
+        (
+            "CREATE TABLE `t1` (`a` int, `b` int AUTO_INCREMENT, KEY (`a`, `b`))",
+            1,
+        ),
+        (
+            "CREATE TABLE `t1` (`a` int, `A` int, CONSTRAINT `fk` FOREIGN KEY (`a`) REFERENCES `t2` (`x`))",
+            1,
+        ),
+        (
+            "CREATE TABLE `t1` (`a` int, `p` int, CONSTRAINT `fk` FOREIGN KEY (`p`) REFERENCES `T1` (`x`))",
+            1,
+        ),
+    ] {
+        let mut issues = Vec::new();
+        let create = match parse_statement(sql, &mut issues, &options) {
+            Some(Statement::CreateTable(c)) => c,
+            s => panic!("Expected create table got {:#?}", s),
+        };
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        let issues = create.validate();
+        assert_eq!(issues.len(), errors, "Validation of {}: {:#?}", sql, issues);
+    }
+}
+
+#[test]
+pub fn parse_function_characteristic_duplicates() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    for (sql, message) in [
+        (
+            "CREATE FUNCTION `f`(IN `a` INT) RETURNS INT DETERMINISTIC NO SQL COMMENT 'x' BEGIN SET `a` = 1; END",
+            None,
+        ),
+        (
+            "CREATE FUNCTION `f`() RETURNS INT DETERMINISTIC BEGIN SET `a` = 1; END",
+            None,
+        ),
+        (
+            "CREATE FUNCTION `f`(IN `a` INT) RETURNS INT NO SQL COMMENT 'x' COMMENT 'y' BEGIN SET `a` = 1; END",
+            Some("Duplicate characteristic"),
+        ),
+        (
+            "CREATE FUNCTION `f`(IN `a` INT) RETURNS INT DETERMINISTIC NOT DETERMINISTIC BEGIN SET `a` = 1; END",
+            Some("Conflicting characteristic"),
+        ),
+    ] {
+        let mut issues = Vec::new();
+        let stmt = parse_statement(sql, &mut issues, &options);
+        assert!(
+            matches!(stmt, Some(Statement::CreateFunction(_))),
+            "Statement for {}",
+            sql
+        );
+        match message {
+            Some(message) => {
+                assert_eq!(issues.len(), 1, "Issues for {}: {:#?}", sql, issues);
+                assert_eq!(issues[0].message, Message::Static(message));
+            }
+            None => assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues),
+        }
+    }
+}
+
+#[test]
+pub fn statement_outer_span() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    for sql in [
+        "SELECT `a`, `b` FROM `t1`, `t2` WHERE `a` = 1 GROUP BY `a`, `b` HAVING `b` > 2 ORDER BY `a`, `b` DESC LIMIT 4",
+        "SELECT `a` FROM `t1` JOIN `t2` ON `t1`.`id` = `t2`.`id`",
+        "INSERT INTO `t1` (`a`, `b`) VALUES (1, 2), (3, 4) ON DUPLICATE KEY UPDATE `a` = 5",
+        "INSERT INTO `t1` SET `a` = 1, `b` = 2",
+        "INSERT INTO `t1` (`a`) SELECT `b` FROM `t2` ORDER BY `b`",
+        "REPLACE INTO `t1` (`a`) VALUES (1) RETURNING `a`, `b`",
+        "UPDATE `t1` SET `a` = 1 WHERE `b` = 2",
+        "SELECT `a` FROM `t1` WHERE `b` IN (SELECT `b` FROM `t2`) FOR UPDATE NOWAIT",
+        "INSERT INTO `t1` (`a`) VALUES ((SELECT MAX(`a`) FROM `t2`))",
+    ] {
+        let mut issues = Vec::new();
+        let stmt = parse_statement(sql, &mut issues, &options).expect("Statement");
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        assert_eq!(stmt.outer_span(), stmt.span(), "Span of {}", sql);
+        assert_eq!(stmt.outer_span(), 0..sql.len(), "Span of {}", sql);
+    }
+}
+
+#[test]
+pub fn parse_check_constraint_and_checksum() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "CREATE TABLE `t1` (
+        `a` int NOT NULL,
+        `b` int CHECK (`b` > 0),
+        CHECK (`a` < 10),
+        CONSTRAINT `c1` CHECK (`a` <> `b`)
+    ) ENGINE=InnoDB CHECKSUM=1";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let create = match stmt {
+        Some(Statement::CreateTable(c)) => c,
+        _ => panic!("We should get a create table statement"),
+    };
+    assert_eq!(create.create_definitions.len(), 4);
+    assert!(matches!(
+        create.create_definitions[2],
+        CreateDefinition::CheckConstraint {
+            constraint: None,
+            ..
+        }
+    ));
+    match &create.create_definitions[3] {
+        CreateDefinition::CheckConstraint {
+            constraint: Some((_, Some(name))),
+            ..
+        } => assert_eq!(name.as_str(), "c1"),
+        _ => panic!("Expected a named check constraint"),
+    }
+    assert_eq!(create.options.len(), 2);
+    assert!(matches!(
+        create.options[1],
+        TableOption::Checksum {
+            value: (true, _),
+            ..
+        }
+    ));
+
+    let mut issues = Vec::new();
+    parse_statement(
+        "CREATE TABLE `t1` (`a` int) CHECKSUM=2",
+        &mut issues,
+        &options,
+    );
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn parse_aliases() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "SELECT `a` AS `x`, `b` `y` FROM `t1` AS `u` JOIN (SELECT `c` FROM `t2`) `v` ON `u`.`a` = `v`.`c` WHERE `a` = 1";
+    let mut issues = Vec::new();
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        _ => panic!("We should get a select statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(select.select_exprs[0].as_span.is_some());
+    assert_eq!(select.select_exprs[0].as_.as_ref().unwrap().as_str(), "x");
+    assert!(select.select_exprs[1].as_span.is_none());
+    assert_eq!(select.select_exprs[1].as_.as_ref().unwrap().as_str(), "y");
+    match &select.table_references.as_ref().unwrap()[0] {
+        TableReference::Join { left, right, .. } => {
+            assert!(matches!(
+                left.as_ref(),
+                TableReference::Table { as_span: Some(_), as_: Some(a), .. } if a.as_str() == "u"
+            ));
+            assert!(matches!(
+                right.as_ref(),
+                TableReference::Query { as_span: None, as_: Some(a), .. } if a.as_str() == "v"
+            ));
+        }
+        _ => panic!("Expected a join"),
+    }
+}
+
+#[test]
+pub fn parse_group_concat() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql =
+        "SELECT `a`, GROUP_CONCAT(`b` ORDER BY `c` SEPARATOR ';') AS `bs` FROM `t1` GROUP BY `a`";
+    let mut issues = Vec::new();
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        _ => panic!("We should get a select statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    match &select.select_exprs[1].expr {
+        Expression::GroupConcat {
+            distinct_span: None,
+            order_by: Some((_, order)),
+            separator: Some((_, separator)),
+            ..
+        } => {
+            assert_eq!(order.len(), 1);
+            assert_eq!(separator.as_str(), ";");
+        }
+        e => panic!("Expected GROUP_CONCAT, got {:?}", e),
+    }
+}
+
+#[test]
+pub fn parse_aggregate_modifiers() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "SELECT COUNT(DISTINCT `a`), COUNT(*), SUM(ALL `b`), MAX(`c`) FROM `t1`";
+    let mut issues = Vec::new();
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        _ => panic!("We should get a select statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(matches!(
+        select.select_exprs[0].expr,
+        Expression::Count {
+            modifier: Some(AggregateModifier::Distinct(_)),
+            ..
+        }
+    ));
+    assert!(matches!(
+        select.select_exprs[1].expr,
+        Expression::Count { modifier: None, .. }
+    ));
+    assert!(matches!(
+        select.select_exprs[2].expr,
+        Expression::AggregateFunction {
+            function: Function::Sum,
+            modifier: Some(AggregateModifier::All(_)),
+            ..
+        }
+    ));
+    assert!(matches!(
+        select.select_exprs[3].expr,
+        Expression::AggregateFunction {
+            function: Function::Max,
+            modifier: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+pub fn parse_date_functions() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "SELECT FROM_UNIXTIME(`ts`), DATE_SUB(`d`, INTERVAL 3 MONTH), STR_TO_DATE(`s`, '%Y'), LEFT(`name`, 2) FROM `t1` WHERE `d` > NOW() - INTERVAL 1 DAY";
+    let mut issues = Vec::new();
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        _ => panic!("We should get a select statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    match &select.select_exprs[1].expr {
+        Expression::Function(Function::DateSub, args, _) => assert!(matches!(
+            args[1],
+            Expression::Interval {
+                unit: (IntervalUnit::Month, _),
+                ..
+            }
+        )),
+        e => panic!("Expected DATE_SUB, got {:?}", e),
+    }
+    assert!(matches!(
+        select.select_exprs[3].expr,
+        Expression::Function(Function::Left, _, _)
+    ));
+}
+
+#[test]
+pub fn parse_partition_selection() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let sql = "SELECT `a` FROM `t1` PARTITION (`p1`, `p2`) AS `x` WHERE `a` = 1";
+    let mut issues = Vec::new();
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        _ => panic!("We should get a select statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    match &select.table_references.as_ref().unwrap()[0] {
+        TableReference::Table {
+            partition: Some((_, partitions)),
+            as_: Some(as_),
+            ..
+        } => {
+            assert_eq!(partitions.len(), 2);
+            assert_eq!(partitions[1].as_str(), "p2");
+            assert_eq!(as_.as_str(), "x");
+        }
+        r => panic!("Expected a table with partitions, got {:?}", r),
+    }
+
+    let sql = "DELETE FROM `t1` PARTITION (`p0`) WHERE `a` = 1";
+    let mut issues = Vec::new();
+    let delete = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Delete(d)) => d,
+        _ => panic!("We should get a delete statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(delete.partition.map(|(_, p)| p.len()), Some(1));
+
+    let mut issues = Vec::new();
+    parse_statement(
+        "DELETE FROM `t1`, `t2` PARTITION (`p0`) USING `t1` JOIN `t2` ON `t1`.`a` = `t2`.`a`",
+        &mut issues,
+        &options,
+    );
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn parse_load_data() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "LOAD DATA LOW_PRIORITY INFILE 'data.txt' REPLACE INTO TABLE `db`.`t1`
+        CHARACTER SET utf8mb4 COLUMNS ESCAPED BY '\\\\' TERMINATED BY '\\t'
+        LINES STARTING BY 'x' TERMINATED BY '\\n' IGNORE 2 ROWS";
+    let mut issues = Vec::new();
+    let load = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::LoadData(l)) => l,
+        _ => panic!("We should get a load data statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(matches!(load.flags[..], [LoadDataFlag::LowPriority(_)]));
+    assert!(matches!(
+        load.on_duplicate,
+        Some(LoadDataOnDuplicate::Replace(_))
+    ));
+    assert_eq!(
+        load.charset.as_ref().map(|(_, c)| c.as_str()),
+        Some("utf8mb4")
+    );
+    assert_eq!(load.fields.map(|(_, f)| f.len()), Some(2));
+    match load.lines.as_ref().map(|(_, l)| &l[..]) {
+        Some([LoadDataLinesOption::StartingBy(_, s), LoadDataLinesOption::TerminatedBy(_, t)]) => {
+            assert_eq!(s.as_str(), "x");
+            assert_eq!(t.as_str(), "\\n");
+        }
+        l => panic!("Unexpected lines options {:?}", l),
+    }
+    assert_eq!(load.ignore_lines.map(|(_, (n, _))| n), Some(2));
+    assert!(load.columns.is_empty());
+
+    let mut issues = Vec::new();
+    parse_statement(
+        "LOAD DATA INFILE 'data.txt' INTO TABLE `t1` FIELDS LINES TERMINATED BY '\\n'",
+        &mut issues,
+        &options,
+    );
+    assert!(!issues.is_empty());
+}
+
+#[test]
+pub fn parse_result() {
+    let options = ParseOptions::new()
+        .dialect(SQLDialect::MariaDB)
+        .warn_unquoted_identifiers(true);
+
+    let result = parse("SELECT a FROM `t1`; SELECT `b` FROM `t2`;", &options);
+    assert!(!result.has_errors(), "Issues: {:#?}", result.issues);
+    assert_eq!(result.issues.warnings().count(), 1);
+    assert_eq!(result.statements.len(), 2);
+
+    let result = parse("SELECT `a` FROM; SELECT `b` FROM `t2`;", &options);
+    assert!(result.has_errors());
+    assert!(matches!(
+        result.into_statements().last(),
+        Some(Statement::Select(_))
+    ));
+}
+
+#[test]
+pub fn parse_signal() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE TRIGGER `check_age` BEFORE INSERT ON `people` FOR EACH ROW BEGIN
+        IF NEW.`age` < 0 THEN
+            SIGNAL SQLSTATE VALUE '45000' SET MESSAGE_TEXT = 'Age must be positive';
+        END IF;
+    END";
+    let mut issues = Vec::new();
+    parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    let mut issues = Vec::new();
+    let signal = match parse_statement("RESIGNAL", &mut issues, &options) {
+        Some(Statement::Signal(s)) => s,
+        _ => panic!("We should get a signal statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(signal.resignal);
+    assert!(signal.condition.is_none());
+
+    let mut issues = Vec::new();
+    let signal = match parse_statement(
+        "RESIGNAL `bad_value` SET MYSQL_ERRNO = 1644, TABLE_NAME = 't1'",
+        &mut issues,
+        &options,
+    ) {
+        Some(Statement::Signal(s)) => s,
+        _ => panic!("We should get a signal statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(
+        matches!(signal.condition, Some(SignalCondition::Name(n)) if n.as_str() == "bad_value")
+    );
+    assert!(matches!(
+        signal.set_items[..],
+        [
+            (SignalConditionInformation::MysqlErrno(_), _),
+            (SignalConditionInformation::TableName(_), _)
+        ]
+    ));
+
+    for sql in [
+        "SIGNAL",
+        "SIGNAL SQLSTATE '00000'",
+        "SIGNAL SQLSTATE '4500'",
+    ] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert_eq!(issues.len(), 1, "{}: {:#?}", sql, issues);
+    }
+}
+
+#[test]
+pub fn parse_loops() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE FUNCTION `f`(IN `n` INT) RETURNS INT BEGIN
+        SET `n` = `n` + 1;
+        WHILE `n` < 10 DO
+            SET `n` = `n` + 1;
+        END WHILE;
+        `r1`: REPEAT
+            SET `n` = `n` - 1;
+        UNTIL `n` <= 0 END REPEAT `r1`;
+        LOOP
+            SET `n` = `n` + 1;
+        END LOOP;
+    END";
+    let mut issues = Vec::new();
+    let function = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateFunction(f)) => f,
+        _ => panic!("We should get a create function statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let body = match function.return_.as_deref() {
+        Some(Statement::Block(b)) => &b.statements,
+        s => panic!("Expected block, got {:?}", s),
+    };
+    assert!(matches!(&body[1], Statement::While(w) if w.label.is_none() && w.body.len() == 1));
+    match &body[2] {
+        Statement::Repeat(r) => {
+            assert_eq!(r.label.as_ref().map(|l| l.as_str()), Some("r1"));
+            assert_eq!(r.end_label.as_ref().map(|l| l.as_str()), Some("r1"));
+        }
+        s => panic!("Expected repeat, got {:?}", s),
+    }
+    assert!(matches!(&body[3], Statement::Loop(l) if l.body.len() == 1));
+
+    for sql in [
+        "`a`: LOOP SELECT 1; END LOOP `b`",
+        "LOOP SELECT 1; END LOOP `b`",
+        "`a`: SELECT 1",
+    ] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert_eq!(issues.len(), 1, "{}: {:#?}", sql, issues);
+    }
+
+    // Labels are case insensitive
+    for sql in [
+        "`a`: LOOP SELECT 1; END LOOP `A`",
+        "`a`: BEGIN SELECT 1; END `A`",
+    ] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert!(issues.is_empty(), "{}: {:#?}", sql, issues);
+    }
+}
+
+#[test]
+pub fn parse_labels() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE FUNCTION `f`(IN `n` INT) RETURNS INT BEGIN
+        `outer`: BEGIN
+            `l1`: LOOP
+                SET `n` = `n` - 1;
+                IF `n` > 5 THEN
+                    ITERATE `l1`;
+                END IF;
+                IF `n` <= 0 THEN
+                    LEAVE `outer`;
+                END IF;
+            END LOOP `l1`;
+        END `outer`;
+    END";
+    let mut issues = Vec::new();
+    let function = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateFunction(f)) => f,
+        _ => panic!("We should get a create function statement"),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let block = match function.return_.as_deref() {
+        Some(Statement::Block(b)) => match &b.statements[..] {
+            [Statement::Block(b)] => b,
+            s => panic!("Expected inner block, got {:?}", s),
+        },
+        s => panic!("Expected block, got {:?}", s),
+    };
+    assert_eq!(block.label.as_ref().map(|l| l.as_str()), Some("outer"));
+    assert_eq!(block.end_label.as_ref().map(|l| l.as_str()), Some("outer"));
+    let body = match &block.statements[..] {
+        [Statement::Loop(l)] => &l.body,
+        s => panic!("Expected loop, got {:?}", s),
+    };
+    match &body[1] {
+        Statement::If(i) => assert!(matches!(
+            &i.conditions[0].then[..],
+            [Statement::Iterate(_, l)] if l.as_str() == "l1"
+        )),
+        s => panic!("Expected if, got {:?}", s),
+    }
+
+    let sql = "`b`: BEGIN END";
+    let mut issues = Vec::new();
+    let block = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Block(b)) => b,
+        s => panic!("Expected block, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(block.span(), 0..sql.len());
+
+    let mut issues = Vec::new();
+    parse_statement("`b`: BEGIN SELECT 1; END `c`", &mut issues, &options);
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn parse_call() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE TRIGGER `t1_insert` AFTER INSERT ON `t1` FOR EACH ROW BEGIN
+        CALL `log_insert`(NEW.`id`, (SELECT COUNT(*) FROM `t2`));
+        CALL `refresh`;
+    END";
+    let mut issues = Vec::new();
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(stmt.map(|s| s.referenced_tables().len()), Some(2));
+
+    let mut issues = Vec::new();
+    let call = match parse_statement("CALL `p`()", &mut issues, &options) {
+        Some(Statement::Call(c)) => c,
+        s => panic!("Expected call, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(call.args.is_empty());
+}
+
+#[test]
+pub fn create_option_spans_keep_casing() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "create Or rePlace Algorithm = tempTable Definer = `u`@`h` Sql security Definer \
+        view `v1` AS SELECT 1";
+    let mut issues = Vec::new();
+    let view = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateView(v)) => v,
+        s => panic!("Expected create view, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(&sql[view.create_span.clone()], "create");
+    assert_eq!(&sql[view.view_span.clone()], "view");
+    let mut keywords = Vec::new();
+    for option in &view.create_options {
+        match option {
+            CreateOption::OrReplace(s) => keywords.push(&sql[s.clone()]),
+            CreateOption::Algorithm(s, a) => {
+                keywords.push(&sql[s.clone()]);
+                keywords.push(&sql[a.span()]);
+            }
+            CreateOption::Definer { definer_span, .. } => keywords.push(&sql[definer_span.clone()]),
+            CreateOption::SqlSecurityDefiner(s, d) => {
+                keywords.push(&sql[s.clone()]);
+                keywords.push(&sql[d.clone()]);
+            }
+            o => panic!("Unexpected option {:?}", o),
+        }
+    }
+    assert_eq!(
+        keywords,
+        [
+            "Or rePlace",
+            "Algorithm",
+            "tempTable",
+            "Definer",
+            "Sql security",
+            "Definer"
+        ]
+    );
+
+    for (sql, keyword) in [
+        ("Create Temporary table `t1` (`a` INT)", "Temporary"),
+        ("CREATE uNiQuE INDEX `i1` ON `t1` (`a`)", "uNiQuE"),
+        ("CREATE Sql Security User VIEW `v1` AS SELECT 1", "User"),
+    ] {
+        let mut issues = Vec::new();
+        let create_options = match parse_statement(sql, &mut issues, &options) {
+            Some(Statement::CreateTable(t)) => t.create_options,
+            Some(Statement::CreateIndex(i)) => i.create_options,
+            Some(Statement::CreateView(v)) => v.create_options,
+            s => panic!("Expected create statement, got {:?}", s),
+        };
+        assert!(issues.is_empty(), "{}: {:#?}", sql, issues);
+        let span = match &create_options[..] {
+            [CreateOption::Temporary(s)]
+            | [CreateOption::Unique(s)]
+            | [CreateOption::SqlSecurityUser(_, s)] => s.clone(),
+            o => panic!("Unexpected options {:?}", o),
+        };
+        assert_eq!(&sql[span], keyword);
+    }
+}
+
+#[test]
+pub fn parse_json_value() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE VIEW `v1` AS SELECT
+        JSON_VALUE(`doc`, '$.price' RETURNING NUMERIC(10, 2) DEFAULT 0 ON EMPTY ERROR ON ERROR) AS `price`,
+        JSON_QUERY(`doc`, '$.tags') AS `tags`
+        FROM `t1`";
+    let mut issues = Vec::new();
+    let view = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateView(v)) => v,
+        s => panic!("Expected create view, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let select = &view.select;
+    match &select.select_exprs[0].expr {
+        Expression::JsonValue {
+            query: false,
+            returning: Some((_, type_)),
+            on_empty: Some((JsonOnResponse::Default(_, _), _)),
+            on_error: Some((JsonOnResponse::Error(_), on_error)),
+            ..
+        } => {
+            assert!(matches!(type_.type_, Type::Numeric(10, 2, _)));
+            assert!(type_.properties.is_empty());
+            assert_eq!(&sql[on_error.clone()], "ON ERROR");
+        }
+        e => panic!("Expected JSON_VALUE, got {:?}", e),
+    }
+    assert!(matches!(
+        &select.select_exprs[1].expr,
+        Expression::JsonValue {
+            query: true,
+            returning: None,
+            on_empty: None,
+            on_error: None,
+            ..
+        }
+    ));
+
+    for sql in [
+        "SELECT JSON_VALUE(`doc`, '$.x' NULL ON ERROR NULL ON EMPTY)",
+        "SELECT JSON_VALUE(`doc`, '$.x' NULL ON EMPTY ERROR ON EMPTY)",
+    ] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert_eq!(issues.len(), 1, "{}: {:#?}", sql, issues);
+    }
+}
+
+#[test]
+pub fn parse_order_keys() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE VIEW `v1` AS SELECT `a`, `b` FROM `t1`
+        ORDER BY `a` COLLATE utf8mb4_bin DESC, `b`, `a` + `b` ASC";
+    let mut issues = Vec::new();
+    let view = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateView(v)) => v,
+        s => panic!("Expected create view, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let (_, keys) = view.select.order_by.as_ref().unwrap();
+    assert_eq!(keys.len(), 3);
+    assert!(matches!(keys[0].expr, Expression::Identifier(_)));
+    assert_eq!(
+        keys[0].collate.as_ref().map(|(_, c)| c.as_str()),
+        Some("utf8mb4_bin")
+    );
+    assert!(matches!(keys[0].direction, OrderFlag::Desc(_)));
+    assert_eq!(&sql[keys[0].span()], "`a` COLLATE utf8mb4_bin DESC");
+    assert!(keys[1].collate.is_none());
+    assert!(matches!(keys[1].direction, OrderFlag::None));
+    assert!(keys[1].nulls.is_none());
+    assert!(matches!(keys[2].expr, Expression::Binary { .. }));
+
+    let pg_options = ParseOptions::new().dialect(SQLDialect::PostgreSQL);
+    let sql = "SELECT a FROM t1 ORDER BY a DESC NULLS LAST, b NULLS FIRST";
+    let mut issues = Vec::new();
+    let select = match parse_statement(sql, &mut issues, &pg_options) {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let (_, keys) = select.order_by.as_ref().unwrap();
+    assert!(matches!(keys[0].nulls, Some(NullsOrder::Last(_))));
+    assert!(matches!(keys[1].nulls, Some(NullsOrder::First(_))));
+
+    let mut issues = Vec::new();
+    parse_statement(
+        "SELECT `a` FROM `t1` ORDER BY `a` NULLS FIRST",
+        &mut issues,
+        &options,
+    );
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn by_semantics_ignores_spans() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE TABLE `t1` (`a` INT NOT NULL, a VARCHAR(10), `b` int   not null, `c` INT,
+        `d` INT DEFAULT 1, `e` INT  DEFAULT  1, `f` INT DEFAULT 2, `g` INT(11), `h` VARCHAR(20))";
+    let mut issues = Vec::new();
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table, got {:?}", s),
+    };
+    let columns: Vec<_> = create
+        .create_definitions
+        .iter()
+        .filter_map(|d| match d {
+            CreateDefinition::ColumnDefinition {
+                identifier,
+                data_type,
+                ..
+            } => Some((
+                BySemantics(identifier.clone()),
+                BySemantics(data_type.clone()),
+            )),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(columns.len(), 9);
+    assert!(columns[0].0 == columns[1].0);
+    assert!(columns[0].0 != columns[2].0);
+    assert!(columns[0].1 == columns[2].1);
+    assert!(columns[0].1 != columns[1].1);
+    assert!(columns[0].1 != columns[3].1);
+    assert!(columns[4].1 == columns[5].1);
+    assert!(columns[4].1 != columns[6].1);
+    assert!(columns[3].1 != columns[7].1);
+    assert!(columns[1].1 != columns[8].1);
+
+    let options = options.fold_identifier_case(IdentifierCase::Lower);
+    let sql = "CREATE TABLE `t1` (A ENUM('x', 'y'), `a` ENUM('x',  'y'), `A` ENUM('x', 'z'),
+        `b` INT REFERENCES `t2` (`c`) ON DELETE CASCADE, `c` INT REFERENCES t2 (C) ON DELETE CASCADE,
+        `d` INT REFERENCES `t2` (`c`) ON DELETE SET NULL)";
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let columns: Vec<_> = create
+        .create_definitions
+        .iter()
+        .filter_map(|d| match d {
+            CreateDefinition::ColumnDefinition {
+                identifier,
+                data_type,
+                ..
+            } => Some((
+                BySemantics(identifier.clone()),
+                BySemantics(data_type.clone()),
+            )),
+            _ => None,
+        })
+        .collect();
+    assert!(columns[0].0 == columns[1].0);
+    assert!(columns[0].0 != columns[2].0);
+    assert!(columns[0].1 == columns[1].1);
+    assert!(columns[0].1 != columns[2].1);
+    assert!(columns[3].1 == columns[4].1);
+    assert!(columns[3].1 != columns[5].1);
+}
+
+#[test]
+pub fn parse_grant_revoke() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "GRANT USAGE ON *.* TO 'u'@'localhost' IDENTIFIED BY PASSWORD '*ABC', `v`";
+    let mut issues = Vec::new();
+    let grant = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Grant(g)) => g,
+        s => panic!("Expected grant, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(grant.privileges[0].name[0].as_str(), "USAGE");
+    assert!(matches!(grant.level, GrantLevel::Global(_)));
+    assert!(matches!(
+        &grant.grantees[0].identified_by,
+        Some((_, p, true)) if p.as_str() == "*ABC"
+    ));
+    assert!(grant.grantees[1].host.is_none());
+    assert_eq!(&sql[grant.span()], sql);
+
+    let sql = "GRANT EXECUTE ON PROCEDURE `db`.`p` TO `u`@`localhost`";
+    let grant = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Grant(g)) => g,
+        s => panic!("Expected grant, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(matches!(
+        grant.object_type,
+        Some(GrantObjectType::Procedure(_))
+    ));
+    assert!(matches!(&grant.level, GrantLevel::Object(n) if n.identifier.as_str() == "p"));
+    assert!(Statement::Grant(grant).referenced_tables().is_empty());
+
+    let sql = "REVOKE SELECT (`a`), UPDATE ON `t1` FROM 'u'@'h'";
+    let revoke = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Revoke(r)) => r,
+        s => panic!("Expected revoke, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(revoke.privileges.len(), 2);
+    assert_eq!(revoke.privileges[0].columns[0].as_str(), "a");
+    let statement = Statement::Revoke(revoke);
+    let tables = statement.referenced_tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].as_str(), "t1");
+
+    parse_statement("GRANT ON `t1` TO 'u'", &mut issues, &options);
+    assert!(!issues.is_empty());
+}
+
+#[test]
+pub fn create_table_trailing_comma() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    let sql = "CREATE TABLE `t1` (`a` INT, `b` INT,)";
+    let mut issues = Vec::new();
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table, got {:?}", s),
+    };
+    assert_eq!(create.create_definitions.len(), 2);
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+    assert_eq!(issues[0].level, Level::Error);
+    assert_eq!(&sql[issues[0].span.clone()], ",");
+}
+
+#[test]
+pub fn parse_explain_describe() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "EXPLAIN UPDATE `t1` SET `a` = 1";
+    let explain = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Explain(e)) => e,
+        s => panic!("Expected explain, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(explain.format.is_none());
+    assert!(matches!(*explain.inner, Statement::Update(_)));
+    assert_eq!(&sql[explain.span()], sql);
+    let statement = Statement::Explain(explain);
+    let tables = statement.referenced_tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].as_str(), "t1");
+
+    for sql in ["DESC `t1`", "EXPLAIN t1", "describe t1 a"] {
+        match parse_statement(sql, &mut issues, &options) {
+            Some(Statement::Describe(d)) => assert_eq!(d.table.identifier.as_str(), "t1"),
+            s => panic!("Expected describe, got {:?}", s),
+        }
+        assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    }
+
+    for (sql, modifier) in [
+        ("EXPLAIN EXTENDED SELECT 1", "EXTENDED"),
+        ("EXPLAIN PARTITIONS SELECT `a` FROM `t1`", "PARTITIONS"),
+        ("EXPLAIN ANALYZE FORMAT=JSON SELECT 1", "ANALYZE"),
+    ] {
+        match parse_statement(sql, &mut issues, &options) {
+            Some(Statement::Explain(e)) => {
+                assert_eq!(&sql[e.modifier.unwrap().span()], modifier)
+            }
+            s => panic!("Expected explain, got {:?}", s),
+        }
+        assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    }
+
+    // Without a following statement the modifier names the table to describe
+    match parse_statement("DESC extended", &mut issues, &options) {
+        Some(Statement::Describe(d)) => assert_eq!(d.table.identifier.as_str(), "extended"),
+        s => panic!("Expected describe, got {:?}", s),
+    }
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    match parse_statement("DESC t1 'a%'", &mut issues, &options) {
+        Some(Statement::Describe(d)) => {
+            assert!(matches!(d.column, Some(DescribeColumn::Pattern(p)) if p.as_str() == "a%"))
+        }
+        s => panic!("Expected describe, got {:?}", s),
+    }
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    parse_statement("EXPLAIN FORMAT=JSON", &mut issues, &options);
+    assert!(!issues.is_empty());
+}
+
+#[test]
+pub fn parse_declare_handler() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "DECLARE EXIT HANDLER FOR 1062, `dup_key`, SQLEXCEPTION, SQLWARNING
+        UPDATE `errors` SET `n` = `n` + 1";
+    let handler = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::DeclareHandler(h)) => h,
+        s => panic!("Expected declare handler, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(matches!(handler.action, HandlerAction::Exit(_)));
+    assert!(matches!(
+        &handler.conditions[..],
+        [
+            HandlerCondition::ErrorCode((1062, _)),
+            HandlerCondition::Name(n),
+            HandlerCondition::SqlException(_),
+            HandlerCondition::SqlWarning(_),
+        ] if n.as_str() == "dup_key"
+    ));
+    assert_eq!(&sql[handler.span()], sql);
+    let statement = Statement::DeclareHandler(handler);
+    let tables = statement.referenced_tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].as_str(), "errors");
+
+    let sql = "CREATE FUNCTION `f`(IN `a` INT) RETURNS INT BEGIN
+        DECLARE CONTINUE HANDLER FOR SQLSTATE VALUE '22012' BEGIN END;
+        SIGNAL SQLSTATE '22012';
+    END";
+    parse_statement(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    parse_statement(
+        "DECLARE CONTINUE HANDLER FOR SQLSTATE '00000' CALL `f`()",
+        &mut issues,
+        &options,
+    );
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+
+    issues.clear();
+    parse_statement("DECLARE `x` INT", &mut issues, &options);
+    assert!(!issues.is_empty());
+}
+
+#[test]
+pub fn max_issues_caps_growth() {
+    let options = ParseOptions::new()
+        .dialect(SQLDialect::MariaDB)
+        .warn_unquoted_identifiers(true)
+        .max_issues(2);
+    let mut issues = Vec::new();
+
+    parse_statement("SELECT a, b, c FROM t1", &mut issues, &options);
+    assert_eq!(issues.len(), 2, "Issues: {:#?}", issues);
+    assert_eq!(issues[1].message, Message::Static("Too many issues"));
+    assert!(!issues.has_errors());
+
+    issues.clear();
+    parse_statement("SELECT a, b, c FROM", &mut issues, &options);
+    assert_eq!(issues.len(), 2, "Issues: {:#?}", issues);
+    assert_eq!(issues[1].level, Level::Error);
+
+    issues.clear();
+    parse_statement("SELECT a FROM t1", &mut issues, &options);
+    assert_eq!(issues.len(), 2, "Issues: {:#?}", issues);
+    assert!(issues
+        .iter()
+        .all(|i| i.message != Message::Static("Too many issues")));
+}
+
+#[test]
+pub fn parse_index_key_synonyms() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    for keyword in ["KEY", "INDEX"] {
+        for unique in ["UNIQUE ", ""] {
+            let sql = alloc::format!("CREATE TABLE `t1` (`a` INT, {unique}{keyword} `i` (`a`))");
+            let create = match parse_statement(&sql, &mut issues, &options) {
+                Some(Statement::CreateTable(c)) => c,
+                s => panic!("Expected create table for {sql}, got {:?}", s),
+            };
+            assert!(issues.is_empty(), "Issues for {sql}: {:#?}", issues);
+            let index_type = match &create.create_definitions[1] {
+                CreateDefinition::IndexDefinition { index_type, .. } => index_type.clone(),
+                d => panic!("Expected index definition for {sql}, got {:?}", d),
+            };
+            let index_keyword = match (&index_type, unique.is_empty()) {
+                (IndexType::Index(k), true) => k.clone(),
+                (IndexType::Unique(_, Some(k)), false) => k.clone(),
+                (t, _) => panic!("Unexpected index type for {sql}: {:?}", t),
+            };
+            assert_eq!(&sql[index_keyword.span()], keyword);
+            assert_eq!(
+                matches!(index_keyword, IndexKeyword::Key(_)),
+                keyword == "KEY"
+            );
+            assert_eq!(&sql[index_type.span()], alloc::format!("{unique}{keyword}"));
+
+            let sql = alloc::format!("ALTER TABLE `t1` ADD {unique}{keyword} `i` (`a`)");
+            let alter = match parse_statement(&sql, &mut issues, &options) {
+                Some(Statement::AlterTable(a)) => a,
+                s => panic!("Expected alter table for {sql}, got {:?}", s),
+            };
+            assert!(issues.is_empty(), "Issues for {sql}: {:#?}", issues);
+            assert!(matches!(
+                alter.alter_specifications[0],
+                AlterSpecification::AddIndex { .. }
+            ));
+        }
+
+        let sql =
+            alloc::format!("ALTER TABLE `t1` RENAME {keyword} `i` TO `j`, DROP {keyword} `k`");
+        let alter = match parse_statement(&sql, &mut issues, &options) {
+            Some(Statement::AlterTable(a)) => a,
+            s => panic!("Expected alter table for {sql}, got {:?}", s),
+        };
+        assert!(issues.is_empty(), "Issues for {sql}: {:#?}", issues);
+        match &alter.alter_specifications[..] {
+            [AlterSpecification::RenameIndex {
+                index_keyword,
+                old_name,
+                new_name,
+                ..
+            }, AlterSpecification::DropIndex { .. }] => {
+                assert_eq!(&sql[index_keyword.span()], keyword);
+                assert_eq!(old_name.as_str(), "i");
+                assert_eq!(new_name.as_str(), "j");
+            }
+            s => panic!("Unexpected alter specifications for {sql}: {:?}", s),
+        }
+    }
+
+    let sql = "CREATE TABLE `t1` (`a` TEXT, FULLTEXT `f` (`a`), SPATIAL INDEX `s` (`a`))";
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(matches!(
+        create.create_definitions[1],
+        CreateDefinition::IndexDefinition {
+            index_type: IndexType::FullText(_, None),
+            ..
+        }
+    ));
+    assert!(matches!(
+        create.create_definitions[2],
+        CreateDefinition::IndexDefinition {
+            index_type: IndexType::Spatial(_, Some(IndexKeyword::Index(_))),
+            ..
+        }
+    ));
+}
+
+#[test]
+pub fn parse_visible_invisible() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "CREATE TABLE `t1` (`a` INT NOT NULL, `b` INT DEFAULT 0 INVISIBLE,
+        `c` INT VISIBLE, KEY `i` (`a`) INVISIBLE COMMENT 'x')";
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let properties = |i: usize| match &create.create_definitions[i] {
+        CreateDefinition::ColumnDefinition { data_type, .. } => data_type.properties.clone(),
+        d => panic!("Expected column definition, got {:?}", d),
+    };
+    match properties(1).last() {
+        Some(DataTypeProperty::Invisible(span)) => assert_eq!(&sql[span.clone()], "INVISIBLE"),
+        p => panic!("Expected invisible, got {:?}", p),
+    }
+    assert!(matches!(
+        properties(2).last(),
+        Some(DataTypeProperty::Visible(_))
+    ));
+    assert!(matches!(
+        &create.create_definitions[3],
+        CreateDefinition::IndexDefinition { index_options, .. }
+            if matches!(index_options[..], [IndexOption::Invisible(_), IndexOption::Comment(_)])
+    ));
+
+    let sql = "ALTER TABLE `t1` ADD INDEX `j` (`b`) VISIBLE, MODIFY `c` INT INVISIBLE";
+    let alter = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::AlterTable(a)) => a,
+        s => panic!("Expected alter table, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(matches!(
+        &alter.alter_specifications[0],
+        AlterSpecification::AddIndex { index_options, .. }
+            if matches!(index_options[..], [IndexOption::Visible(_)])
+    ));
+}
+
+#[test]
+pub fn parse_system_versioning() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "CREATE TABLE `t1` (
+        `x` INT,
+        `start_ts` TIMESTAMP(6) GENERATED ALWAYS AS ROW START INVISIBLE,
+        `end_ts` TIMESTAMP(6) GENERATED ALWAYS AS ROW END INVISIBLE,
+        PERIOD FOR SYSTEM_TIME(`start_ts`, `end_ts`)
+    ) WITH SYSTEM VERSIONING";
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert!(create.validate().is_empty());
+    match &create.create_definitions[1] {
+        CreateDefinition::ColumnDefinition { data_type, .. } => {
+            assert!(matches!(
+                data_type.properties[..],
+                [
+                    DataTypeProperty::GeneratedAlways(_),
+                    DataTypeProperty::AsRowStart(_),
+                    DataTypeProperty::Invisible(_)
+                ]
+            ));
+            assert_eq!(&sql[data_type.properties[1].span()], "AS ROW START");
+        }
+        d => panic!("Expected column definition, got {:?}", d),
+    }
+    match &create.create_definitions[3] {
+        d @ CreateDefinition::Period {
+            name, start, end, ..
+        } => {
+            assert_eq!(name.as_str(), "SYSTEM_TIME");
+            assert_eq!(start.as_str(), "start_ts");
+            assert_eq!(end.as_str(), "end_ts");
+            assert_eq!(
+                &sql[d.span()],
+                "PERIOD FOR SYSTEM_TIME(`start_ts`, `end_ts`"
+            );
+        }
+        d => panic!("Expected period, got {:?}", d),
+    }
+    match &create.options[..] {
+        [o @ TableOption::SystemVersioning { .. }] => {
+            assert_eq!(&sql[o.span()], "WITH SYSTEM VERSIONING")
+        }
+        o => panic!("Expected system versioning, got {:?}", o),
+    }
+
+    let sql = "CREATE TABLE `t2` (`period` INT, `s` DATE, PERIOD FOR `p` (`s`, `e`))";
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTable(c)) => c,
+        s => panic!("Expected create table, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let validation = create.validate();
+    assert_eq!(validation.len(), 1, "Issues: {:#?}", validation);
+    assert_eq!(&sql[validation[0].span.clone()], "`e`");
+}
+
+#[test]
+pub fn dedupe_repeated_issues() {
+    let options = ParseOptions::new()
+        .dialect(SQLDialect::MariaDB)
+        .warn_unquoted_identifiers(true);
+    let sql = "SELECT a, b FROM";
+
+    let mut once = Vec::new();
+    parse_statement(sql, &mut once, &options);
+    assert!(once.len() > 1, "Issues: {:#?}", once);
+
+    let mut issues = Vec::new();
+    parse_statement(sql, &mut issues, &options);
+    parse_statement(sql, &mut issues, &options);
+    assert_eq!(issues.len(), 2 * once.len());
+    dedupe_issues(&mut issues);
+    assert_eq!(issues, once);
+}
+
+#[test]
+pub fn order_by_shared_between_contexts() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "SELECT GROUP_CONCAT(`a` ORDER BY `a` COLLATE utf8mb4_bin DESC),
+        ROW_NUMBER() OVER (ORDER BY `b` COLLATE utf8mb4_bin DESC)
+        FROM `t1` ORDER BY `c` COLLATE utf8mb4_bin DESC";
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    let mut order_bys = Vec::new();
+    for select_expr in &select.select_exprs {
+        match &select_expr.expr {
+            Expression::GroupConcat {
+                order_by: Some(order_by),
+                ..
+            } => order_bys.push(order_by),
+            Expression::WindowFunction { window_spec, .. } => order_bys.push(&window_spec.order_by),
+            e => panic!("Unexpected expression {:?}", e),
+        }
+    }
+    order_bys.push(select.order_by.as_ref().unwrap());
+    assert_eq!(order_bys.len(), 3);
+    for (span, keys) in order_bys {
+        assert_eq!(&sql[span.clone()], "ORDER BY");
+        assert!(matches!(
+            &keys[..],
+            [OrderKey {
+                collate: Some((_, c)),
+                direction: OrderFlag::Desc(_),
+                ..
+            }] if c.as_str() == "utf8mb4_bin"
+        ));
+    }
+}
+
+#[test]
+pub fn parse_create_database_charset() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "CREATE SCHEMA `db` CHARACTER SET = utf8mb3 COLLATE utf8_general_ci";
+    match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateDatabase(c)) => {
+            assert_eq!(c.name.as_str(), "db");
+            assert_eq!(c.options.len(), 2);
+        }
+        s => panic!("Expected create database, got {:?}", s),
+    }
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    let sql = "CREATE DATABASE `db` DEFAULT CHARSET latin1 DEFAULT COLLATE utf8mb4_bin";
+    let stmt = parse_statement(sql, &mut issues, &options);
+    assert!(matches!(stmt, Some(Statement::CreateDatabase(_))));
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].level, Level::Warning);
+    assert_eq!(&sql[issues[0].span.clone()], "utf8mb4_bin");
+}
+
+#[test]
+pub fn if_exists_spans_are_consistent() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "CREATE TABLE IF NOT EXISTS `t1` (`a` int);
+        CREATE VIEW IF NOT EXISTS `v1` AS SELECT 1;
+        CREATE INDEX IF NOT EXISTS `i1` ON `t1` (`a`);
+        DROP TABLE IF EXISTS `t1`;";
+    let stmts = parse_statements(sql, &mut issues, &options);
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let spans: Vec<_> = stmts
+        .iter()
+        .map(|s| match s {
+            Statement::CreateTable(v) => v.if_not_exists.clone(),
+            Statement::CreateView(v) => v.if_not_exists.clone(),
+            Statement::CreateIndex(v) => v.if_not_exists.clone(),
+            Statement::DropTable(v) => v.if_exists.clone(),
+            s => panic!("Unexpected statement {:?}", s),
+        })
+        .collect();
+    assert_eq!(&sql[spans[0].clone().unwrap()], "IF NOT EXISTS");
+    assert_eq!(&sql[spans[1].clone().unwrap()], "IF NOT EXISTS");
+    assert_eq!(&sql[spans[2].clone().unwrap()], "IF NOT EXISTS");
+    assert_eq!(&sql[spans[3].clone().unwrap()], "IF EXISTS");
+}
+
+#[test]
+pub fn warn_chained_comparison() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "SELECT `a` FROM `t1` WHERE `a` = `b` + 1 = `c`";
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select, got {:?}", s),
+    };
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+    assert_eq!(issues[0].level, Level::Warning);
+    assert_eq!(issues[0].span.start, sql.rfind('=').unwrap());
+    assert!(matches!(
+        select.where_,
+        Some((Expression::Binary {
+            op: BinaryOperator::Eq,
+            lhs,
+            ..
+        }, _)) if matches!(*lhs, Expression::Binary { op: BinaryOperator::Eq, .. })
+    ));
+
+    issues.clear();
+    let sql = "SELECT `a` FROM `t1` WHERE (`a` = `b`) = `c` AND `a` < 1 OR NOT `b` = `c` = 1";
+    parse_statement(sql, &mut issues, &options);
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+    assert_eq!(issues[0].span.start, sql.rfind('=').unwrap());
+}
+
+#[test]
+pub fn parse_create_tablespace() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "CREATE TABLESPACE `ts1` ADD DATAFILE 'ts1.ibd' EXTENT_SIZE 4096
+        COMMENT = 'logs' ENGINE InnoDB";
+    let create = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::CreateTablespace(c)) => c,
+        s => panic!("Expected create tablespace, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    assert_eq!(
+        &sql[create.datafile.as_ref().unwrap().0.clone()],
+        "ADD DATAFILE"
+    );
+    assert!(matches!(
+        &create.options[..],
+        [
+            TablespaceOption::ExtentSize { value: (4096, _), .. },
+            TablespaceOption::Comment { value, .. },
+            TablespaceOption::Engine { .. },
+        ] if value.as_str() == "logs"
+    ));
+    assert_eq!(create.span(), 0..sql.len());
+
+    let sql = "CREATE OR REPLACE TABLESPACE `ts1`";
+    parse_statement(sql, &mut issues, &options);
+    assert_eq!(issues.len(), 1, "Issues: {:#?}", issues);
+}
+
+#[test]
+pub fn shift_spans_covers_all_spans() {
+    let maria = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let postgresql = ParseOptions::new().dialect(SQLDialect::PostgreSQL);
+    // At least one of every kind of statement, with routine bodies for the compound ones
+    let corpus = [
+        (&postgresql, "CREATE UNIQUE INDEX i ON t1 (a, b) WHERE a > 1;"),
+        (
+            &maria,
+            "CREATE TABLE `t1` (`a` int NOT NULL DEFAULT 1, `b` text, PRIMARY KEY (`a`),
+                CONSTRAINT `fk` FOREIGN KEY (`b`) REFERENCES `t2` (`c`)) ENGINE=InnoDB COMMENT='x';",
+        ),
+        (&maria, "CREATE TABLE `t2` AS SELECT `a` FROM `t1`;"),
+        (&maria, "CREATE VIEW `v` AS SELECT `a`, `b` AS `c` FROM `t1` WHERE `a` = 1;"),
+        (
+            &maria,
+            "CREATE TRIGGER `tr` BEFORE INSERT ON `t1` FOR EACH ROW DELETE FROM `t2` WHERE `a` = NEW.`a`;",
+        ),
+        (
+            &maria,
+            "CREATE FUNCTION `f`(IN `a` INT) RETURNS INT DETERMINISTIC BEGIN SET `a` = 1; END;",
+        ),
+        (
+            &maria,
+            "CREATE FUNCTION `g`(IN `n` INT) RETURNS INT BEGIN
+                DECLARE CONTINUE HANDLER FOR SQLEXCEPTION SET `n` = 0;
+                `l`: LOOP
+                    IF `n` > 1 THEN LEAVE `l`; ELSEIF `n` < 0 THEN SET `n` = 1; ELSE ITERATE `l`; END IF;
+                END LOOP `l`;
+                WHILE `n` > 0 DO SET `n` = `n` - 1; END WHILE;
+                REPEAT SET `n` = `n` + 1; UNTIL `n` > 5 END REPEAT;
+                CASE `n` WHEN 1 THEN SELECT 1; ELSE SELECT 2; END;
+                SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'x';
+                CALL `q`(`n`, 1);
+            END;",
+        ),
+        (
+            &maria,
+            "SELECT DISTINCT `a`, COUNT(*), SUM(DISTINCT `b`) AS `s`, (1, 2) = (`a`, `b`),
+                CASE WHEN `a` THEN 'x' ELSE _latin1'y' END, CAST(`b` AS CHAR),
+                ROW_NUMBER() OVER (ORDER BY `a`), GROUP_CONCAT(`a` ORDER BY `b` SEPARATOR ','),
+                MATCH (`b`) AGAINST ('x' IN BOOLEAN MODE), @@GLOBAL.time_zone, `doc`->>'$.a'
+            FROM `t1` JOIN `t2` ON `t1`.`id` = `t2`.`id` LEFT JOIN (SELECT 1 AS `x`) AS `q` ON `q`.`x` = `t1`.`a`
+            WHERE `b` IN (SELECT `b` FROM `t3`) AND `c` >= 1 AND `d` IS NOT NULL
+            GROUP BY `a` HAVING COUNT(*) > 1 ORDER BY `a` COLLATE utf8mb4_bin DESC LIMIT 5, 10
+            FOR UPDATE;",
+        ),
+        (
+            &maria,
+            "SELECT TRIM(LEADING 'x' FROM `a`), CONVERT(`a` USING utf8mb4), `d` + INTERVAL 1 DAY,
+                JSON_VALUE(`doc`, '$.a' RETURNING INT DEFAULT 0 ON EMPTY) FROM `t1`;",
+        ),
+        (&maria, "(SELECT `a` FROM `t1`) UNION ALL (SELECT `a` FROM `t2`) ORDER BY `a` LIMIT 1;"),
+        (&maria, "DELETE FROM `t1` WHERE `a` = 1;"),
+        (&maria, "DELETE `t1` FROM `t1` JOIN `t2` ON `t1`.`a` = `t2`.`a`;"),
+        (
+            &maria,
+            "INSERT INTO `t1` (`a`, `b`) VALUES (1, 'x'), (2, 'y') ON DUPLICATE KEY UPDATE `b` = VALUES(`b`);",
+        ),
+        (&maria, "REPLACE INTO `t1` SET `a` = 1, `b` = 2;"),
+        (&maria, "INSERT INTO `t1` (`a`) SELECT `a` FROM `t2`;"),
+        (
+            &postgresql,
+            "INSERT INTO t1 (a) VALUES (1) ON CONFLICT (a) DO UPDATE SET a = 2 RETURNING a;",
+        ),
+        (&maria, "UPDATE `t1` SET `a` = `a` + 1 WHERE `b` = 2;"),
+        (&maria, "DROP INDEX `i` ON `t1`;"),
+        (&maria, "DROP TEMPORARY TABLE IF EXISTS `t1`, `t2`;"),
+        (&maria, "DROP FUNCTION IF EXISTS `f`;"),
+        (&maria, "DROP PROCEDURE `p`;"),
+        (&maria, "DROP EVENT `e`;"),
+        (&maria, "DROP DATABASE `d`;"),
+        (&maria, "DROP SERVER `s`;"),
+        (&maria, "DROP TRIGGER `tr`;"),
+        (&maria, "DROP VIEW `v`;"),
+        (&maria, "SET `a` = 'UTC', `b` = 1;"),
+        (
+            &maria,
+            "ALTER TABLE `t1` ADD COLUMN `c` INT AFTER `a`, MODIFY `a` BIGINT, ADD INDEX `i` (`c`),
+                ADD CONSTRAINT `fk` FOREIGN KEY (`c`) REFERENCES `t2` (`c`), RENAME TO `t3`;",
+        ),
+        (
+            &maria,
+            "BEGIN; COMMIT; START TRANSACTION; SAVEPOINT `s`; ROLLBACK TO SAVEPOINT `s`;
+                RELEASE SAVEPOINT `s`; ROLLBACK; END;",
+        ),
+        (&postgresql, "COPY t1 (a, b) FROM STDIN;\n1\t2\n\\.\n"),
+        (&postgresql, "CREATE TYPE mood AS ENUM ('a', 'b');"),
+        (&postgresql, "DO $$ BEGIN SELECT 1 END $$;"),
+        (&maria, "CREATE DATABASE IF NOT EXISTS `d` CHARACTER SET utf8mb4;"),
+        (
+            &maria,
+            "CREATE TABLESPACE `ts` ADD DATAFILE 'ts.ibd' ENGINE=InnoDB COMMENT 'x';",
+        ),
+        (&maria, "TRUNCATE TABLE `t1`;"),
+        (&maria, "RENAME TABLE `t1` TO `t2`, `t3` TO `t4`;"),
+        (&maria, "SHOW FULL TABLES FROM `d` LIKE 't%';"),
+        (&maria, "SHOW DATABASES LIKE 'd%';"),
+        (&maria, "SHOW FULL COLUMNS FROM `t1` FROM `d` LIKE 'a%';"),
+        (&maria, "SHOW CREATE TABLE `d`.`t1`;"),
+        (&maria, "USE `d`;"),
+        (&maria, "LOCK TABLES `t1` AS `a` READ, `t2` WRITE; UNLOCK TABLES;"),
+        (&maria, "ANALYZE TABLE `t1`, `t2`;"),
+        (
+            &maria,
+            "LOAD DATA INFILE 'x.csv' INTO TABLE `t1` FIELDS TERMINATED BY ',' OPTIONALLY ENCLOSED BY '\"' (`a`, `b`);",
+        ),
+        (
+            &maria,
+            "GRANT SELECT (`a`), INSERT ON `d`.`t1` TO 'u'@'localhost' IDENTIFIED BY 'pw';",
+        ),
+        (&maria, "REVOKE ALL PRIVILEGES ON `d`.* FROM 'u'@'localhost';"),
+        (&maria, "EXPLAIN FORMAT=JSON SELECT `a` FROM `t1`;"),
+        (&maria, "DESCRIBE `t1` `a`;"),
+    ];
+    for (options, sql) in corpus {
+        let mut issues = Vec::new();
+        let mut expected = parse_statements(sql, &mut issues, options);
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        assert!(!expected.is_empty(), "{}", sql);
+        for k in [1, 7] {
+            let padded = alloc::format!("{}{}", " ".repeat(k), sql);
+            let mut issues = Vec::new();
+            let shifted = parse_statements(&padded, &mut issues, options);
+            assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+            for stmt in &mut expected {
+                stmt.shift_spans(k as isize);
+            }
+            assert_eq!(
+                alloc::format!("{:?}", shifted),
+                alloc::format!("{:?}", expected),
+                "Shifting {} by {}",
+                sql,
+                k
+            );
+            for stmt in &mut expected {
+                stmt.shift_spans(-(k as isize));
+            }
+        }
+    }
+}
+
+#[test]
+pub fn parse_row_constructors() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "SELECT (1, 2) = (`a`, `b`), ROW(1, 2) FROM `t1`
+        WHERE (`a`, `b`) IN ((1, 2), (3, 4)) AND `c` IN (1, 2)";
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    match &select.select_exprs[0].expr {
+        Expression::Binary { lhs, rhs, .. } => {
+            assert!(
+                matches!(&**lhs, Expression::Row { row_span: None, values, .. } if values.len() == 2)
+            );
+            assert!(
+                matches!(&**rhs, Expression::Row { row_span: None, values, .. } if values.len() == 2)
+            );
+            assert_eq!(&sql[lhs.span()], "(1, 2)");
+            assert_eq!(&sql[rhs.span()], "(`a`, `b`)");
+        }
+        e => panic!("Expected comparison, got {:?}", e),
+    }
+    let row = &select.select_exprs[1].expr;
+    assert!(matches!(
+        row,
+        Expression::Row {
+            row_span: Some(_),
+            ..
+        }
+    ));
+    assert_eq!(&sql[row.span()], "ROW(1, 2)");
+    assert_eq!(
+        row.to_sql(&SQLDialect::MariaDB).as_deref(),
+        Some("ROW(1, 2)")
+    );
+
+    match &select.where_ {
+        Some((Expression::Binary { lhs, rhs, .. }, _)) => {
+            match &**lhs {
+                Expression::In { lhs, rhs, .. } => {
+                    assert!(matches!(&**lhs, Expression::Row { .. }));
+                    assert_eq!(&sql[lhs.span()], "(`a`, `b`)");
+                    assert_eq!(rhs.len(), 2);
+                    assert!(rhs.iter().all(|v| matches!(v, Expression::Row { .. })));
+                }
+                e => panic!("Expected IN, got {:?}", e),
+            }
+            assert!(matches!(&**rhs, Expression::In { rhs, .. }
+                if matches!(rhs[..], [Expression::Integer(_), Expression::Integer(_)])));
+        }
+        e => panic!("Expected AND, got {:?}", e),
+    }
+}
+
+#[test]
+pub fn parse_keyword_named_functions() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+
+    type IsFunction = fn(&Function<'_>) -> bool;
+    let cases: &[(&str, IsFunction, usize)] = &[
+        ("NULLIF(`a`, 0)", |f| matches!(f, Function::NullIf), 2),
+        ("IFNULL(`a`, 0)", |f| matches!(f, Function::IfNull), 2),
+        (
+            "COALESCE(`a`, `b`, 0)",
+            |f| matches!(f, Function::Coalesce),
+            3,
+        ),
+        ("GREATEST(`a`, `b`)", |f| matches!(f, Function::Greatest), 2),
+        ("LEAST(`a`, `b`)", |f| matches!(f, Function::Least), 2),
+        ("NVL(`a`, 0)", |f| matches!(f, Function::IfNull), 2),
+        ("NVL2(`a`, 1, 0)", |f| matches!(f, Function::NVL2), 3),
+        ("MOD(`a`, 2)", |f| matches!(f, Function::Other("MOD")), 2),
+        (
+            "DATABASE()",
+            |f| matches!(f, Function::Other("DATABASE")),
+            0,
+        ),
+    ];
+    for (expr, is_function, arg_count) in cases {
+        let mut issues = Vec::new();
+        let sql = alloc::format!("SELECT {} FROM `t1`", expr);
+        let select = match parse_statement(&sql, &mut issues, &options) {
+            Some(Statement::Select(s)) => s,
+            s => panic!("Expected select for {}, got {:?}", expr, s),
+        };
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", expr, issues);
+        match &select.select_exprs[0].expr {
+            Expression::Function(function, args, _) => {
+                assert!(
+                    is_function(function),
+                    "Wrong function for {}: {:?}",
+                    expr,
+                    function
+                );
+                assert_eq!(args.len(), *arg_count, "Wrong arguments for {}", expr);
+            }
+            e => panic!("Expected function call for {}, got {:?}", expr, e),
+        }
+    }
+}
+
+#[test]
+pub fn parse_match_against() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+
+    let sql = "SELECT `id` FROM `t1`
+        WHERE MATCH (`title`, `t1`.`body`) AGAINST ('+sql -parse' IN BOOLEAN MODE) > 0
+        AND MATCH (`title`) AGAINST ('sql' IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION)";
+    let select = match parse_statement(sql, &mut issues, &options) {
+        Some(Statement::Select(s)) => s,
+        s => panic!("Expected select, got {:?}", s),
+    };
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+
+    let (boolean, natural) = match &select.where_ {
+        Some((Expression::Binary { lhs, rhs, .. }, _)) => match &**lhs {
+            Expression::Binary { lhs: boolean, .. } => (boolean, rhs),
+            e => panic!("Expected comparison, got {:?}", e),
+        },
+        e => panic!("Expected AND, got {:?}", e),
+    };
+    match &**boolean {
+        Expression::Match {
+            columns,
+            against,
+            modifier: Some(MatchModifier::BooleanMode(mode_span)),
+            ..
+        } => {
+            assert_eq!(columns.len(), 2);
+            assert!(matches!(&**against, Expression::String(s) if s.as_str() == "+sql -parse"));
+            assert_eq!(&sql[mode_span.clone()], "IN BOOLEAN MODE");
+        }
+        e => panic!("Expected boolean mode match, got {:?}", e),
+    }
+    assert!(matches!(
+        &**natural,
+        Expression::Match {
+            modifier: Some(MatchModifier::NaturalLanguageModeWithQueryExpansion(_)),
+            ..
+        }
+    ));
+    assert_eq!(
+        natural.to_sql(&SQLDialect::MariaDB).as_deref(),
+        Some("MATCH (`title`) AGAINST ('sql' IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION)")
+    );
+}
+
+#[test]
+pub fn parse_charset_introducer_only_for_charsets() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    for sql in ["SELECT _latin1'x'", "SELECT _UTF8MB4 'x'"] {
+        let mut issues = Vec::new();
+        let select = match parse_statement(sql, &mut issues, &options) {
+            Some(Statement::Select(s)) => s,
+            s => panic!("Expected select for {}, got {:?}", sql, s),
+        };
+        assert!(issues.is_empty(), "Issues for {}: {:#?}", sql, issues);
+        assert!(
+            matches!(
+                select.select_exprs[0].expr,
+                Expression::CharsetString { .. }
+            ),
+            "{}",
+            sql
+        );
+    }
+    // Neither is a character set introducer, so the string follows an identifier
+    for sql in ["SELECT _id 'x' FROM `t1`", "SELECT `_latin1` 'x' FROM `t1`"] {
+        let mut issues = Vec::new();
+        parse_statement(sql, &mut issues, &options);
+        assert_eq!(
+            issues.first().map(|i| &i.message),
+            Some(&Message::Owned(
+                "Expression should not follow expression".into()
+            )),
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+pub fn referenced_tables_clauses() {
+    let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    let mut issues = Vec::new();
+    for (sql, expected) in [
+        (
+            "SELECT `a` FROM `t1` ORDER BY (SELECT `b` FROM `t9`)",
+            &["t1", "t9"][..],
+        ),
+        (
+            "SELECT `a` FROM `t1` GROUP BY `a`, (SELECT `b` FROM `t2`)",
+            &["t1", "t2"],
+        ),
+        (
+            "SELECT ROW_NUMBER() OVER (ORDER BY (SELECT `b` FROM `t3`)) FROM `t1`",
+            &["t3", "t1"],
+        ),
+        (
+            "(SELECT `a` FROM `t1`) UNION (SELECT `a` FROM `t2`) ORDER BY (SELECT `b` FROM `t4`)",
+            &["t1", "t2", "t4"],
+        ),
+    ] {
+        let stmt = parse_statement(sql, &mut issues, &options).expect("statement");
+        assert!(issues.is_empty(), "Issues: {:#?}", issues);
+        let names: Vec<_> = stmt
+            .referenced_tables()
+            .iter()
+            .map(|v| v.as_str())
+            .collect();
+        assert_eq!(names, expected, "{}", sql);
+    }
+
+    // Tables are compared by their case folded names
+    let options = options.fold_identifier_case(IdentifierCase::Lower);
+    let stmt = parse_statement("SELECT 1 FROM Db.T1, db.t1, DB.`T1`", &mut issues, &options)
+        .expect("statement");
+    assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    let names: Vec<_> = stmt
+        .referenced_tables()
+        .iter()
+        .map(|v| v.as_str())
+        .collect();
+    assert_eq!(names, ["t1", "T1"]);
+}
@@ -0,0 +1,369 @@
+use alloc::vec::Vec;
+
+use crate::{
+    alter::parse_cols,
+    keywords::Keyword,
+    lexer::Token,
+    parser::{ParseError, Parser},
+    qualified_name::parse_qualified_name,
+    Identifier, QualifiedName, SString, Span, Spanned, SpansMut,
+};
+
+/// Flag specified before "INFILE"
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadDataFlag {
+    /// Span of "LOW_PRIORITY"
+    LowPriority(Span),
+    /// Span of "CONCURRENT"
+    Concurrent(Span),
+    /// Span of "LOCAL"
+    Local(Span),
+}
+
+impl Spanned for LoadDataFlag {
+    fn span(&self) -> Span {
+        match &self {
+            LoadDataFlag::LowPriority(v) => v.span(),
+            LoadDataFlag::Concurrent(v) => v.span(),
+            LoadDataFlag::Local(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for LoadDataFlag {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LoadDataFlag::LowPriority(v) => v.spans_mut(f),
+            LoadDataFlag::Concurrent(v) => v.spans_mut(f),
+            LoadDataFlag::Local(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// What to do with rows that duplicate an existing unique key
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadDataOnDuplicate {
+    /// Span of "REPLACE"
+    Replace(Span),
+    /// Span of "IGNORE"
+    Ignore(Span),
+}
+
+impl Spanned for LoadDataOnDuplicate {
+    fn span(&self) -> Span {
+        match &self {
+            LoadDataOnDuplicate::Replace(v) => v.span(),
+            LoadDataOnDuplicate::Ignore(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for LoadDataOnDuplicate {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LoadDataOnDuplicate::Replace(v) => v.spans_mut(f),
+            LoadDataOnDuplicate::Ignore(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Option given after "FIELDS" or "COLUMNS"
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadDataFieldsOption<'a> {
+    /// Span of "TERMINATED BY" and the terminator
+    TerminatedBy(Span, SString<'a>),
+    /// Span of "[OPTIONALLY] ENCLOSED BY", the enclosing character and whether
+    /// "OPTIONALLY" was given
+    EnclosedBy(Span, SString<'a>, bool),
+    /// Span of "ESCAPED BY" and the escape character
+    EscapedBy(Span, SString<'a>),
+}
+
+impl<'a> Spanned for LoadDataFieldsOption<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            LoadDataFieldsOption::TerminatedBy(s, v) => s.join_span(v),
+            LoadDataFieldsOption::EnclosedBy(s, v, _) => s.join_span(v),
+            LoadDataFieldsOption::EscapedBy(s, v) => s.join_span(v),
+        }
+    }
+}
+
+impl<'a> SpansMut for LoadDataFieldsOption<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LoadDataFieldsOption::TerminatedBy(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            LoadDataFieldsOption::EnclosedBy(v0, v1, _) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            LoadDataFieldsOption::EscapedBy(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+        }
+    }
+}
+
+/// Option given after "LINES"
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadDataLinesOption<'a> {
+    /// Span of "STARTING BY" and the line prefix
+    StartingBy(Span, SString<'a>),
+    /// Span of "TERMINATED BY" and the terminator
+    TerminatedBy(Span, SString<'a>),
+}
+
+impl<'a> Spanned for LoadDataLinesOption<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            LoadDataLinesOption::StartingBy(s, v) => s.join_span(v),
+            LoadDataLinesOption::TerminatedBy(s, v) => s.join_span(v),
+        }
+    }
+}
+
+impl<'a> SpansMut for LoadDataLinesOption<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LoadDataLinesOption::StartingBy(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            LoadDataLinesOption::TerminatedBy(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+        }
+    }
+}
+
+/// Represent a load data infile statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, LoadData, LoadDataFieldsOption, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "LOAD DATA LOCAL INFILE '/tmp/t1.csv' IGNORE INTO TABLE `t1`
+///     FIELDS TERMINATED BY ',' OPTIONALLY ENCLOSED BY '\"'
+///     LINES TERMINATED BY '\\n'
+///     IGNORE 1 LINES (`a`, `b`);";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let load: LoadData = match stmts.pop() {
+///     Some(Statement::LoadData(l)) => l,
+///     _ => panic!("We should get a load data statement")
+/// };
+///
+/// assert!(load.file_name.as_str() == "/tmp/t1.csv");
+/// assert!(load.table.identifier.as_str() == "t1");
+/// let (_, fields) = load.fields.unwrap();
+/// assert!(matches!(&fields[1], LoadDataFieldsOption::EnclosedBy(_, v, true) if v.as_str() == "\""));
+/// assert!(load.ignore_lines.unwrap().1 .0 == 1);
+/// assert!(load.columns.len() == 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadData<'a> {
+    /// Span of "LOAD DATA"
+    pub load_data_span: Span,
+    /// Flags specified before "INFILE"
+    pub flags: Vec<LoadDataFlag>,
+    /// Span of "INFILE"
+    pub infile_span: Span,
+    /// Path of the file to load
+    pub file_name: SString<'a>,
+    /// Handling of duplicate keys if specified
+    pub on_duplicate: Option<LoadDataOnDuplicate>,
+    /// Span of "INTO TABLE"
+    pub into_table_span: Span,
+    /// Table to load into
+    pub table: QualifiedName<'a>,
+    /// Span of "CHARACTER SET" and the character set of the file if specified
+    pub charset: Option<(Span, Identifier<'a>)>,
+    /// Span of "FIELDS" and the field options if specified
+    pub fields: Option<(Span, Vec<LoadDataFieldsOption<'a>>)>,
+    /// Span of "LINES" and the line options if specified
+    pub lines: Option<(Span, Vec<LoadDataLinesOption<'a>>)>,
+    /// Span of "IGNORE ... LINES" and the number of lines to skip if specified
+    pub ignore_lines: Option<(Span, (u64, Span))>,
+    /// Columns to load into, all columns of the table if empty
+    pub columns: Vec<Identifier<'a>>,
+}
+
+impl<'a> Spanned for LoadData<'a> {
+    fn span(&self) -> Span {
+        self.load_data_span
+            .join_span(&self.flags)
+            .join_span(&self.infile_span)
+            .join_span(&self.file_name)
+            .join_span(&self.on_duplicate)
+            .join_span(&self.into_table_span)
+            .join_span(&self.table)
+            .join_span(&self.charset)
+            .join_span(&self.fields)
+            .join_span(&self.lines)
+            .join_span(&self.ignore_lines)
+            .join_span(&self.columns)
+    }
+}
+
+impl<'a> SpansMut for LoadData<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.load_data_span.spans_mut(f);
+        self.flags.spans_mut(f);
+        self.infile_span.spans_mut(f);
+        self.file_name.spans_mut(f);
+        self.on_duplicate.spans_mut(f);
+        self.into_table_span.spans_mut(f);
+        self.table.spans_mut(f);
+        self.charset.spans_mut(f);
+        self.fields.spans_mut(f);
+        self.lines.spans_mut(f);
+        self.ignore_lines.spans_mut(f);
+        self.columns.spans_mut(f);
+    }
+}
+
+pub(crate) fn parse_load_data<'a>(parser: &mut Parser<'a, '_>) -> Result<LoadData<'a>, ParseError> {
+    let load_data_span = parser.consume_keywords(&[Keyword::LOAD, Keyword::DATA])?;
+
+    let mut flags = Vec::new();
+    loop {
+        match &parser.token {
+            Token::Ident(_, Keyword::LOW_PRIORITY) => {
+                flags.push(LoadDataFlag::LowPriority(parser.consume()))
+            }
+            Token::Ident(_, Keyword::CONCURRENT) => {
+                flags.push(LoadDataFlag::Concurrent(parser.consume()))
+            }
+            Token::Ident(_, Keyword::LOCAL) => flags.push(LoadDataFlag::Local(parser.consume())),
+            _ => break,
+        }
+    }
+
+    let infile_span = parser.consume_keyword(Keyword::INFILE)?;
+    let file_name = parser.consume_string()?;
+
+    let on_duplicate = match &parser.token {
+        Token::Ident(_, Keyword::REPLACE) => Some(LoadDataOnDuplicate::Replace(parser.consume())),
+        Token::Ident(_, Keyword::IGNORE) => Some(LoadDataOnDuplicate::Ignore(parser.consume())),
+        _ => None,
+    };
+
+    let into_table_span = parser.consume_keywords(&[Keyword::INTO, Keyword::TABLE])?;
+    let table = parse_qualified_name(parser)?;
+
+    let charset = match &parser.token {
+        Token::Ident(_, Keyword::CHARSET) => {
+            Some((parser.consume(), parser.consume_plain_identifier()?))
+        }
+        Token::Ident(_, Keyword::CHARACTER) => Some((
+            parser.consume_keywords(&[Keyword::CHARACTER, Keyword::SET])?,
+            parser.consume_plain_identifier()?,
+        )),
+        _ => None,
+    };
+
+    let fields = match &parser.token {
+        Token::Ident(_, Keyword::FIELDS | Keyword::COLUMNS) => {
+            let fields_span = parser.consume();
+            let mut options = Vec::new();
+            loop {
+                let option = match &parser.token {
+                    Token::Ident(_, Keyword::TERMINATED) => {
+                        let span = parser.consume_keywords(&[Keyword::TERMINATED, Keyword::BY])?;
+                        LoadDataFieldsOption::TerminatedBy(span, parser.consume_string()?)
+                    }
+                    Token::Ident(_, Keyword::OPTIONALLY | Keyword::ENCLOSED) => {
+                        let optionally = parser.skip_keyword(Keyword::OPTIONALLY);
+                        let span = parser
+                            .consume_keywords(&[Keyword::ENCLOSED, Keyword::BY])?
+                            .join_span(&optionally);
+                        LoadDataFieldsOption::EnclosedBy(
+                            span,
+                            parser.consume_string()?,
+                            optionally.is_some(),
+                        )
+                    }
+                    Token::Ident(_, Keyword::ESCAPED) => {
+                        let span = parser.consume_keywords(&[Keyword::ESCAPED, Keyword::BY])?;
+                        LoadDataFieldsOption::EscapedBy(span, parser.consume_string()?)
+                    }
+                    _ => break,
+                };
+                options.push(option);
+            }
+            if options.is_empty() {
+                parser.expected_failure("'TERMINATED BY', 'ENCLOSED BY' or 'ESCAPED BY'")?;
+            }
+            Some((fields_span, options))
+        }
+        _ => None,
+    };
+
+    let lines = match &parser.token {
+        Token::Ident(_, Keyword::LINES) => {
+            let lines_span = parser.consume();
+            let mut options = Vec::new();
+            loop {
+                let option = match &parser.token {
+                    Token::Ident(_, Keyword::STARTING) => {
+                        let span = parser.consume_keywords(&[Keyword::STARTING, Keyword::BY])?;
+                        LoadDataLinesOption::StartingBy(span, parser.consume_string()?)
+                    }
+                    Token::Ident(_, Keyword::TERMINATED) => {
+                        let span = parser.consume_keywords(&[Keyword::TERMINATED, Keyword::BY])?;
+                        LoadDataLinesOption::TerminatedBy(span, parser.consume_string()?)
+                    }
+                    _ => break,
+                };
+                options.push(option);
+            }
+            if options.is_empty() {
+                parser.expected_failure("'STARTING BY' or 'TERMINATED BY'")?;
+            }
+            Some((lines_span, options))
+        }
+        _ => None,
+    };
+
+    let ignore_lines = match parser.skip_keyword(Keyword::IGNORE) {
+        Some(ignore_span) => {
+            let count = parser.consume_int()?;
+            let span = match &parser.token {
+                Token::Ident(_, Keyword::ROWS) => parser.consume(),
+                _ => parser.consume_keyword(Keyword::LINES)?,
+            };
+            Some((ignore_span.join_span(&span), count))
+        }
+        None => None,
+    };
+
+    let columns = if matches!(parser.token, Token::LParen) {
+        parse_cols(parser)?
+    } else {
+        Vec::new()
+    };
+
+    // TODO [SET col_name={expr | DEFAULT}, ...]
+
+    Ok(LoadData {
+        load_data_span,
+        flags,
+        infile_span,
+        file_name,
+        on_duplicate,
+        into_table_span,
+        table,
+        charset,
+        fields,
+        lines,
+        ignore_lines,
+        columns,
+    })
+}
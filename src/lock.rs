@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+
+use crate::{
+    keywords::Keyword,
+    lexer::Token,
+    parser::{ParseError, Parser},
+    qualified_name::parse_qualified_name,
+    select::parse_optional_alias,
+    Identifier, QualifiedName, Span, Spanned, SpansMut,
+};
+
+/// Kind of lock to take on a table
+#[derive(Clone, Debug, PartialEq)]
+pub enum LockMode {
+    /// Span of "READ"
+    Read(Span),
+    /// Span of "READ LOCAL"
+    ReadLocal(Span),
+    /// Span of "WRITE"
+    Write(Span),
+    /// Span of "LOW_PRIORITY WRITE"
+    LowPriorityWrite(Span),
+}
+
+impl Spanned for LockMode {
+    fn span(&self) -> Span {
+        match &self {
+            LockMode::Read(v) => v.span(),
+            LockMode::ReadLocal(v) => v.span(),
+            LockMode::Write(v) => v.span(),
+            LockMode::LowPriorityWrite(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for LockMode {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LockMode::Read(v) => v.spans_mut(f),
+            LockMode::ReadLocal(v) => v.spans_mut(f),
+            LockMode::Write(v) => v.spans_mut(f),
+            LockMode::LowPriorityWrite(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Table to lock in a lock tables statement
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockTable<'a> {
+    /// Name of the table to lock
+    pub table: QualifiedName<'a>,
+    /// Span of "AS" if specified
+    pub as_span: Option<Span>,
+    /// Alias of the table if specified
+    pub as_: Option<Identifier<'a>>,
+    /// The kind of lock to take
+    pub mode: LockMode,
+}
+
+impl<'a> Spanned for LockTable<'a> {
+    fn span(&self) -> Span {
+        self.table
+            .join_span(&self.as_span)
+            .join_span(&self.as_)
+            .join_span(&self.mode)
+    }
+}
+
+impl<'a> SpansMut for LockTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.table.spans_mut(f);
+        self.as_span.spans_mut(f);
+        self.as_.spans_mut(f);
+        self.mode.spans_mut(f);
+    }
+}
+
+/// Represent a lock tables statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, LockTables, LockMode, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "LOCK TABLES `t1` WRITE, `t2` AS `a` READ LOCAL;
+///     INSERT INTO `t1` VALUES (1);
+///     UNLOCK TABLES;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// assert!(matches!(stmts.pop(), Some(Statement::UnlockTables(_))));
+/// stmts.pop();
+/// let lock_tables: LockTables = match stmts.pop() {
+///     Some(Statement::LockTables(l)) => l,
+///     _ => panic!("We should get a lock tables statement")
+/// };
+///
+/// assert!(lock_tables.tables[0].table.identifier.as_str() == "t1");
+/// assert!(matches!(lock_tables.tables[0].mode, LockMode::Write(_)));
+/// assert!(lock_tables.tables[1].as_.as_ref().unwrap().as_str() == "a");
+/// assert!(matches!(lock_tables.tables[1].mode, LockMode::ReadLocal(_)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockTables<'a> {
+    /// Span of "LOCK TABLES" or "LOCK TABLE"
+    pub lock_span: Span,
+    /// The tables to lock
+    pub tables: Vec<LockTable<'a>>,
+}
+
+impl<'a> Spanned for LockTables<'a> {
+    fn span(&self) -> Span {
+        self.lock_span.join_span(&self.tables)
+    }
+}
+
+impl<'a> SpansMut for LockTables<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.lock_span.spans_mut(f);
+        self.tables.spans_mut(f);
+    }
+}
+
+fn parse_lock_mode(parser: &mut Parser<'_, '_>) -> Result<LockMode, ParseError> {
+    match &parser.token {
+        Token::Ident(_, Keyword::READ) => {
+            let span = parser.consume_keyword(Keyword::READ)?;
+            Ok(match parser.skip_keyword(Keyword::LOCAL) {
+                Some(local_span) => LockMode::ReadLocal(span.join_span(&local_span)),
+                None => LockMode::Read(span),
+            })
+        }
+        Token::Ident(_, Keyword::WRITE) => {
+            Ok(LockMode::Write(parser.consume_keyword(Keyword::WRITE)?))
+        }
+        Token::Ident(_, Keyword::LOW_PRIORITY) => {
+            Ok(LockMode::LowPriorityWrite(parser.consume_keywords(&[
+                Keyword::LOW_PRIORITY,
+                Keyword::WRITE,
+            ])?))
+        }
+        _ => parser.expected_failure("'READ' or 'WRITE'"),
+    }
+}
+
+pub(crate) fn parse_lock_tables<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<LockTables<'a>, ParseError> {
+    let lock_span = parser.consume_keyword(Keyword::LOCK)?;
+    let lock_span = match &parser.token {
+        Token::Ident(_, Keyword::TABLE) => parser.consume_keyword(Keyword::TABLE)?,
+        _ => parser.consume_keyword(Keyword::TABLES)?,
+    }
+    .join_span(&lock_span);
+    let mut tables = Vec::new();
+    loop {
+        let table = parse_qualified_name(parser)?;
+        let (as_span, as_) = parse_optional_alias(parser)?;
+        let mode = parse_lock_mode(parser)?;
+        tables.push(LockTable {
+            table,
+            as_span,
+            as_,
+            mode,
+        });
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
+    }
+    Ok(LockTables { lock_span, tables })
+}
+
+pub(crate) fn parse_unlock_tables(parser: &mut Parser<'_, '_>) -> Result<Span, ParseError> {
+    let unlock_span = parser.consume_keyword(Keyword::UNLOCK)?;
+    Ok(match &parser.token {
+        Token::Ident(_, Keyword::TABLE) => parser.consume_keyword(Keyword::TABLE)?,
+        _ => parser.consume_keyword(Keyword::TABLES)?,
+    }
+    .join_span(&unlock_span))
+}
@@ -10,13 +10,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use alloc::{borrow::Cow, fmt::Write, format, string::String, vec::Vec};
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
 
 use crate::{
-    issue::Issue,
+    issue::{Issue, Message},
     keywords::Keyword,
     lexer::{Lexer, Token},
-    Identifier, ParseOptions, SString, Span, Spanned,
+    Delimiter, Identifier, IdentifierCase, ParseOptions, SString, Span, Spanned,
 };
 
 #[derive(Debug)]
@@ -27,36 +27,60 @@ pub(crate) enum ParseError {
 pub(crate) struct Parser<'a, 'b> {
     pub(crate) token: Token<'a>,
     pub(crate) span: Span,
+    /// Span of the last consumed token
+    pub(crate) last_span: Span,
     pub(crate) lexer: Lexer<'a>,
-    pub(crate) issues: &'b mut Vec<Issue>,
+    pub(crate) issues: ParserIssues<'b>,
     pub(crate) arg: usize,
     pub(crate) delimiter: Token<'a>,
     pub(crate) options: &'b ParseOptions,
     pub(crate) permit_compound_statements: bool,
 }
 
-pub(crate) fn decode_single_quoted_string(s: &str) -> Cow<'_, str> {
-    if !s.contains('\'') && !s.contains('\\') {
-        s.into()
-    } else {
-        let mut r = String::new();
-        let mut chars = s.chars();
-        loop {
-            match chars.next() {
-                None => break,
-                Some('\'') => {
-                    chars.next();
-                    r.push('\'');
-                }
-                Some(c) => r.push(c),
-            }
+/// Issues found while parsing, bounded by [ParseOptions::max_issues]
+pub(crate) struct ParserIssues<'b> {
+    issues: &'b mut Vec<Issue>,
+    /// Number of issues that may still be pushed
+    remaining: usize,
+    /// True once the last issue has been replaced by the "Too many issues" marker
+    truncated: bool,
+}
+
+impl<'b> ParserIssues<'b> {
+    fn new(issues: &'b mut Vec<Issue>, max_issues: Option<usize>) -> Self {
+        Self {
+            issues,
+            // Keep at least one issue so errors are never lost entirely
+            remaining: max_issues.map_or(usize::MAX, |v| v.max(1)),
+            truncated: false,
         }
-        r.into()
+    }
+
+    /// Push an issue, once the limit is reached the last issue is replaced by a
+    /// marker whose level is the highest of the issues it stands in for
+    pub(crate) fn push(&mut self, issue: Issue) {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            self.issues.push(issue);
+            return;
+        }
+        let last = match self.issues.last_mut() {
+            Some(v) => v,
+            None => return,
+        };
+        if !self.truncated {
+            self.truncated = true;
+            last.message = "Too many issues".into();
+            last.fragments.clear();
+        }
+        last.level = last.level.max(issue.level);
     }
 }
 
-pub(crate) fn decode_double_quoted_string(s: &str) -> Cow<'_, str> {
-    if !s.contains('"') && !s.contains('\\') {
+/// Collapse doubled quotes in the content of a quoted string, backslash
+/// escapes are kept as written, see [SString::decoded]
+fn decode_quoted_string(s: &str, quote: char) -> Cow<'_, str> {
+    if !s.contains(quote) {
         s.into()
     } else {
         let mut r = String::new();
@@ -64,9 +88,15 @@ pub(crate) fn decode_double_quoted_string(s: &str) -> Cow<'_, str> {
         loop {
             match chars.next() {
                 None => break,
-                Some('\'') => {
+                Some('\\') => {
+                    r.push('\\');
+                    if let Some(c) = chars.next() {
+                        r.push(c);
+                    }
+                }
+                Some(c) if c == quote => {
                     chars.next();
-                    r.push('\'');
+                    r.push(quote);
                 }
                 Some(c) => r.push(c),
             }
@@ -75,32 +105,29 @@ pub(crate) fn decode_double_quoted_string(s: &str) -> Cow<'_, str> {
     }
 }
 
-pub(crate) struct SingleQuotedString<'a>(pub(crate) &'a str);
+pub(crate) fn decode_single_quoted_string(s: &str) -> Cow<'_, str> {
+    decode_quoted_string(s, '\'')
+}
 
-impl<'a> alloc::fmt::Display for SingleQuotedString<'a> {
-    fn fmt(&self, f: &mut alloc::fmt::Formatter<'_>) -> alloc::fmt::Result {
-        f.write_char('\'')?;
-        for c in self.0.chars() {
-            if c == '\'' {
-                f.write_char('\'')?;
-            }
-            f.write_char(c)?;
-        }
-        f.write_char('\'')
-    }
+pub(crate) fn decode_double_quoted_string(s: &str) -> Cow<'_, str> {
+    decode_quoted_string(s, '"')
 }
 
 impl<'a, 'b> Parser<'a, 'b> {
     pub(crate) fn new(src: &'a str, issues: &'b mut Vec<Issue>, options: &'b ParseOptions) -> Self {
-        let mut lexer = Lexer::new(src);
+        let mut lexer = Lexer::new(src, options.keep_hints);
         let (token, span) = lexer.next_token();
         Self {
             token,
+            last_span: span.start..span.start,
             span,
             lexer,
-            issues,
+            issues: ParserIssues::new(issues, options.max_issues),
             arg: 0,
-            delimiter: Token::SemiColon,
+            delimiter: match options.default_delimiter {
+                Delimiter::SemiColon => Token::SemiColon,
+                Delimiter::DoubleDollar => Token::DoubleDollar,
+            },
             options,
             permit_compound_statements: false,
         }
@@ -184,18 +211,40 @@ impl<'a, 'b> Parser<'a, 'b> {
         let (token, span) = self.lexer.next_token();
         self.token = token;
         self.span = span;
+        self.last_span = stdin.1.clone();
         stdin
     }
 
     pub(crate) fn next(&mut self) {
         let (token, span) = self.lexer.next_token();
         self.token = token;
-        self.span = span;
+        self.last_span = core::mem::replace(&mut self.span, span);
+    }
+
+    /// Return the token n tokens after the current token without consuming anything
+    ///
+    /// peek_n(0) is the current token
+    pub(crate) fn peek_n(&self, n: usize) -> Token<'a> {
+        if n == 0 {
+            return self.token.clone();
+        }
+        let mut lexer = self.lexer.clone();
+        let mut token = self.token.clone();
+        for _ in 0..n {
+            if token == Token::Eof {
+                break;
+            }
+            token = lexer.next_token().0;
+        }
+        token
     }
 
     pub(crate) fn expected_error(&mut self, name: &'static str) {
-        self.issues
-            .push(Issue::err(format!("Expected '{}' here", name), &self.span));
+        let message = Message::Expected {
+            expected: name,
+            found: self.token.name(),
+        };
+        self.issues.push(Issue::err(message, &self.span));
     }
 
     pub(crate) fn expected_failure<T>(&mut self, name: &'static str) -> Result<T, ParseError> {
@@ -203,6 +252,21 @@ impl<'a, 'b> Parser<'a, 'b> {
         Err(ParseError::Unrecovered)
     }
 
+    fn unquoted_identifier(&self, v: &'a str, span: Span) -> Identifier<'a> {
+        let mut identifier = Identifier::new(v, span);
+        match self.options.fold_identifier_case {
+            IdentifierCase::None => (),
+            IdentifierCase::Lower if v.chars().any(char::is_uppercase) => {
+                identifier.value = v.to_lowercase().into()
+            }
+            IdentifierCase::Upper if v.chars().any(char::is_lowercase) => {
+                identifier.value = v.to_uppercase().into()
+            }
+            IdentifierCase::Lower | IdentifierCase::Upper => (),
+        }
+        identifier
+    }
+
     pub(crate) fn token_to_plain_identifier(
         &mut self,
         token: &Token<'a>,
@@ -224,7 +288,11 @@ impl<'a, 'b> Parser<'a, 'b> {
                         &span,
                     ));
                 }
-                Ok(Identifier::new(v, span))
+                if kw == &Keyword::QUOTED_IDENTIFIER {
+                    Ok(Identifier::new(v, span))
+                } else {
+                    Ok(self.unquoted_identifier(v, span))
+                }
             }
             _ => self.expected_failure("identifier"),
         }
@@ -253,7 +321,12 @@ impl<'a, 'b> Parser<'a, 'b> {
                         &self.span,
                     ));
                 }
-                Ok(Identifier::new(v, self.consume()))
+                if kw == &Keyword::QUOTED_IDENTIFIER {
+                    Ok(Identifier::new(v, self.consume()))
+                } else {
+                    let span = self.consume();
+                    Ok(self.unquoted_identifier(v, span))
+                }
             }
             Token::DoubleQuotedString(v) if self.options.dialect.is_postgresql() => {
                 Ok(Identifier::new(v, self.consume()))
@@ -320,6 +393,15 @@ impl<'a, 'b> Parser<'a, 'b> {
         span
     }
 
+    fn warn_double_quoted_string(&mut self) {
+        if self.options.warn_double_quoted_strings && self.options.dialect.is_maria() {
+            self.issues.push(Issue::warn(
+                "double quoted strings are identifiers with ANSI_QUOTES, use single quotes",
+                &self.span,
+            ));
+        }
+    }
+
     pub(crate) fn consume_string(&mut self) -> Result<SString<'a>, ParseError> {
         let (mut a, mut b) = match &self.token {
             Token::SingleQuotedString(v) => {
@@ -330,6 +412,7 @@ impl<'a, 'b> Parser<'a, 'b> {
             }
             Token::DoubleQuotedString(v) => {
                 let v = *v;
+                self.warn_double_quoted_string();
                 let span = self.span.clone();
                 self.next();
                 (decode_double_quoted_string(v), span)
@@ -344,6 +427,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                     self.next();
                 }
                 Token::DoubleQuotedString(v) => {
+                    self.warn_double_quoted_string();
                     b = b.join_span(&self.span);
                     a.to_mut().push_str(decode_double_quoted_string(v).as_ref());
                     self.next();
@@ -388,7 +472,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         }
     }
 
-    pub(crate) fn error<T>(&mut self, message: impl Into<String>) -> Result<T, ParseError> {
+    pub(crate) fn error<T>(&mut self, message: impl Into<Message>) -> Result<T, ParseError> {
         self.issues.push(Issue::err(message, &self.span));
         Err(ParseError::Unrecovered)
     }
@@ -401,3 +485,25 @@ impl<'a, 'b> Parser<'a, 'b> {
         self.error(format!("Not yet implemented at {}:{}", file, line))
     }
 }
+
+/// Parse an optional "IF NOT EXISTS", returning its span if present
+pub(crate) fn parse_if_not_exists(parser: &mut Parser<'_, '_>) -> Result<Option<Span>, ParseError> {
+    match parser.skip_keyword(Keyword::IF) {
+        Some(if_span) => Ok(Some(
+            parser
+                .consume_keywords(&[Keyword::NOT, Keyword::EXISTS])?
+                .join_span(&if_span),
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Parse an optional "IF EXISTS", returning its span if present
+pub(crate) fn parse_if_exists(parser: &mut Parser<'_, '_>) -> Result<Option<Span>, ParseError> {
+    match parser.skip_keyword(Keyword::IF) {
+        Some(if_span) => Ok(Some(
+            parser.consume_keyword(Keyword::EXISTS)?.join_span(&if_span),
+        )),
+        None => Ok(None),
+    }
+}
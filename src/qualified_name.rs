@@ -3,10 +3,10 @@ use alloc::vec::Vec;
 use crate::{
     lexer::Token,
     parser::{ParseError, Parser},
-    Identifier, Span, Spanned,
+    Identifier, Span, Spanned, SpansMut,
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct QualifiedName<'a> {
     pub prefix: Vec<(Identifier<'a>, Span)>,
     pub identifier: Identifier<'a>,
@@ -18,6 +18,13 @@ impl<'a> Spanned for QualifiedName<'a> {
     }
 }
 
+impl<'a> SpansMut for QualifiedName<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.prefix.spans_mut(f);
+        self.identifier.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_qualified_name<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<QualifiedName<'a>, ParseError> {
@@ -5,14 +5,17 @@ use crate::{
     lexer::Token,
     parser::{ParseError, Parser},
     qualified_name::parse_qualified_name,
-    QualifiedName, Span, Spanned,
+    QualifiedName, Span, Spanned, SpansMut,
 };
 
-#[derive(Debug, Clone)]
+/// A single "table TO new_table" rename
+#[derive(Debug, Clone, PartialEq)]
 pub struct TableToTable<'a> {
+    /// The table to rename
     pub table: QualifiedName<'a>,
     /// Span of "TO"
     pub to_span: Span,
+    /// The new name of the table
     pub new_table: QualifiedName<'a>,
 }
 
@@ -24,6 +27,14 @@ impl<'a> Spanned for TableToTable<'a> {
     }
 }
 
+impl<'a> SpansMut for TableToTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.table.spans_mut(f);
+        self.to_span.spans_mut(f);
+        self.new_table.spans_mut(f);
+    }
+}
+
 /// Represent a rename table statement
 /// ```
 /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, RenameTable, Statement};
@@ -42,14 +53,27 @@ impl<'a> Spanned for TableToTable<'a> {
 ///
 /// assert!(rename_table.table_to_tables.get(0).unwrap().table.identifier.as_str() == "t1");
 ///
-///
+/// // Several tables can be renamed atomically, e.g. to swap two tables
+/// let sql = "RENAME TABLE `t1` TO `tmp`, `t2` TO `t1`, `tmp` TO `t2`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// let rename_table: RenameTable = match stmts.pop() {
+///     Some(Statement::RenameTable(c)) => c,
+///     _ => panic!("We should get a rename table statement")
+/// };
+/// let renames: Vec<_> = rename_table.table_to_tables.iter()
+///     .map(|r| (r.table.identifier.as_str(), r.new_table.identifier.as_str()))
+///     .collect();
+/// assert_eq!(renames, [("t1", "tmp"), ("t2", "t1"), ("tmp", "t2")]);
+/// ```
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RenameTable<'a> {
     /// Span of "RENAME"
     pub rename_span: Span,
     /// Span of "TABLE" if specified
     pub table_span: Option<Span>,
+    /// The renames to perform, in order
     pub table_to_tables: Vec<TableToTable<'a>>,
 }
 
@@ -61,6 +85,14 @@ impl<'a> Spanned for RenameTable<'a> {
     }
 }
 
+impl<'a> SpansMut for RenameTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.rename_span.spans_mut(f);
+        self.table_span.spans_mut(f);
+        self.table_to_tables.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_rename_table<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<RenameTable<'a>, ParseError> {
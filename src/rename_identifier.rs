@@ -0,0 +1,652 @@
+use alloc::vec::Vec;
+
+use crate::{
+    alter::{AlterSpecification, ColumnPosition, IndexCol},
+    create::CreateDefinition,
+    data_type::{DataType, DataTypeProperty},
+    explain::DescribeColumn,
+    expression::{Expression, Function, IdentifierPart, JsonOnResponse},
+    grant::{GrantLevel, Privilege},
+    insert_replace::{OnConflictAction, OnConflictTarget},
+    select::{JoinSpecification, Select, SelectExpr, TableReference},
+    HandlerCondition, Identifier, QualifiedName, SignalCondition, Statement,
+};
+
+type Identifiers<'b, 'a> = Vec<&'b mut Identifier<'a>>;
+
+fn qualified_name_identifiers<'b, 'a>(
+    name: &'b mut QualifiedName<'a>,
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    for (identifier, _) in &mut name.prefix {
+        ans.push(identifier);
+    }
+    ans.push(&mut name.identifier);
+}
+
+fn privileges_identifiers<'b, 'a>(
+    privileges: &'b mut [Privilege<'a>],
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    for privilege in privileges {
+        for column in &mut privilege.columns {
+            ans.push(column);
+        }
+    }
+}
+
+fn grant_level_identifiers<'b, 'a>(level: &'b mut GrantLevel<'a>, ans: &mut Identifiers<'b, 'a>) {
+    match level {
+        GrantLevel::Database(database, _) => ans.push(database),
+        GrantLevel::Object(name) => qualified_name_identifiers(name, ans),
+        GrantLevel::CurrentDatabase(_) | GrantLevel::Global(_) => (),
+    }
+}
+
+fn index_cols_identifiers<'b, 'a>(cols: &'b mut [IndexCol<'a>], ans: &mut Identifiers<'b, 'a>) {
+    for col in cols {
+        ans.push(&mut col.name);
+    }
+}
+
+fn statements_identifiers<'b, 'a>(
+    statements: &'b mut [Statement<'a>],
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    for statement in statements {
+        statement_identifiers(statement, ans);
+    }
+}
+
+/// Collect the table, column and alias identifiers of a statement
+pub(crate) fn statement_identifiers<'b, 'a>(
+    statement: &'b mut Statement<'a>,
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    match statement {
+        Statement::CreateView(v) => {
+            qualified_name_identifiers(&mut v.name, ans);
+            select_identifiers(&mut v.select, ans);
+        }
+        Statement::CreateTable(v) => {
+            qualified_name_identifiers(&mut v.identifier, ans);
+            for definition in &mut v.create_definitions {
+                match definition {
+                    CreateDefinition::ColumnDefinition {
+                        identifier,
+                        data_type,
+                        ..
+                    } => {
+                        ans.push(identifier);
+                        data_type_identifiers(data_type, ans);
+                    }
+                    CreateDefinition::IndexDefinition { cols, .. } => {
+                        index_cols_identifiers(cols, ans)
+                    }
+                    CreateDefinition::CheckConstraint { expr, .. } => {
+                        expression_identifiers(expr, ans)
+                    }
+                    CreateDefinition::Period { start, end, .. } => {
+                        ans.push(start);
+                        ans.push(end);
+                    }
+                    CreateDefinition::ConstraintDefinition {
+                        cols,
+                        references_table,
+                        references_cols,
+                        ..
+                    } => {
+                        for col in cols {
+                            ans.push(col);
+                        }
+                        ans.push(references_table);
+                        for col in references_cols {
+                            ans.push(col);
+                        }
+                    }
+                }
+            }
+            if let Some(select) = &mut v.select {
+                statement_identifiers(select, ans);
+            }
+        }
+        Statement::CreateTrigger(v) => {
+            ans.push(&mut v.table);
+            if let Some((_, when)) = &mut v.when {
+                expression_identifiers(when, ans);
+            }
+            statement_identifiers(&mut v.statement, ans);
+        }
+        Statement::CreateFunction(v) => {
+            for (_, identifier, data_type) in &mut v.params {
+                ans.push(identifier);
+                data_type_identifiers(data_type, ans);
+            }
+            if let Some(return_) = &mut v.return_ {
+                statement_identifiers(return_, ans);
+            }
+        }
+        Statement::CreateIndex(v) => {
+            qualified_name_identifiers(&mut v.table_name, ans);
+            for column_name in &mut v.column_names {
+                ans.push(column_name);
+            }
+            if let Some((_, where_)) = &mut v.where_ {
+                expression_identifiers(where_, ans);
+            }
+        }
+        Statement::AlterTable(v) => {
+            qualified_name_identifiers(&mut v.table, ans);
+            for specification in &mut v.alter_specifications {
+                alter_specification_identifiers(specification, ans);
+            }
+        }
+        Statement::Select(v) => select_identifiers(v, ans),
+        Statement::Delete(v) => {
+            for table in &mut v.tables {
+                qualified_name_identifiers(table, ans);
+            }
+            for reference in &mut v.using {
+                table_reference_identifiers(reference, ans);
+            }
+            if let Some((_, partitions)) = &mut v.partition {
+                for partition in partitions {
+                    ans.push(partition);
+                }
+            }
+            if let Some((where_, _)) = &mut v.where_ {
+                expression_identifiers(where_, ans);
+            }
+        }
+        Statement::InsertReplace(v) => {
+            qualified_name_identifiers(&mut v.table, ans);
+            for column in &mut v.columns {
+                ans.push(column);
+            }
+            if let Some((_, rows)) = &mut v.values {
+                for row in rows {
+                    for value in row.iter_mut() {
+                        expression_identifiers(value, ans);
+                    }
+                }
+            }
+            if let Some(select) = &mut v.select {
+                select_identifiers(select, ans);
+            }
+            if let Some(set) = &mut v.set {
+                for pair in &mut set.pairs {
+                    ans.push(&mut pair.column);
+                    expression_identifiers(&mut pair.value, ans);
+                }
+            }
+            if let Some(update) = &mut v.on_duplicate_key_update {
+                for pair in &mut update.pairs {
+                    ans.push(&mut pair.column);
+                    expression_identifiers(&mut pair.value, ans);
+                }
+            }
+            if let Some(on_conflict) = &mut v.on_conflict {
+                if let OnConflictTarget::Column { name } = &mut on_conflict.target {
+                    ans.push(name);
+                }
+                if let OnConflictAction::DoUpdateSet { sets, where_, .. } = &mut on_conflict.action
+                {
+                    for (column, value) in sets {
+                        ans.push(column);
+                        expression_identifiers(value, ans);
+                    }
+                    if let Some((_, where_)) = where_ {
+                        expression_identifiers(where_, ans);
+                    }
+                }
+            }
+            if let Some((_, returning)) = &mut v.returning {
+                select_exprs_identifiers(returning, ans);
+            }
+        }
+        Statement::Update(v) => {
+            for reference in &mut v.tables {
+                table_reference_identifiers(reference, ans);
+            }
+            for (target, value) in &mut v.set {
+                for identifier in target {
+                    ans.push(identifier);
+                }
+                expression_identifiers(value, ans);
+            }
+            if let Some((where_, _)) = &mut v.where_ {
+                expression_identifiers(where_, ans);
+            }
+        }
+        Statement::DropTable(v) => {
+            for table in &mut v.tables {
+                qualified_name_identifiers(table, ans);
+            }
+        }
+        Statement::TruncateTable(v) => qualified_name_identifiers(&mut v.table_name, ans),
+        Statement::RenameTable(v) => {
+            for table_to_table in &mut v.table_to_tables {
+                qualified_name_identifiers(&mut table_to_table.table, ans);
+                qualified_name_identifiers(&mut table_to_table.new_table, ans);
+            }
+        }
+        Statement::Block(v) => statements_identifiers(&mut v.statements, ans),
+        Statement::Do(v) => statements_identifiers(v, ans),
+        Statement::If(v) => {
+            for condition in &mut v.conditions {
+                expression_identifiers(&mut condition.search_condition, ans);
+                statements_identifiers(&mut condition.then, ans);
+            }
+            if let Some((_, else_)) = &mut v.else_ {
+                statements_identifiers(else_, ans);
+            }
+        }
+        Statement::While(v) => {
+            expression_identifiers(&mut v.condition, ans);
+            statements_identifiers(&mut v.body, ans);
+        }
+        Statement::Repeat(v) => {
+            statements_identifiers(&mut v.body, ans);
+            expression_identifiers(&mut v.condition, ans);
+        }
+        Statement::Loop(v) => statements_identifiers(&mut v.body, ans),
+        Statement::Call(v) => {
+            for arg in &mut v.args {
+                expression_identifiers(arg, ans);
+            }
+        }
+        Statement::Grant(v) => {
+            privileges_identifiers(&mut v.privileges, ans);
+            grant_level_identifiers(&mut v.level, ans);
+        }
+        Statement::Revoke(v) => {
+            privileges_identifiers(&mut v.privileges, ans);
+            grant_level_identifiers(&mut v.level, ans);
+        }
+        Statement::Explain(v) => statement_identifiers(&mut v.inner, ans),
+        Statement::DeclareHandler(v) => {
+            for condition in &mut v.conditions {
+                if let HandlerCondition::Name(name) = condition {
+                    ans.push(name);
+                }
+            }
+            statement_identifiers(&mut v.statement, ans);
+        }
+        Statement::Describe(v) => {
+            qualified_name_identifiers(&mut v.table, ans);
+            if let Some(DescribeColumn::Name(column)) = &mut v.column {
+                ans.push(column);
+            }
+        }
+        Statement::Case(v) => {
+            expression_identifiers(&mut v.value, ans);
+            for when in &mut v.whens {
+                expression_identifiers(&mut when.when, ans);
+                statements_identifiers(&mut when.then, ans);
+            }
+            if let Some((_, else_)) = &mut v.else_ {
+                statements_identifiers(else_, ans);
+            }
+        }
+        Statement::Union(v) => {
+            statement_identifiers(&mut v.left, ans);
+            for with in &mut v.with {
+                statement_identifiers(&mut with.union_statement, ans);
+            }
+            if let Some((_, order_by)) = &mut v.order_by {
+                for key in order_by {
+                    expression_identifiers(&mut key.expr, ans);
+                }
+            }
+        }
+        Statement::Set(v) => {
+            for (_, value) in &mut v.values {
+                expression_identifiers(value, ans);
+            }
+        }
+        Statement::LockTables(v) => {
+            for table in &mut v.tables {
+                qualified_name_identifiers(&mut table.table, ans);
+                if let Some(as_) = &mut table.as_ {
+                    ans.push(as_);
+                }
+            }
+        }
+        Statement::TableMaintenance(v) => {
+            for table in &mut v.tables {
+                qualified_name_identifiers(table, ans);
+            }
+        }
+        Statement::Signal(v) => {
+            if let Some(SignalCondition::Name(name)) = &mut v.condition {
+                ans.push(name);
+            }
+            for (_, value) in &mut v.set_items {
+                expression_identifiers(value, ans);
+            }
+        }
+        Statement::LoadData(v) => {
+            qualified_name_identifiers(&mut v.table, ans);
+            for column in &mut v.columns {
+                ans.push(column);
+            }
+        }
+        Statement::DropIndex(v) => qualified_name_identifiers(&mut v.table_name, ans),
+        Statement::DropView(v) => {
+            for view in &mut v.views {
+                qualified_name_identifiers(view, ans);
+            }
+        }
+        Statement::ShowTables(v) => {
+            if let Some((_, db)) = &mut v.db {
+                ans.push(db);
+            }
+        }
+        Statement::ShowColumns(v) => {
+            qualified_name_identifiers(&mut v.table, ans);
+            if let Some((_, db)) = &mut v.db {
+                ans.push(db);
+            }
+        }
+        Statement::ShowCreateTable(v) => qualified_name_identifiers(&mut v.table, ans),
+        Statement::Copy(v) => {
+            ans.push(&mut v.table);
+            for column in &mut v.columns {
+                ans.push(column);
+            }
+        }
+        // These name routines, databases, labels and other objects rather
+        // than tables, columns or aliases
+        Statement::DropFunction(_)
+        | Statement::DropProcedure(_)
+        | Statement::DropEvent(_)
+        | Statement::DropDatabase(_)
+        | Statement::DropServer(_)
+        | Statement::DropTrigger(_)
+        | Statement::CreateTypeEnum(_)
+        | Statement::CreateDatabase(_)
+        | Statement::CreateTablespace(_)
+        | Statement::ShowDatabases(_)
+        | Statement::Use(_)
+        | Statement::Transaction(_)
+        | Statement::Leave(_, _)
+        | Statement::Iterate(_, _)
+        | Statement::UnlockTables(_)
+        | Statement::Stdin(_, _)
+        | Statement::Invalid(_) => (),
+    }
+}
+
+fn alter_specification_identifiers<'b, 'a>(
+    specification: &'b mut AlterSpecification<'a>,
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    match specification {
+        AlterSpecification::AddColumn {
+            identifier,
+            data_type,
+            position,
+            ..
+        } => {
+            ans.push(identifier);
+            data_type_identifiers(data_type, ans);
+            if let Some(ColumnPosition::After(_, col)) = position {
+                ans.push(col);
+            }
+        }
+        AlterSpecification::Modify {
+            col,
+            definition,
+            position,
+            ..
+        } => {
+            ans.push(col);
+            data_type_identifiers(definition, ans);
+            if let Some(ColumnPosition::After(_, col)) = position {
+                ans.push(col);
+            }
+        }
+        AlterSpecification::AddIndex { cols, .. } => index_cols_identifiers(cols, ans),
+        AlterSpecification::AddForeignKey {
+            cols,
+            references_table,
+            references_cols,
+            ..
+        } => {
+            index_cols_identifiers(cols, ans);
+            ans.push(references_table);
+            for col in references_cols {
+                ans.push(col);
+            }
+        }
+        AlterSpecification::RenameTo { new_name, .. } => qualified_name_identifiers(new_name, ans),
+        _ => (),
+    }
+}
+
+fn data_type_identifiers<'b, 'a>(data_type: &'b mut DataType<'a>, ans: &mut Identifiers<'b, 'a>) {
+    for property in &mut data_type.properties {
+        match property {
+            DataTypeProperty::Default(e)
+            | DataTypeProperty::As((_, e))
+            | DataTypeProperty::Check((_, e)) => expression_identifiers(e, ans),
+            DataTypeProperty::References { table, cols, .. } => {
+                ans.push(table);
+                for col in cols {
+                    ans.push(col);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn select_exprs_identifiers<'b, 'a>(
+    select_exprs: &'b mut [SelectExpr<'a>],
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    for select_expr in select_exprs {
+        expression_identifiers(&mut select_expr.expr, ans);
+        if let Some(as_) = &mut select_expr.as_ {
+            ans.push(as_);
+        }
+    }
+}
+
+fn select_identifiers<'b, 'a>(select: &'b mut Select<'a>, ans: &mut Identifiers<'b, 'a>) {
+    select_exprs_identifiers(&mut select.select_exprs, ans);
+    if let Some(references) = &mut select.table_references {
+        for reference in references {
+            table_reference_identifiers(reference, ans);
+        }
+    }
+    if let Some((where_, _)) = &mut select.where_ {
+        expression_identifiers(where_, ans);
+    }
+    if let Some((_, group_by)) = &mut select.group_by {
+        expressions_identifiers(group_by, ans);
+    }
+    if let Some((having, _)) = &mut select.having {
+        expression_identifiers(having, ans);
+    }
+    if let Some((_, order_by)) = &mut select.order_by {
+        for key in order_by {
+            expression_identifiers(&mut key.expr, ans);
+        }
+    }
+}
+
+fn table_reference_identifiers<'b, 'a>(
+    reference: &'b mut TableReference<'a>,
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    match reference {
+        TableReference::Table {
+            identifier,
+            partition,
+            as_,
+            ..
+        } => {
+            qualified_name_identifiers(identifier, ans);
+            if let Some((_, partitions)) = partition {
+                for partition in partitions {
+                    ans.push(partition);
+                }
+            }
+            if let Some(as_) = as_ {
+                ans.push(as_);
+            }
+        }
+        TableReference::Query { query, as_, .. } => {
+            statement_identifiers(query, ans);
+            if let Some(as_) = as_ {
+                ans.push(as_);
+            }
+        }
+        TableReference::Join {
+            left,
+            right,
+            specification,
+            ..
+        } => {
+            table_reference_identifiers(left, ans);
+            table_reference_identifiers(right, ans);
+            match specification {
+                Some(JoinSpecification::On(e, _)) => expression_identifiers(e, ans),
+                Some(JoinSpecification::Using(cols, _)) => {
+                    for col in cols {
+                        ans.push(col);
+                    }
+                }
+                None => (),
+            }
+        }
+    }
+}
+
+fn expressions_identifiers<'b, 'a>(
+    expressions: &'b mut [Expression<'a>],
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    for expression in expressions {
+        expression_identifiers(expression, ans);
+    }
+}
+
+fn function_identifiers<'b, 'a>(function: &'b mut Function<'a>, ans: &mut Identifiers<'b, 'a>) {
+    if let Function::Qualified(name) = function {
+        qualified_name_identifiers(name, ans);
+    }
+}
+
+fn expression_identifiers<'b, 'a>(
+    expression: &'b mut Expression<'a>,
+    ans: &mut Identifiers<'b, 'a>,
+) {
+    match expression {
+        Expression::Identifier(parts) => {
+            for part in parts {
+                if let IdentifierPart::Name(identifier) = part {
+                    ans.push(identifier);
+                }
+            }
+        }
+        Expression::Binary { lhs, rhs, .. } => {
+            expression_identifiers(lhs, ans);
+            expression_identifiers(rhs, ans);
+        }
+        Expression::Unary { operand, .. } => expression_identifiers(operand, ans),
+        Expression::Subquery(s) | Expression::Exists(s) => statement_identifiers(s, ans),
+        Expression::Function(function, args, _)
+        | Expression::AggregateFunction { function, args, .. } => {
+            function_identifiers(function, ans);
+            expressions_identifiers(args, ans);
+        }
+        Expression::Row { values: args, .. } => expressions_identifiers(args, ans),
+        Expression::Match {
+            columns, against, ..
+        } => {
+            expressions_identifiers(columns, ans);
+            expression_identifiers(against, ans);
+        }
+        Expression::WindowFunction {
+            function,
+            args,
+            window_spec,
+            ..
+        } => {
+            function_identifiers(function, ans);
+            expressions_identifiers(args, ans);
+            for key in &mut window_spec.order_by.1 {
+                expression_identifiers(&mut key.expr, ans);
+            }
+        }
+        Expression::In { lhs, rhs, .. } => {
+            expression_identifiers(lhs, ans);
+            expressions_identifiers(rhs, ans);
+        }
+        Expression::Is(e, _, _) => expression_identifiers(e, ans),
+        Expression::Case {
+            value,
+            whens,
+            else_,
+            ..
+        } => {
+            if let Some(value) = value {
+                expression_identifiers(value, ans);
+            }
+            for when in whens {
+                expression_identifiers(&mut when.when, ans);
+                expression_identifiers(&mut when.then, ans);
+            }
+            if let Some((_, else_)) = else_ {
+                expression_identifiers(else_, ans);
+            }
+        }
+        Expression::Trim { remove, expr, .. } => {
+            if let Some(remove) = remove {
+                expression_identifiers(remove, ans);
+            }
+            expression_identifiers(expr, ans);
+        }
+        Expression::JsonValue {
+            doc,
+            path,
+            on_empty,
+            on_error,
+            ..
+        } => {
+            expression_identifiers(doc, ans);
+            expression_identifiers(path, ans);
+            for (response, _) in [on_empty, on_error].into_iter().flatten() {
+                if let JsonOnResponse::Default(_, value) = response {
+                    expression_identifiers(value, ans);
+                }
+            }
+        }
+        Expression::GroupConcat { expr, order_by, .. } => {
+            expression_identifiers(expr, ans);
+            if let Some((_, order)) = order_by {
+                for key in order {
+                    expression_identifiers(&mut key.expr, ans);
+                }
+            }
+        }
+        Expression::Cast { expr, .. }
+        | Expression::Collate { expr, .. }
+        | Expression::JsonExtract { target: expr, .. }
+        | Expression::Count { expr, .. }
+        | Expression::Convert { expr, .. }
+        | Expression::Interval { expr, .. } => expression_identifiers(expr, ans),
+        Expression::Null(_)
+        | Expression::Bool(_, _)
+        | Expression::String(_)
+        | Expression::Integer(_)
+        | Expression::ListHack(_)
+        | Expression::Float(_)
+        | Expression::Arg(_)
+        | Expression::Invalid(_)
+        | Expression::Variable { .. }
+        | Expression::CharsetString { .. } => (),
+    }
+}
@@ -13,28 +13,39 @@ use alloc::{boxed::Box, vec::Vec};
 
 use crate::qualified_name::parse_qualified_name;
 use crate::{
-    expression::{parse_expression, Expression},
+    alter::parse_cols,
+    expression::{parse_expression, Expression, UnaryOperator},
     keywords::Keyword,
     lexer::Token,
     parser::{ParseError, Parser},
     span::OptSpanned,
     statement::parse_compound_query,
-    Identifier, Span, Spanned, Statement,
+    Identifier, Span, Spanned, SpansMut, Statement,
 };
 use crate::{Issue, QualifiedName};
 
 /// Value in select
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SelectExpr<'a> {
     /// Value to select
     pub expr: Expression<'a>,
-    /// Optional name to give value if specified
+    /// Span of "AS" if specified
+    pub as_span: Option<Span>,
+    /// Optional name to give value if specified, with or without "AS"
     pub as_: Option<Identifier<'a>>,
 }
 
 impl<'a> Spanned for SelectExpr<'a> {
     fn span(&self) -> Span {
-        self.expr.join_span(&self.as_)
+        self.expr.join_span(&self.as_span).join_span(&self.as_)
+    }
+}
+
+impl<'a> SpansMut for SelectExpr<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.expr.spans_mut(f);
+        self.as_span.spans_mut(f);
+        self.as_.spans_mut(f);
     }
 }
 
@@ -42,16 +53,36 @@ pub(crate) fn parse_select_expr<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<SelectExpr<'a>, ParseError> {
     let expr = parse_expression(parser, false)?;
-    let as_ = if parser.skip_keyword(Keyword::AS).is_some() {
+    let (as_span, as_) = parse_optional_alias(parser)?;
+    Ok(SelectExpr { expr, as_span, as_ })
+}
+
+/// Parse "PARTITION (partition_names)" selecting partitions of a table
+pub(crate) fn parse_partition_selection<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<Option<(Span, Vec<Identifier<'a>>)>, ParseError> {
+    match parser.skip_keyword(Keyword::PARTITION) {
+        Some(span) => Ok(Some((span, parse_cols(parser)?))),
+        None => Ok(None),
+    }
+}
+
+/// Parse "[AS] alias", returning the span of "AS" and the alias. "AS" may
+/// only be omitted for aliases that are not reserved keywords
+pub(crate) fn parse_optional_alias<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<(Option<Span>, Option<Identifier<'a>>), ParseError> {
+    let as_span = parser.skip_keyword(Keyword::AS);
+    let as_ = if as_span.is_some() || matches!(&parser.token, Token::Ident(_, k) if !k.reserved()) {
         Some(parser.consume_plain_identifier()?)
     } else {
         None
     };
-    Ok(SelectExpr { expr, as_ })
+    Ok((as_span, as_))
 }
 
 /// Specification for join
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum JoinSpecification<'a> {
     /// On specification expression and span of "ON"
     On(Expression<'a>, Span),
@@ -68,8 +99,23 @@ impl<'a> Spanned for JoinSpecification<'a> {
     }
 }
 
+impl<'a> SpansMut for JoinSpecification<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            JoinSpecification::On(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            JoinSpecification::Using(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+        }
+    }
+}
+
 /// Type of join
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum JoinType {
     Inner(Span),
     Cross(Span),
@@ -99,13 +145,32 @@ impl Spanned for JoinType {
     }
 }
 
+impl SpansMut for JoinType {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            JoinType::Inner(v) => v.spans_mut(f),
+            JoinType::Cross(v) => v.spans_mut(f),
+            JoinType::Normal(v) => v.spans_mut(f),
+            JoinType::Straight(v) => v.spans_mut(f),
+            JoinType::Left(v) => v.spans_mut(f),
+            JoinType::Right(v) => v.spans_mut(f),
+            JoinType::Natural(v) => v.spans_mut(f),
+            JoinType::NaturalInner(v) => v.spans_mut(f),
+            JoinType::NaturalLeft(v) => v.spans_mut(f),
+            JoinType::NaturalRight(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Reference to table in select
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TableReference<'a> {
     /// Reference to a table or view
     Table {
         /// Name of table to to select from
         identifier: QualifiedName<'a>,
+        /// Span of "PARTITION" and names of the selected partitions if specified
+        partition: Option<(Span, Vec<Identifier<'a>>)>,
         /// Span of "AS" if specified
         as_span: Option<Span>,
         /// Alias for table if specified
@@ -139,9 +204,11 @@ impl<'a> Spanned for TableReference<'a> {
         match &self {
             TableReference::Table {
                 identifier,
+                partition,
                 as_span,
                 as_,
             } => identifier
+                .opt_join_span(partition)
                 .opt_join_span(as_span)
                 .opt_join_span(as_)
                 .expect("span of table"),
@@ -163,27 +230,68 @@ impl<'a> Spanned for TableReference<'a> {
     }
 }
 
+impl<'a> SpansMut for TableReference<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TableReference::Table {
+                identifier,
+                partition,
+                as_span,
+                as_,
+            } => {
+                identifier.spans_mut(f);
+                partition.spans_mut(f);
+                as_span.spans_mut(f);
+                as_.spans_mut(f);
+            }
+            TableReference::Query {
+                query,
+                as_span,
+                as_,
+            } => {
+                query.spans_mut(f);
+                as_span.spans_mut(f);
+                as_.spans_mut(f);
+            }
+            TableReference::Join {
+                join,
+                left,
+                right,
+                specification,
+            } => {
+                join.spans_mut(f);
+                left.spans_mut(f);
+                right.spans_mut(f);
+                specification.spans_mut(f);
+            }
+        }
+    }
+}
+
 pub(crate) fn parse_table_reference_inner<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<TableReference<'a>, ParseError> {
     // TODO [LATERAL] table_subquery [AS] alias [(col_list)]
-    // if parser.skip_token(Token::LParen).is_some() {
-    //     let a = parse_table_reference(parser)?;
-    //     parser.consume_token(Token::RParen)?;
-    //     return Ok(a);
-    // }
+
+    // A parenthesized join group such as "(b JOIN c ON ...)" as opposed to
+    // a derived table, whose first token after the parentheses is SELECT
+    if parser.token == Token::LParen {
+        let mut n = 1;
+        while parser.peek_n(n) == Token::LParen {
+            n += 1;
+        }
+        if !matches!(parser.peek_n(n), Token::Ident(_, Keyword::SELECT)) {
+            parser.consume_token(Token::LParen)?;
+            let a = parse_table_reference(parser)?;
+            parser.consume_token(Token::RParen)?;
+            return Ok(a);
+        }
+    }
 
     match &parser.token {
         Token::Ident(_, Keyword::SELECT) | Token::LParen => {
             let query = parse_compound_query(parser)?;
-            let as_span = parser.skip_keyword(Keyword::AS);
-            let as_ = if as_span.is_some()
-                || (matches!(&parser.token, Token::Ident(_, k) if !k.reserved()))
-            {
-                Some(parser.consume_plain_identifier()?)
-            } else {
-                None
-            };
+            let (as_span, as_) = parse_optional_alias(parser)?;
             Ok(TableReference::Query {
                 query: Box::new(query),
                 as_span,
@@ -206,19 +314,13 @@ pub(crate) fn parse_table_reference_inner<'a>(
             // index_list:
             //     index_name [, index_name] .
 
-            // TODO [PARTITION (partition_names)] [[AS] alias]
-            let as_span = parser.skip_keyword(Keyword::AS);
-            let as_ = if as_span.is_some()
-                || (matches!(&parser.token, Token::Ident(_, k) if !k.reserved()))
-            {
-                Some(parser.consume_plain_identifier()?)
-            } else {
-                None
-            };
+            let partition = parse_partition_selection(parser)?;
+            let (as_span, as_) = parse_optional_alias(parser)?;
 
             // TODO [index_hint_list]
             Ok(TableReference::Table {
                 identifier,
+                partition,
                 as_span,
                 as_,
             })
@@ -348,7 +450,7 @@ pub(crate) fn parse_table_reference<'a>(
 }
 
 /// Flags specified after SELECT
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SelectFlag {
     All(Span),
     Distinct(Span),
@@ -379,13 +481,41 @@ impl Spanned for SelectFlag {
     }
 }
 
+impl SpansMut for SelectFlag {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            SelectFlag::All(v) => v.spans_mut(f),
+            SelectFlag::Distinct(v) => v.spans_mut(f),
+            SelectFlag::DistinctRow(v) => v.spans_mut(f),
+            SelectFlag::HighPriority(v) => v.spans_mut(f),
+            SelectFlag::StraightJoin(v) => v.spans_mut(f),
+            SelectFlag::SqlSmallResult(v) => v.spans_mut(f),
+            SelectFlag::SqlBigResult(v) => v.spans_mut(f),
+            SelectFlag::SqlBufferResult(v) => v.spans_mut(f),
+            SelectFlag::SqlNoCache(v) => v.spans_mut(f),
+            SelectFlag::SqlCalcFoundRows(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Ordering direction
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OrderFlag {
     Asc(Span),
     Desc(Span),
     None,
 }
+
+impl SpansMut for OrderFlag {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            OrderFlag::Asc(v) => v.spans_mut(f),
+            OrderFlag::Desc(v) => v.spans_mut(f),
+            OrderFlag::None => (),
+        }
+    }
+}
+
 impl OptSpanned for OrderFlag {
     fn opt_span(&self) -> Option<Span> {
         match &self {
@@ -396,8 +526,126 @@ impl OptSpanned for OrderFlag {
     }
 }
 
+/// Placement of nulls in an order key
+#[derive(Debug, Clone, PartialEq)]
+pub enum NullsOrder {
+    /// Span of "NULLS FIRST"
+    First(Span),
+    /// Span of "NULLS LAST"
+    Last(Span),
+}
+
+impl Spanned for NullsOrder {
+    fn span(&self) -> Span {
+        match &self {
+            NullsOrder::First(v) => v.span(),
+            NullsOrder::Last(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for NullsOrder {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            NullsOrder::First(v) => v.spans_mut(f),
+            NullsOrder::Last(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Key in an "ORDER BY" list, "expr [COLLATE collation] [ASC | DESC] [NULLS {FIRST | LAST}]"
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderKey<'a> {
+    /// Expression to order by
+    pub expr: Expression<'a>,
+    /// Span of "COLLATE" and the collation if specified
+    pub collate: Option<(Span, Identifier<'a>)>,
+    /// Ordering direction
+    pub direction: OrderFlag,
+    /// Placement of nulls if specified
+    pub nulls: Option<NullsOrder>,
+}
+
+impl<'a> Spanned for OrderKey<'a> {
+    fn span(&self) -> Span {
+        self.expr
+            .join_span(&self.collate)
+            .join_span(&self.direction)
+            .join_span(&self.nulls)
+    }
+}
+
+impl<'a> SpansMut for OrderKey<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.expr.spans_mut(f);
+        self.collate.spans_mut(f);
+        self.direction.spans_mut(f);
+        self.nulls.spans_mut(f);
+    }
+}
+
+fn parse_order_key<'a>(parser: &mut Parser<'a, '_>) -> Result<OrderKey<'a>, ParseError> {
+    let (expr, collate) = match parse_expression(parser, false)? {
+        Expression::Collate {
+            expr,
+            collate_span,
+            collation,
+        } => (*expr, Some((collate_span, collation))),
+        expr => (expr, None),
+    };
+    let direction = match &parser.token {
+        Token::Ident(_, Keyword::ASC) => OrderFlag::Asc(parser.consume()),
+        Token::Ident(_, Keyword::DESC) => OrderFlag::Desc(parser.consume()),
+        _ => OrderFlag::None,
+    };
+    let nulls = match parser.skip_keyword(Keyword::NULLS) {
+        Some(nulls_span) => {
+            let nulls = match &parser.token {
+                Token::Ident(_, Keyword::FIRST) => {
+                    NullsOrder::First(nulls_span.join_span(&parser.consume()))
+                }
+                Token::Ident(_, Keyword::LAST) => {
+                    NullsOrder::Last(nulls_span.join_span(&parser.consume()))
+                }
+                _ => parser.expected_failure("'FIRST' or 'LAST'")?,
+            };
+            if !parser.options.dialect.is_postgresql() {
+                parser.issues.push(Issue::err(
+                    "NULLS FIRST/LAST is only supported by PostgreSQL",
+                    &nulls,
+                ));
+            }
+            Some(nulls)
+        }
+        None => None,
+    };
+    Ok(OrderKey {
+        expr,
+        collate,
+        direction,
+        nulls,
+    })
+}
+
+/// Parse "ORDER BY" and the following keys, returning the span of "ORDER BY"
+///
+/// Shared by selects, unions, window specifications and "GROUP_CONCAT"
+pub(crate) fn parse_order_by<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<(Span, Vec<OrderKey<'a>>), ParseError> {
+    let span = parser.consume_keywords(&[Keyword::ORDER, Keyword::BY])?;
+    let mut keys = Vec::new();
+    loop {
+        keys.push(parse_order_key(parser)?);
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
+    }
+    Ok((span, keys))
+}
+
 /// Lock strength for locking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum LockStrength {
     Update(Span),
     Share(Span),
@@ -415,12 +663,34 @@ impl Spanned for LockStrength {
     }
 }
 
-#[derive(Debug, Clone)]
+impl SpansMut for LockStrength {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LockStrength::Update(v) => v.spans_mut(f),
+            LockStrength::Share(v) => v.spans_mut(f),
+            LockStrength::NoKeyUpdate(v) => v.spans_mut(f),
+            LockStrength::KeyShare(v) => v.spans_mut(f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum LockWait {
     NoWait(Span),
     SkipLocket(Span),
     Default,
 }
+
+impl SpansMut for LockWait {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            LockWait::NoWait(v) => v.spans_mut(f),
+            LockWait::SkipLocket(v) => v.spans_mut(f),
+            LockWait::Default => (),
+        }
+    }
+}
+
 impl OptSpanned for LockWait {
     fn opt_span(&self) -> Option<Span> {
         match &self {
@@ -431,7 +701,7 @@ impl OptSpanned for LockWait {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Locking<'a> {
     /// Span of "FOR"
     pub for_span: Span,
@@ -448,6 +718,15 @@ impl<'a> Spanned for Locking<'a> {
     }
 }
 
+impl<'a> SpansMut for Locking<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.for_span.spans_mut(f);
+        self.strength.spans_mut(f);
+        self.of.spans_mut(f);
+        self.wait.spans_mut(f);
+    }
+}
+
 /// Representation of select Statement
 ///
 /// ```
@@ -492,10 +771,13 @@ impl<'a> Spanned for Locking<'a> {
 /// assert!(s.locking.is_some());
 /// println!("{:#?}", s.locking);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Select<'a> {
     /// Span of "SELECT"
     pub select_span: Span,
+    /// Span and content of optimizer hint comments "/*+ ... */" after "SELECT",
+    /// only kept if enabled in the parse options
+    pub hints: Vec<(Span, &'a str)>,
     /// Flags specified after "SELECT"
     pub flags: Vec<SelectFlag>,
     /// List of values to select
@@ -513,16 +795,19 @@ pub struct Select<'a> {
     /// Span of window if specified
     pub window_span: Option<Span>,
     /// Span of "ORDER BY" and list of order expression and directions, if specified
-    pub order_by: Option<(Span, Vec<(Expression<'a>, OrderFlag)>)>,
+    pub order_by: Option<(Span, Vec<OrderKey<'a>>)>,
     /// Span of "LIMIT", offset and count expressions if specified
     pub limit: Option<(Span, Option<Expression<'a>>, Expression<'a>)>,
     /// Row locking clause
     pub locking: Option<Locking<'a>>,
+    /// Span of the last token of the select, see [crate::Statement::outer_span]
+    pub end_span: Span,
 }
 
 impl<'a> Spanned for Select<'a> {
     fn span(&self) -> Span {
         self.select_span
+            .join_span(&self.hints)
             .join_span(&self.flags)
             .join_span(&self.select_exprs)
             .join_span(&self.from_span)
@@ -533,11 +818,82 @@ impl<'a> Spanned for Select<'a> {
             .join_span(&self.window_span)
             .join_span(&self.order_by)
             .join_span(&self.limit)
+            .join_span(&self.locking)
+            .join_span(&self.end_span)
+    }
+}
+
+impl<'a> SpansMut for Select<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.select_span.spans_mut(f);
+        for (span, _) in &mut self.hints {
+            span.spans_mut(f);
+        }
+        self.flags.spans_mut(f);
+        self.select_exprs.spans_mut(f);
+        self.from_span.spans_mut(f);
+        self.table_references.spans_mut(f);
+        self.where_.spans_mut(f);
+        self.group_by.spans_mut(f);
+        self.having.spans_mut(f);
+        self.window_span.spans_mut(f);
+        self.order_by.spans_mut(f);
+        self.limit.spans_mut(f);
+        self.locking.spans_mut(f);
+        self.end_span.spans_mut(f);
     }
 }
 
+/// Report an issue if a LIMIT count or offset is a negative or non-integer literal
+fn validate_limit_value(parser: &mut Parser<'_, '_>, value: &Expression<'_>) {
+    match value {
+        Expression::Unary {
+            op: UnaryOperator::Minus,
+            ..
+        } => parser
+            .issues
+            .push(Issue::err("LIMIT values cannot be negative", value)),
+        Expression::Float(_)
+        | Expression::String(_)
+        | Expression::Bool(_, _)
+        | Expression::Null(_) => parser
+            .issues
+            .push(Issue::err("LIMIT values must be integers", value)),
+        _ => (),
+    }
+}
+
+/// Parse "LIMIT [offset,] count" or "LIMIT count OFFSET offset" if present
+pub(crate) fn parse_limit<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<Option<(Span, Option<Expression<'a>>, Expression<'a>)>, ParseError> {
+    let span = match parser.skip_keyword(Keyword::LIMIT) {
+        Some(span) => span,
+        None => return Ok(None),
+    };
+    let n = parse_expression(parser, true)?;
+    let (offset, count) = match parser.token {
+        Token::Comma => {
+            parser.consume();
+            (Some(n), parse_expression(parser, true)?)
+        }
+        Token::Ident(_, Keyword::OFFSET) => {
+            parser.consume();
+            (Some(parse_expression(parser, true)?), n)
+        }
+        _ => (None, n),
+    };
+    if let Some(offset) = &offset {
+        validate_limit_value(parser, offset);
+    }
+    validate_limit_value(parser, &count);
+    Ok(Some((span, offset, count)))
+}
+
 pub(crate) fn parse_select<'a>(parser: &mut Parser<'a, '_>) -> Result<Select<'a>, ParseError> {
     let select_span = parser.consume_keyword(Keyword::SELECT)?;
+    let mut hints = parser.lexer.take_hints();
+    hints.retain(|(span, _)| span.start > select_span.start);
     let mut flags = Vec::new();
     let mut select_exprs = Vec::new();
 
@@ -591,6 +947,7 @@ pub(crate) fn parse_select<'a>(parser: &mut Parser<'a, '_>) -> Result<Select<'a>
         None => {
             return Ok(Select {
                 select_span,
+                hints,
                 flags,
                 select_exprs,
                 from_span: None,
@@ -602,6 +959,7 @@ pub(crate) fn parse_select<'a>(parser: &mut Parser<'a, '_>) -> Result<Select<'a>
                 order_by: None,
                 limit: None,
                 locking: None,
+                end_span: parser.last_span.clone(),
             })
         }
     };
@@ -647,42 +1005,12 @@ pub(crate) fn parse_select<'a>(parser: &mut Parser<'a, '_>) -> Result<Select<'a>
         //TODO window_name AS (window_spec) [, window_name AS (window_spec)] ...]
     }
 
-    let order_by = if let Some(span) = parser.skip_keyword(Keyword::ORDER) {
-        let span = parser.consume_keyword(Keyword::BY)?.join_span(&span);
-        let mut order = Vec::new();
-        loop {
-            let e = parse_expression(parser, false)?;
-            let f = match &parser.token {
-                Token::Ident(_, Keyword::ASC) => OrderFlag::Asc(parser.consume()),
-                Token::Ident(_, Keyword::DESC) => OrderFlag::Desc(parser.consume()),
-                _ => OrderFlag::None,
-            };
-            order.push((e, f));
-            if parser.skip_token(Token::Comma).is_none() {
-                break;
-            }
-        }
-        Some((span, order))
-    } else {
-        None
+    let order_by = match &parser.token {
+        Token::Ident(_, Keyword::ORDER) => Some(parse_order_by(parser)?),
+        _ => None,
     };
 
-    let limit = if let Some(span) = parser.skip_keyword(Keyword::LIMIT) {
-        let n = parse_expression(parser, true)?;
-        match parser.token {
-            Token::Comma => {
-                parser.consume();
-                Some((span, Some(n), parse_expression(parser, true)?))
-            }
-            Token::Ident(_, Keyword::OFFSET) => {
-                parser.consume();
-                Some((span, Some(parse_expression(parser, true)?), n))
-            }
-            _ => Some((span, None, n)),
-        }
-    } else {
-        None
-    };
+    let limit = parse_limit(parser)?;
 
     let locking = if let Some(for_span) = parser.skip_keyword(Keyword::FOR) {
         let strength = match &parser.token {
@@ -758,6 +1086,7 @@ pub(crate) fn parse_select<'a>(parser: &mut Parser<'a, '_>) -> Result<Select<'a>
 
     Ok(Select {
         select_span,
+        hints,
         flags,
         select_exprs,
         from_span,
@@ -769,5 +1098,6 @@ pub(crate) fn parse_select<'a>(parser: &mut Parser<'a, '_>) -> Result<Select<'a>
         order_by,
         limit,
         locking,
+        end_span: parser.last_span.clone(),
     })
 }
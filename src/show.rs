@@ -0,0 +1,308 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    keywords::Keyword,
+    lexer::Token,
+    parser::{ParseError, Parser},
+    qualified_name::parse_qualified_name,
+    statement::Statement,
+    Identifier, QualifiedName, SString, Span, Spanned, SpansMut,
+};
+
+/// Represent a show tables statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, ShowTables, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "SHOW TABLES FROM `db` LIKE 'user%';";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let show_tables: ShowTables = match stmts.pop() {
+///     Some(Statement::ShowTables(s)) => s,
+///     _ => panic!("We should get a show tables statement")
+/// };
+///
+/// assert!(show_tables.db.unwrap().1.as_str() == "db");
+/// assert!(show_tables.like.unwrap().1.as_str() == "user%");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowTables<'a> {
+    /// Span of "SHOW"
+    pub show_span: Span,
+    /// Span of "FULL" if specified
+    pub full_span: Option<Span>,
+    /// Span of "TABLES"
+    pub tables_span: Span,
+    /// Span of "FROM" or "IN" and the database if specified
+    pub db: Option<(Span, Identifier<'a>)>,
+    /// Span of "LIKE" and the pattern if specified
+    pub like: Option<(Span, SString<'a>)>,
+}
+
+impl<'a> Spanned for ShowTables<'a> {
+    fn span(&self) -> Span {
+        self.show_span
+            .join_span(&self.full_span)
+            .join_span(&self.tables_span)
+            .join_span(&self.db)
+            .join_span(&self.like)
+    }
+}
+
+impl<'a> SpansMut for ShowTables<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.show_span.spans_mut(f);
+        self.full_span.spans_mut(f);
+        self.tables_span.spans_mut(f);
+        self.db.spans_mut(f);
+        self.like.spans_mut(f);
+    }
+}
+
+/// Represent a show databases statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, ShowDatabases, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "SHOW DATABASES;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let show_databases: ShowDatabases = match stmts.pop() {
+///     Some(Statement::ShowDatabases(s)) => s,
+///     _ => panic!("We should get a show databases statement")
+/// };
+///
+/// assert!(show_databases.like.is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowDatabases<'a> {
+    /// Span of "SHOW"
+    pub show_span: Span,
+    /// Span of "DATABASES"
+    pub databases_span: Span,
+    /// Span of "LIKE" and the pattern if specified
+    pub like: Option<(Span, SString<'a>)>,
+}
+
+impl<'a> Spanned for ShowDatabases<'a> {
+    fn span(&self) -> Span {
+        self.show_span
+            .join_span(&self.databases_span)
+            .join_span(&self.like)
+    }
+}
+
+impl<'a> SpansMut for ShowDatabases<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.show_span.spans_mut(f);
+        self.databases_span.spans_mut(f);
+        self.like.spans_mut(f);
+    }
+}
+
+/// Represent a show columns statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, ShowColumns, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "SHOW FULL COLUMNS FROM `t1` FROM `db`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let show_columns: ShowColumns = match stmts.pop() {
+///     Some(Statement::ShowColumns(s)) => s,
+///     _ => panic!("We should get a show columns statement")
+/// };
+///
+/// assert!(show_columns.full_span.is_some());
+/// assert!(show_columns.table.identifier.as_str() == "t1");
+/// assert!(show_columns.db.unwrap().1.as_str() == "db");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowColumns<'a> {
+    /// Span of "SHOW"
+    pub show_span: Span,
+    /// Span of "FULL" if specified
+    pub full_span: Option<Span>,
+    /// Span of "COLUMNS" or "FIELDS"
+    pub columns_span: Span,
+    /// Span of "FROM" or "IN"
+    pub from_span: Span,
+    /// Table to show columns of
+    pub table: QualifiedName<'a>,
+    /// Span of "FROM" or "IN" and the database if specified
+    pub db: Option<(Span, Identifier<'a>)>,
+    /// Span of "LIKE" and the pattern if specified
+    pub like: Option<(Span, SString<'a>)>,
+}
+
+impl<'a> Spanned for ShowColumns<'a> {
+    fn span(&self) -> Span {
+        self.show_span
+            .join_span(&self.full_span)
+            .join_span(&self.columns_span)
+            .join_span(&self.from_span)
+            .join_span(&self.table)
+            .join_span(&self.db)
+            .join_span(&self.like)
+    }
+}
+
+impl<'a> SpansMut for ShowColumns<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.show_span.spans_mut(f);
+        self.full_span.spans_mut(f);
+        self.columns_span.spans_mut(f);
+        self.from_span.spans_mut(f);
+        self.table.spans_mut(f);
+        self.db.spans_mut(f);
+        self.like.spans_mut(f);
+    }
+}
+
+/// Represent a show create table statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, ShowCreateTable, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "SHOW CREATE TABLE `db`.`t1`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let show_create_table: ShowCreateTable = match stmts.pop() {
+///     Some(Statement::ShowCreateTable(s)) => s,
+///     _ => panic!("We should get a show create table statement")
+/// };
+///
+/// assert!(show_create_table.table.identifier.as_str() == "t1");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowCreateTable<'a> {
+    /// Span of "SHOW"
+    pub show_span: Span,
+    /// Span of "CREATE TABLE"
+    pub create_table_span: Span,
+    /// Table to show the definition of
+    pub table: QualifiedName<'a>,
+}
+
+impl<'a> Spanned for ShowCreateTable<'a> {
+    fn span(&self) -> Span {
+        self.show_span
+            .join_span(&self.create_table_span)
+            .join_span(&self.table)
+    }
+}
+
+impl<'a> SpansMut for ShowCreateTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.show_span.spans_mut(f);
+        self.create_table_span.spans_mut(f);
+        self.table.spans_mut(f);
+    }
+}
+
+fn parse_from_or_in(parser: &mut Parser<'_, '_>) -> Option<Span> {
+    match &parser.token {
+        Token::Ident(_, Keyword::FROM | Keyword::IN) => Some(parser.consume()),
+        _ => None,
+    }
+}
+
+fn parse_like<'a>(parser: &mut Parser<'a, '_>) -> Result<Option<(Span, SString<'a>)>, ParseError> {
+    if let Some(like_span) = parser.skip_keyword(Keyword::LIKE) {
+        Ok(Some((like_span, parser.consume_string()?)))
+    } else {
+        Ok(None)
+    }
+}
+
+pub(crate) fn parse_show<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
+    let show_span = parser.consume_keyword(Keyword::SHOW)?;
+    let full_span = parser.skip_keyword(Keyword::FULL);
+    match &parser.token {
+        Token::Ident(_, Keyword::TABLES) => {
+            let tables_span = parser.consume_keyword(Keyword::TABLES)?;
+            let db = if let Some(span) = parse_from_or_in(parser) {
+                Some((span, parser.consume_plain_identifier()?))
+            } else {
+                None
+            };
+            let like = parse_like(parser)?;
+            Ok(Statement::ShowTables(ShowTables {
+                show_span,
+                full_span,
+                tables_span,
+                db,
+                like,
+            }))
+        }
+        Token::Ident(_, Keyword::COLUMNS | Keyword::FIELDS) => {
+            let columns_span = parser.consume();
+            let from_span = match parse_from_or_in(parser) {
+                Some(v) => v,
+                None => parser.expected_failure("'FROM' | 'IN'")?,
+            };
+            let table = parse_qualified_name(parser)?;
+            let db = if let Some(span) = parse_from_or_in(parser) {
+                Some((span, parser.consume_plain_identifier()?))
+            } else {
+                None
+            };
+            let like = parse_like(parser)?;
+            Ok(Statement::ShowColumns(ShowColumns {
+                show_span,
+                full_span,
+                columns_span,
+                from_span,
+                table,
+                db,
+                like,
+            }))
+        }
+        Token::Ident(_, Keyword::DATABASES) if full_span.is_none() => {
+            let databases_span = parser.consume_keyword(Keyword::DATABASES)?;
+            let like = parse_like(parser)?;
+            Ok(Statement::ShowDatabases(ShowDatabases {
+                show_span,
+                databases_span,
+                like,
+            }))
+        }
+        Token::Ident(_, Keyword::CREATE)
+            if full_span.is_none()
+                && matches!(parser.peek_n(1), Token::Ident(_, Keyword::TABLE)) =>
+        {
+            let create_table_span = parser.consume_keywords(&[Keyword::CREATE, Keyword::TABLE])?;
+            let table = parse_qualified_name(parser)?;
+            Ok(Statement::ShowCreateTable(ShowCreateTable {
+                show_span,
+                create_table_span,
+                table,
+            }))
+        }
+        _ if full_span.is_some() => parser.expected_failure("'TABLES' | 'COLUMNS' | 'FIELDS'"),
+        _ => parser
+            .expected_failure("'TABLES' | 'DATABASES' | 'COLUMNS' | 'FIELDS' | 'CREATE TABLE'"),
+    }
+}
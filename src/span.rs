@@ -11,12 +11,42 @@
 // limitations under the License.
 
 use alloc::{boxed::Box, vec::Vec};
+use core::{
+    hash::{Hash, Hasher},
+    mem::{discriminant, Discriminant},
+};
+
+use crate::{DataTypeProperty, Expression, Identifier, SQLDialect, SString, Type};
 
 /// Byte span of ast fragment
+///
+/// A span is a range of byte offsets into the parsed source with
+/// `start <= end`. Use [NewSpan::new] to construct spans manually
 pub type Span = core::ops::Range<usize>;
 
-/// Compute an optional byte span of an ast fragment
+/// Checked construction of a [Span]
+///
+/// ```
+/// use sql_parse::{NewSpan, Span, Spanned};
+/// let span = Span::new(3, 7);
+/// assert_eq!(span, 3..7);
+/// assert_eq!(span.join_span(&Span::new(1, 4)), 1..7);
+/// ```
+pub trait NewSpan {
+    /// Construct a span covering the bytes from start to end
+    ///
+    /// Panics in debug builds if end is before start
+    fn new(start: usize, end: usize) -> Self;
+}
 
+impl NewSpan for Span {
+    fn new(start: usize, end: usize) -> Self {
+        debug_assert!(start <= end, "Reversed span {}..{}", start, end);
+        start..end
+    }
+}
+
+/// Compute an optional byte span of an ast fragment
 pub trait OptSpanned {
     /// Compute an optional byte span of an ast fragment
     fn opt_span(&self) -> Option<Span>;
@@ -33,6 +63,9 @@ pub trait OptSpanned {
 }
 
 /// Compute byte span of an ast fragment
+///
+/// Spans index into the parsed source, so slicing the source with a span
+/// recovers the fragment as written, including the casing of keywords
 pub trait Spanned {
     /// Compute byte span of an ast fragment
     fn span(&self) -> Span;
@@ -40,7 +73,9 @@ pub trait Spanned {
     /// Compute the minimal span containing both self and other
     fn join_span(&self, other: &impl OptSpanned) -> Span {
         let l = self.span();
+        debug_assert!(l.start <= l.end, "Reversed span {:?}", l);
         if let Some(r) = other.opt_span() {
+            debug_assert!(r.start <= r.end, "Reversed span {:?}", r);
             usize::min(l.start, r.start)..usize::max(l.end, r.end)
         } else {
             l
@@ -123,12 +158,18 @@ impl<S: Spanned> Spanned for (bool, S) {
     }
 }
 
-impl<'a, S: Spanned> Spanned for (&'a str, S) {
+impl<S: Spanned> Spanned for (&str, S) {
     fn span(&self) -> Span {
         self.1.span()
     }
 }
 
+impl Spanned for (Span, &str) {
+    fn span(&self) -> Span {
+        self.0.span()
+    }
+}
+
 impl<'a, S: Spanned> Spanned for (alloc::borrow::Cow<'a, str>, S) {
     fn span(&self) -> Span {
         self.1.span()
@@ -146,3 +187,266 @@ impl<T1: Spanned, T2: OptSpanned, T3: OptSpanned> Spanned for (T1, T2, T3) {
         self.0.join_span(&self.1).join_span(&self.2)
     }
 }
+
+/// Visit every byte span of an ast fragment mutably
+///
+/// Used to remap the spans of a fragment, see [crate::Statement::shift_spans]
+pub trait SpansMut {
+    /// Call f with every span of the ast fragment
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span));
+}
+
+impl SpansMut for Span {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        f(self)
+    }
+}
+
+macro_rules! impl_spans_mut_noop {
+    ($($t:ty),*) => {
+        $(impl SpansMut for $t {
+            fn spans_mut(&mut self, _: &mut dyn FnMut(&mut Span)) {}
+        })*
+    };
+}
+
+impl_spans_mut_noop!(u32, u64, usize, f64);
+
+impl<T: SpansMut + ?Sized> SpansMut for Box<T> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.as_mut().spans_mut(f)
+    }
+}
+
+impl<T: SpansMut> SpansMut for Option<T> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        if let Some(v) = self {
+            v.spans_mut(f)
+        }
+    }
+}
+
+impl<T: SpansMut> SpansMut for Vec<T> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        for v in self {
+            v.spans_mut(f)
+        }
+    }
+}
+
+impl<T1: SpansMut, T2: SpansMut> SpansMut for (T1, T2) {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.0.spans_mut(f);
+        self.1.spans_mut(f);
+    }
+}
+
+impl<T1: SpansMut, T2: SpansMut, T3: SpansMut> SpansMut for (T1, T2, T3) {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.0.spans_mut(f);
+        self.1.spans_mut(f);
+        self.2.spans_mut(f);
+    }
+}
+
+/// Clone an ast fragment with every span replaced by `0..0`
+///
+/// The derived equality of the clones then compares the fragments while
+/// ignoring where in the source they were parsed from
+pub(crate) fn without_spans<T: Clone + SpansMut>(v: &T) -> T {
+    let mut v = v.clone();
+    v.spans_mut(&mut |s| *s = 0..0);
+    v
+}
+
+/// Wrapper comparing and hashing an ast fragment while ignoring spans
+///
+/// [crate::Identifier]s are compared by their case folded value alone, so a
+/// quoted and an unquoted identifier with the same name are equal.
+/// [crate::DataType]s are compared field by field, including their properties,
+/// with expressions in properties compared by their rendered sql
+/// ```
+/// # use std::collections::HashSet;
+/// # use sql_parse::{SQLDialect, ParseOptions, parse_statement, BySemantics, CreateDefinition, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// let sql = "CREATE TABLE `t1` (`a` INT NOT NULL, `b` VARCHAR(10), `c` int  NOT NULL,
+///     `d` INT(11), `e` INT DEFAULT 1, `f` INT DEFAULT 2, `g` INT DEFAULT  1)";
+/// let create = match parse_statement(sql, &mut issues, &options) {
+///     Some(Statement::CreateTable(c)) => c,
+///     _ => panic!("We should get a create table statement")
+/// };
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// let types: HashSet<_> = create
+///     .create_definitions
+///     .iter()
+///     .filter_map(|d| match d {
+///         CreateDefinition::ColumnDefinition { data_type, .. } => Some(BySemantics(data_type.clone())),
+///         _ => None,
+///     })
+///     .collect();
+/// assert_eq!(types.len(), 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BySemantics<T>(pub T);
+
+impl<'a> PartialEq for BySemantics<crate::Identifier<'a>> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.value == other.0.value
+    }
+}
+
+impl<'a> Eq for BySemantics<crate::Identifier<'a>> {}
+
+impl<'a> Hash for BySemantics<crate::Identifier<'a>> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.value.hash(state)
+    }
+}
+
+/// The parts of a type compared by [BySemantics]: the variant, its widths,
+/// whether a time zone is included and the values of enums and sets
+type TypeParts<'b, 'a> = (
+    Discriminant<Type<'a>>,
+    [Option<usize>; 2],
+    bool,
+    &'b [SString<'a>],
+);
+
+fn type_parts<'b, 'a>(type_: &'b Type<'a>) -> TypeParts<'b, 'a> {
+    let (widths, with_time_zone, values) = match type_ {
+        Type::TinyInt(v)
+        | Type::SmallInt(v)
+        | Type::Integer(v)
+        | Type::Int(v)
+        | Type::BigInt(v)
+        | Type::Char(v)
+        | Type::VarChar(v)
+        | Type::TinyText(v)
+        | Type::MediumText(v)
+        | Type::Text(v)
+        | Type::LongText(v)
+        | Type::DateTime(v)
+        | Type::Time(v)
+        | Type::TinyBlob(v)
+        | Type::MediumBlob(v)
+        | Type::Blob(v)
+        | Type::LongBlob(v)
+        | Type::Binary(v) => ([v.as_ref().map(|v| v.0), None], false, &[][..]),
+        Type::Float(v) | Type::Double(v) => (
+            [v.as_ref().map(|v| v.0), v.as_ref().map(|v| v.1)],
+            false,
+            &[][..],
+        ),
+        Type::Numeric(v0, v1, _) => ([Some(*v0), Some(*v1)], false, &[][..]),
+        Type::VarBinary((v, _)) | Type::Bit(v, _) => ([Some(*v), None], false, &[][..]),
+        Type::Timestamp(v) => (
+            [v.width.as_ref().map(|v| v.0), None],
+            v.with_time_zone.is_some(),
+            &[][..],
+        ),
+        Type::Enum(v) | Type::Set(v) => ([None, None], false, &v[..]),
+        Type::Boolean
+        | Type::Float8
+        | Type::Timestamptz
+        | Type::Date
+        | Type::Named(_)
+        | Type::Json
+        | Type::Bytea
+        | Type::Inet4
+        | Type::Inet6 => ([None, None], false, &[][..]),
+    };
+    (discriminant(type_), widths, with_time_zone, values)
+}
+
+fn sstrings_eq(a: &[SString<'_>], b: &[SString<'_>]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.value == b.value)
+}
+
+fn identifiers_eq(a: &[Identifier<'_>], b: &[Identifier<'_>]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.value == b.value)
+}
+
+/// Compare expressions by their rendered sql, expressions that cannot be
+/// rendered are compared on span free copies
+fn expressions_eq<'a>(a: &Expression<'a>, b: &Expression<'a>) -> bool {
+    let dialect = SQLDialect::MariaDB;
+    match (a.to_sql(&dialect), b.to_sql(&dialect)) {
+        (Some(a), Some(b)) => a == b,
+        (None, None) => without_spans(a) == without_spans(b),
+        _ => false,
+    }
+}
+
+fn property_eq<'a>(a: &DataTypeProperty<'a>, b: &DataTypeProperty<'a>) -> bool {
+    match (a, b) {
+        (DataTypeProperty::Default(a), DataTypeProperty::Default(b))
+        | (DataTypeProperty::As((_, a)), DataTypeProperty::As((_, b)))
+        | (DataTypeProperty::Check((_, a)), DataTypeProperty::Check((_, b))) => {
+            expressions_eq(a, b)
+        }
+        (DataTypeProperty::Comment(a), DataTypeProperty::Comment(b)) => a.value == b.value,
+        (DataTypeProperty::Charset(a), DataTypeProperty::Charset(b))
+        | (DataTypeProperty::Collate(a), DataTypeProperty::Collate(b)) => a.value == b.value,
+        (
+            DataTypeProperty::References {
+                table: a_table,
+                cols: a_cols,
+                ons: a_ons,
+                ..
+            },
+            DataTypeProperty::References {
+                table: b_table,
+                cols: b_cols,
+                ons: b_ons,
+                ..
+            },
+        ) => {
+            a_table.value == b_table.value
+                && identifiers_eq(a_cols, b_cols)
+                && a_ons.len() == b_ons.len()
+                && a_ons.iter().zip(b_ons).all(|(a, b)| {
+                    discriminant(&a.type_) == discriminant(&b.type_)
+                        && discriminant(&a.action) == discriminant(&b.action)
+                })
+        }
+        // The remaining properties only hold spans
+        (a, b) => discriminant(a) == discriminant(b),
+    }
+}
+
+impl<'a> PartialEq for BySemantics<crate::DataType<'a>> {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (type_parts(&self.0.type_), type_parts(&other.0.type_));
+        a.0 == b.0
+            && a.1 == b.1
+            && a.2 == b.2
+            && sstrings_eq(a.3, b.3)
+            && self.0.properties.len() == other.0.properties.len()
+            && self
+                .0
+                .properties
+                .iter()
+                .zip(&other.0.properties)
+                .all(|(a, b)| property_eq(a, b))
+    }
+}
+
+impl<'a> Eq for BySemantics<crate::DataType<'a>> {}
+
+impl<'a> Hash for BySemantics<crate::DataType<'a>> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let (variant, widths, with_time_zone, values) = type_parts(&self.0.type_);
+        variant.hash(state);
+        widths.hash(state);
+        with_time_zone.hash(state);
+        for value in values {
+            value.value.hash(state);
+        }
+        // Expressions are compared by rendering them, so only the kinds of
+        // the properties are hashed
+        for property in &self.0.properties {
+            discriminant(property).hash(state);
+        }
+    }
+}
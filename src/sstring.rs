@@ -10,12 +10,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, string::String};
 
-use crate::{Span, Spanned};
+use crate::{Span, Spanned, SpansMut};
 
 /// A string with attached span
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SString<'a> {
     /// The underlying string
     pub value: Cow<'a, str>,
@@ -33,6 +33,59 @@ impl<'a> SString<'a> {
     pub fn as_str(&self) -> &str {
         self.value.as_ref()
     }
+
+    /// Return the value with MySQL backslash escapes such as `\n`, `\t`, `\\`
+    /// and `\'` resolved. Doubled quotes are already collapsed in the value,
+    /// and the source text can still be found through the span
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, ParseOptions, parse_statement, Statement, TableOption};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let sql = r"CREATE TABLE `t1` (`id` int) COMMENT='it''s a\ttab\\n'";
+    /// let create = match parse_statement(sql, &mut issues, &options) {
+    ///     Some(Statement::CreateTable(c)) => c,
+    ///     _ => panic!("We should get a create table statement")
+    /// };
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let comment = match &create.options[0] {
+    ///     TableOption::Comment { value, .. } => value,
+    ///     _ => panic!("We should get a comment")
+    /// };
+    /// assert_eq!(comment.as_str(), r"it's a\ttab\\n");
+    /// assert_eq!(comment.decoded(), "it's a\ttab\\n");
+    /// assert_eq!(&sql[comment.span.clone()], r"'it''s a\ttab\\n'");
+    /// ```
+    pub fn decoded(&self) -> Cow<'_, str> {
+        let value = self.as_str();
+        if !value.contains('\\') {
+            return Cow::Borrowed(value);
+        }
+        let mut r = String::with_capacity(value.len());
+        let mut chars = value.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                r.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('0') => r.push('\0'),
+                Some('b') => r.push('\x08'),
+                Some('n') => r.push('\n'),
+                Some('r') => r.push('\r'),
+                Some('t') => r.push('\t'),
+                Some('Z') => r.push('\x1a'),
+                // "\%" and "\_" keep the backslash so they still match literally in LIKE
+                Some(c @ ('%' | '_')) => {
+                    r.push('\\');
+                    r.push(c);
+                }
+                Some(c) => r.push(c),
+                None => r.push('\\'),
+            }
+        }
+        Cow::Owned(r)
+    }
 }
 
 impl<'a> core::ops::Deref for SString<'a> {
@@ -48,3 +101,9 @@ impl<'a> Spanned for SString<'a> {
         self.span.span()
     }
 }
+
+impl<'a> SpansMut for SString<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.span.spans_mut(f);
+    }
+}
@@ -10,33 +10,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 
 use crate::{
     alter::{parse_alter, AlterTable},
     create::{
-        parse_create, CreateFunction, CreateIndex, CreateTable, CreateTrigger, CreateTypeEnum,
-        CreateView,
+        parse_create, CreateDatabase, CreateFunction, CreateIndex, CreateTable, CreateTablespace,
+        CreateTrigger, CreateTypeEnum, CreateView,
     },
     delete::{parse_delete, Delete},
     drop::{
         parse_drop, DropDatabase, DropEvent, DropFunction, DropIndex, DropProcedure, DropServer,
         DropTable, DropTrigger, DropView,
     },
-    expression::{parse_expression, Expression},
+    explain::{parse_explain, Describe, Explain},
+    expression::{parse_expression, Expression, JsonOnResponse},
+    grant::{parse_grant, parse_revoke, Grant, GrantLevel, GrantObjectType, Revoke},
     insert_replace::{parse_insert_replace, InsertReplace},
     keywords::Keyword,
     lexer::Token,
+    load_data::{parse_load_data, LoadData},
+    lock::{parse_lock_tables, parse_unlock_tables, LockTables},
     parser::{ParseError, Parser},
+    qualified_name::parse_qualified_name,
     rename::parse_rename_table,
-    select::{parse_select, OrderFlag, Select},
-    span::OptSpanned,
+    rename_identifier::statement_identifiers,
+    select::{
+        parse_limit, parse_order_by, parse_select, JoinSpecification, OrderKey, Select,
+        TableReference,
+    },
+    show::{parse_show, ShowColumns, ShowCreateTable, ShowDatabases, ShowTables},
+    span::{without_spans, OptSpanned},
+    table_maintenance::{parse_table_maintenance, TableMaintenance},
     truncate::{parse_truncate_table, TruncateTable},
     update::{parse_update, Update},
-    Identifier, RenameTable, Span, Spanned,
+    Identifier, Issue, QualifiedName, RenameTable, SString, Span, Spanned, SpansMut,
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Set<'a> {
     pub set_span: Span,
     pub values: Vec<(Identifier<'a>, Expression<'a>)>,
@@ -48,6 +59,13 @@ impl<'a> Spanned for Set<'a> {
     }
 }
 
+impl<'a> SpansMut for Set<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.set_span.spans_mut(f);
+        self.values.spans_mut(f);
+    }
+}
+
 fn parse_set<'a>(parser: &mut Parser<'a, '_>) -> Result<Set<'a>, ParseError> {
     let set_span = parser.consume_keyword(Keyword::SET)?;
     let mut values = Vec::new();
@@ -63,6 +81,119 @@ fn parse_set<'a>(parser: &mut Parser<'a, '_>) -> Result<Set<'a>, ParseError> {
     Ok(Set { set_span, values })
 }
 
+/// Represent a use statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Use, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "USE `db`; SELECT 1;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let use_: Use = match stmts.into_iter().next() {
+///     Some(Statement::Use(u)) => u,
+///     _ => panic!("We should get a use statement")
+/// };
+///
+/// assert!(use_.database.as_str() == "db");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Use<'a> {
+    /// Span of "USE"
+    pub use_span: Span,
+    /// Database to use
+    pub database: Identifier<'a>,
+}
+
+impl<'a> Spanned for Use<'a> {
+    fn span(&self) -> Span {
+        self.use_span.join_span(&self.database)
+    }
+}
+
+impl<'a> SpansMut for Use<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.use_span.spans_mut(f);
+        self.database.spans_mut(f);
+    }
+}
+
+fn parse_use<'a>(parser: &mut Parser<'a, '_>) -> Result<Use<'a>, ParseError> {
+    let use_span = parser.consume_keyword(Keyword::USE)?;
+    let database = parser.consume_plain_identifier()?;
+    Ok(Use { use_span, database })
+}
+
+/// Represent a call statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Call, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "CALL `db`.`p`(1, 'a');";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let call: Call = match stmts.pop() {
+///     Some(Statement::Call(c)) => c,
+///     _ => panic!("We should get a call statement")
+/// };
+///
+/// assert!(call.name.identifier.as_str() == "p");
+/// assert!(call.args.len() == 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call<'a> {
+    /// Span of "CALL"
+    pub call_span: Span,
+    /// Name of the procedure to call
+    pub name: QualifiedName<'a>,
+    /// Arguments passed to the procedure
+    pub args: Vec<Expression<'a>>,
+}
+
+impl<'a> Spanned for Call<'a> {
+    fn span(&self) -> Span {
+        self.call_span.join_span(&self.name).join_span(&self.args)
+    }
+}
+
+impl<'a> SpansMut for Call<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.call_span.spans_mut(f);
+        self.name.spans_mut(f);
+        self.args.spans_mut(f);
+    }
+}
+
+fn parse_call<'a>(parser: &mut Parser<'a, '_>) -> Result<Call<'a>, ParseError> {
+    let call_span = parser.consume_keyword(Keyword::CALL)?;
+    let name = parse_qualified_name(parser)?;
+    let mut args = Vec::new();
+    if parser.skip_token(Token::LParen).is_some() {
+        parser.recovered("')'", &|t| t == &Token::RParen, |parser| {
+            if !matches!(parser.token, Token::RParen) {
+                loop {
+                    args.push(parse_expression(parser, false)?);
+                    if parser.skip_token(Token::Comma).is_none() {
+                        break;
+                    }
+                }
+            }
+            Ok(())
+        })?;
+        parser.consume_token(Token::RParen)?;
+    }
+    Ok(Call {
+        call_span,
+        name,
+        args,
+    })
+}
+
 fn parse_statement_list_inner<'a>(
     parser: &mut Parser<'a, '_>,
     out: &mut Vec<Statement<'a>>,
@@ -101,27 +232,294 @@ fn parse_statement_list<'a>(
 }
 
 fn parse_begin(parser: &mut Parser<'_, '_>) -> Result<Span, ParseError> {
-    parser.consume_keyword(Keyword::BEGIN)
+    let span = parser.consume_keyword(Keyword::BEGIN)?;
+    Ok(span.join_span(&parser.skip_keyword(Keyword::WORK)))
 }
 
 fn parse_end(parser: &mut Parser<'_, '_>) -> Result<Span, ParseError> {
     parser.consume_keyword(Keyword::END)
 }
 
-fn parse_start<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
-    Ok(Statement::StartTransaction(parser.consume_keywords(&[
-        Keyword::START,
-        Keyword::TRANSACTION,
-    ])?))
+fn parse_start(parser: &mut Parser<'_, '_>) -> Result<Span, ParseError> {
+    parser.consume_keywords(&[Keyword::START, Keyword::TRANSACTION])
 }
 
 fn parse_commit(parser: &mut Parser<'_, '_>) -> Result<Span, ParseError> {
-    parser.consume_keyword(Keyword::COMMIT)
+    let span = parser.consume_keyword(Keyword::COMMIT)?;
+    Ok(span.join_span(&parser.skip_keyword(Keyword::WORK)))
 }
 
-fn parse_block<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<Statement<'a>>, ParseError> {
-    parser.consume_keyword(Keyword::BEGIN)?;
-    let mut ans = Vec::new();
+/// Represent a rollback statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Rollback, Statement, Transaction};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "START TRANSACTION; SAVEPOINT `sp1`; ROLLBACK TO SAVEPOINT `sp1`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let rollback: Rollback = match stmts.pop() {
+///     Some(Statement::Transaction(Transaction::Rollback(r))) => r,
+///     _ => panic!("We should get a rollback statement")
+/// };
+///
+/// assert!(rollback.to.unwrap().1.as_str() == "sp1");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rollback<'a> {
+    /// Span of "ROLLBACK"
+    pub rollback_span: Span,
+    /// Span of "WORK" if specified
+    pub work_span: Option<Span>,
+    /// Span of "TO [SAVEPOINT]" and the savepoint to roll back to if specified
+    pub to: Option<(Span, Identifier<'a>)>,
+}
+
+impl<'a> Spanned for Rollback<'a> {
+    fn span(&self) -> Span {
+        self.rollback_span
+            .join_span(&self.work_span)
+            .join_span(&self.to)
+    }
+}
+
+impl<'a> SpansMut for Rollback<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.rollback_span.spans_mut(f);
+        self.work_span.spans_mut(f);
+        self.to.spans_mut(f);
+    }
+}
+
+fn parse_rollback<'a>(parser: &mut Parser<'a, '_>) -> Result<Rollback<'a>, ParseError> {
+    let rollback_span = parser.consume_keyword(Keyword::ROLLBACK)?;
+    let work_span = parser.skip_keyword(Keyword::WORK);
+    let to = if let Some(to_span) = parser.skip_keyword(Keyword::TO) {
+        let to_span = to_span.join_span(&parser.skip_keyword(Keyword::SAVEPOINT));
+        Some((to_span, parser.consume_plain_identifier()?))
+    } else {
+        None
+    };
+    Ok(Rollback {
+        rollback_span,
+        work_span,
+        to,
+    })
+}
+
+/// Represent a savepoint statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Savepoint, Statement, Transaction};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "SAVEPOINT `sp1`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let savepoint: Savepoint = match stmts.pop() {
+///     Some(Statement::Transaction(Transaction::Savepoint(s))) => s,
+///     _ => panic!("We should get a savepoint statement")
+/// };
+///
+/// assert!(savepoint.name.as_str() == "sp1");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Savepoint<'a> {
+    /// Span of "SAVEPOINT"
+    pub savepoint_span: Span,
+    /// Name of the savepoint
+    pub name: Identifier<'a>,
+}
+
+impl<'a> Spanned for Savepoint<'a> {
+    fn span(&self) -> Span {
+        self.savepoint_span.join_span(&self.name)
+    }
+}
+
+impl<'a> SpansMut for Savepoint<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.savepoint_span.spans_mut(f);
+        self.name.spans_mut(f);
+    }
+}
+
+fn parse_savepoint<'a>(parser: &mut Parser<'a, '_>) -> Result<Savepoint<'a>, ParseError> {
+    let savepoint_span = parser.consume_keyword(Keyword::SAVEPOINT)?;
+    let name = parser.consume_plain_identifier()?;
+    Ok(Savepoint {
+        savepoint_span,
+        name,
+    })
+}
+
+/// Represent a release savepoint statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, ReleaseSavepoint, Statement, Transaction};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "RELEASE SAVEPOINT `sp1`;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let release: ReleaseSavepoint = match stmts.pop() {
+///     Some(Statement::Transaction(Transaction::ReleaseSavepoint(r))) => r,
+///     _ => panic!("We should get a release savepoint statement")
+/// };
+///
+/// assert!(release.name.as_str() == "sp1");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReleaseSavepoint<'a> {
+    /// Span of "RELEASE SAVEPOINT"
+    pub release_savepoint_span: Span,
+    /// Name of the savepoint
+    pub name: Identifier<'a>,
+}
+
+impl<'a> Spanned for ReleaseSavepoint<'a> {
+    fn span(&self) -> Span {
+        self.release_savepoint_span.join_span(&self.name)
+    }
+}
+
+impl<'a> SpansMut for ReleaseSavepoint<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.release_savepoint_span.spans_mut(f);
+        self.name.spans_mut(f);
+    }
+}
+
+fn parse_release_savepoint<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<ReleaseSavepoint<'a>, ParseError> {
+    let release_savepoint_span =
+        parser.consume_keywords(&[Keyword::RELEASE, Keyword::SAVEPOINT])?;
+    let name = parser.consume_plain_identifier()?;
+    Ok(ReleaseSavepoint {
+        release_savepoint_span,
+        name,
+    })
+}
+
+/// Transaction control statement
+///
+/// "BEGIN" starts a transaction only at the top level. Inside routine bodies
+/// (functions, triggers and "DO" blocks) and after a label it opens
+/// a compound statement [Block] instead
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Statement, Transaction};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "BEGIN; SAVEPOINT `sp1`; ROLLBACK TO `sp1`; COMMIT;";
+/// let stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// assert!(matches!(
+///     &stmts[..],
+///     [
+///         Statement::Transaction(Transaction::Begin(_)),
+///         Statement::Transaction(Transaction::Savepoint(_)),
+///         Statement::Transaction(Transaction::Rollback(_)),
+///         Statement::Transaction(Transaction::Commit(_)),
+///     ]
+/// ));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Transaction<'a> {
+    /// Span of "BEGIN [WORK]"
+    Begin(Span),
+    /// Span of "END", committing the transaction
+    End(Span),
+    /// Span of "COMMIT [WORK]"
+    Commit(Span),
+    /// Span of "START TRANSACTION"
+    StartTransaction(Span),
+    /// "ROLLBACK [WORK] [TO [SAVEPOINT] name]"
+    Rollback(Rollback<'a>),
+    /// "SAVEPOINT name"
+    Savepoint(Savepoint<'a>),
+    /// "RELEASE SAVEPOINT name"
+    ReleaseSavepoint(ReleaseSavepoint<'a>),
+}
+
+impl<'a> Spanned for Transaction<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            Transaction::Begin(v) => v.span(),
+            Transaction::End(v) => v.span(),
+            Transaction::Commit(v) => v.span(),
+            Transaction::StartTransaction(v) => v.span(),
+            Transaction::Rollback(v) => v.span(),
+            Transaction::Savepoint(v) => v.span(),
+            Transaction::ReleaseSavepoint(v) => v.span(),
+        }
+    }
+}
+
+impl<'a> SpansMut for Transaction<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Transaction::Begin(v) => v.spans_mut(f),
+            Transaction::End(v) => v.spans_mut(f),
+            Transaction::Commit(v) => v.spans_mut(f),
+            Transaction::StartTransaction(v) => v.spans_mut(f),
+            Transaction::Rollback(v) => v.spans_mut(f),
+            Transaction::Savepoint(v) => v.spans_mut(f),
+            Transaction::ReleaseSavepoint(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Compound statement block "[label:] BEGIN ... END [label]"
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block<'a> {
+    /// Label before "BEGIN" if specified
+    pub label: Option<Identifier<'a>>,
+    /// Span of "BEGIN"
+    pub begin_span: Span,
+    /// Statements in the block
+    pub statements: Vec<Statement<'a>>,
+    /// Span of "END"
+    pub end_span: Span,
+    /// Label after "END" if specified
+    pub end_label: Option<Identifier<'a>>,
+}
+
+impl<'a> Spanned for Block<'a> {
+    fn span(&self) -> Span {
+        self.begin_span
+            .join_span(&self.label)
+            .join_span(&self.statements)
+            .join_span(&self.end_span)
+            .join_span(&self.end_label)
+    }
+}
+
+impl<'a> SpansMut for Block<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.label.spans_mut(f);
+        self.begin_span.spans_mut(f);
+        self.statements.spans_mut(f);
+        self.end_span.spans_mut(f);
+        self.end_label.spans_mut(f);
+    }
+}
+
+fn parse_block<'a>(
+    parser: &mut Parser<'a, '_>,
+    label: Option<Identifier<'a>>,
+) -> Result<Block<'a>, ParseError> {
+    let begin_span = parser.consume_keyword(Keyword::BEGIN)?;
+    let mut statements = Vec::new();
     parser.recovered(
         "'END' | 'EXCEPTION'",
         &|e| {
@@ -130,7 +528,7 @@ fn parse_block<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<Statement<'a>>, Pa
                 Token::Ident(_, Keyword::END) | Token::Ident(_, Keyword::EXCEPTION)
             )
         },
-        |parser| parse_statement_list(parser, &mut ans),
+        |parser| parse_statement_list(parser, &mut statements),
     )?;
     if let Some(_exception_span) = parser.skip_keyword(Keyword::EXCEPTION) {
         while let Some(_when_span) = parser.skip_keyword(Keyword::WHEN) {
@@ -140,12 +538,19 @@ fn parse_block<'a>(parser: &mut Parser<'a, '_>) -> Result<Vec<Statement<'a>>, Pa
             parser.consume_token(Token::SemiColon)?;
         }
     }
-    parser.consume_keyword(Keyword::END)?;
-    Ok(ans)
+    let end_span = parser.consume_keyword(Keyword::END)?;
+    let end_label = parse_end_label(parser, &label)?;
+    Ok(Block {
+        label,
+        begin_span,
+        statements,
+        end_span,
+        end_label,
+    })
 }
 
 /// Condition in if statement
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct IfCondition<'a> {
     /// Span of "ELSEIF" if specified
     pub elseif_span: Option<Span>,
@@ -167,8 +572,17 @@ impl<'a> Spanned for IfCondition<'a> {
     }
 }
 
+impl<'a> SpansMut for IfCondition<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.elseif_span.spans_mut(f);
+        self.search_condition.spans_mut(f);
+        self.then_span.spans_mut(f);
+        self.then.spans_mut(f);
+    }
+}
+
 /// If statement
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct If<'a> {
     /// Span of "IF"
     pub if_span: Span,
@@ -189,6 +603,15 @@ impl<'a> Spanned for If<'a> {
     }
 }
 
+impl<'a> SpansMut for If<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.if_span.spans_mut(f);
+        self.conditions.spans_mut(f);
+        self.else_.spans_mut(f);
+        self.endif_span.spans_mut(f);
+    }
+}
+
 fn parse_if<'a>(parser: &mut Parser<'a, '_>) -> Result<If<'a>, ParseError> {
     let if_span = parser.consume_keyword(Keyword::IF)?;
     let mut conditions = Vec::new();
@@ -236,8 +659,692 @@ fn parse_if<'a>(parser: &mut Parser<'a, '_>) -> Result<If<'a>, ParseError> {
     })
 }
 
+/// While statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, While, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "CREATE FUNCTION `f`(IN `i` INT) RETURNS INT BEGIN
+///     `l1`: WHILE `i` < 10 DO
+///         SET `i` = `i` + 1;
+///     END WHILE `l1`;
+/// END";
+/// let stmt = parse_statement(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let while_: While = match stmt {
+///     Some(Statement::CreateFunction(f)) => match f.return_.map(|r| *r) {
+///         Some(Statement::Block(mut b)) => match b.statements.pop() {
+///             Some(Statement::While(w)) => w,
+///             _ => panic!("We should get a while statement")
+///         },
+///         _ => panic!("We should get a block")
+///     },
+///     _ => panic!("We should get a create function statement")
+/// };
+///
+/// assert!(while_.label.unwrap().as_str() == "l1");
+/// assert!(while_.body.len() == 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct While<'a> {
+    /// Label before the loop if specified
+    pub label: Option<Identifier<'a>>,
+    /// Span of "WHILE"
+    pub while_span: Span,
+    /// Condition checked before each iteration
+    pub condition: Expression<'a>,
+    /// Span of "DO"
+    pub do_span: Span,
+    /// Statements executed while `condition` is true
+    pub body: Vec<Statement<'a>>,
+    /// Span of "END WHILE"
+    pub end_while_span: Span,
+    /// Label after "END WHILE" if specified
+    pub end_label: Option<Identifier<'a>>,
+}
+
+impl<'a> Spanned for While<'a> {
+    fn span(&self) -> Span {
+        self.while_span
+            .join_span(&self.label)
+            .join_span(&self.condition)
+            .join_span(&self.do_span)
+            .join_span(&self.body)
+            .join_span(&self.end_while_span)
+            .join_span(&self.end_label)
+    }
+}
+
+impl<'a> SpansMut for While<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.label.spans_mut(f);
+        self.while_span.spans_mut(f);
+        self.condition.spans_mut(f);
+        self.do_span.spans_mut(f);
+        self.body.spans_mut(f);
+        self.end_while_span.spans_mut(f);
+        self.end_label.spans_mut(f);
+    }
+}
+
+/// Repeat statement
+#[derive(Clone, Debug, PartialEq)]
+pub struct Repeat<'a> {
+    /// Label before the loop if specified
+    pub label: Option<Identifier<'a>>,
+    /// Span of "REPEAT"
+    pub repeat_span: Span,
+    /// Statements executed until `condition` is true
+    pub body: Vec<Statement<'a>>,
+    /// Span of "UNTIL"
+    pub until_span: Span,
+    /// Condition checked after each iteration
+    pub condition: Expression<'a>,
+    /// Span of "END REPEAT"
+    pub end_repeat_span: Span,
+    /// Label after "END REPEAT" if specified
+    pub end_label: Option<Identifier<'a>>,
+}
+
+impl<'a> Spanned for Repeat<'a> {
+    fn span(&self) -> Span {
+        self.repeat_span
+            .join_span(&self.label)
+            .join_span(&self.body)
+            .join_span(&self.until_span)
+            .join_span(&self.condition)
+            .join_span(&self.end_repeat_span)
+            .join_span(&self.end_label)
+    }
+}
+
+impl<'a> SpansMut for Repeat<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.label.spans_mut(f);
+        self.repeat_span.spans_mut(f);
+        self.body.spans_mut(f);
+        self.until_span.spans_mut(f);
+        self.condition.spans_mut(f);
+        self.end_repeat_span.spans_mut(f);
+        self.end_label.spans_mut(f);
+    }
+}
+
+/// Loop statement
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loop<'a> {
+    /// Label before the loop if specified
+    pub label: Option<Identifier<'a>>,
+    /// Span of "LOOP"
+    pub loop_span: Span,
+    /// Statements executed until the loop is left
+    pub body: Vec<Statement<'a>>,
+    /// Span of "END LOOP"
+    pub end_loop_span: Span,
+    /// Label after "END LOOP" if specified
+    pub end_label: Option<Identifier<'a>>,
+}
+
+impl<'a> Spanned for Loop<'a> {
+    fn span(&self) -> Span {
+        self.loop_span
+            .join_span(&self.label)
+            .join_span(&self.body)
+            .join_span(&self.end_loop_span)
+            .join_span(&self.end_label)
+    }
+}
+
+impl<'a> SpansMut for Loop<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.label.spans_mut(f);
+        self.loop_span.spans_mut(f);
+        self.body.spans_mut(f);
+        self.end_loop_span.spans_mut(f);
+        self.end_label.spans_mut(f);
+    }
+}
+
+/// Parse the optional label following the end of a labeled statement
+fn parse_end_label<'a>(
+    parser: &mut Parser<'a, '_>,
+    label: &Option<Identifier<'a>>,
+) -> Result<Option<Identifier<'a>>, ParseError> {
+    let end_label = match &parser.token {
+        Token::Ident(_, k) if !k.reserved() => parser.consume_plain_identifier()?,
+        _ => return Ok(None),
+    };
+    match label {
+        Some(label) if !label.value.eq_ignore_ascii_case(&end_label.value) => {
+            parser.issues.push(
+                Issue::err("End label does not match begin label", &end_label)
+                    .frag("Begin label", label),
+            );
+        }
+        Some(_) => (),
+        None => {
+            parser
+                .issues
+                .push(Issue::err("End label without begin label", &end_label));
+        }
+    }
+    Ok(Some(end_label))
+}
+
+fn parse_while<'a>(
+    parser: &mut Parser<'a, '_>,
+    label: Option<Identifier<'a>>,
+) -> Result<While<'a>, ParseError> {
+    let while_span = parser.consume_keyword(Keyword::WHILE)?;
+    let condition = parse_expression(parser, false)?;
+    let do_span = parser.consume_keyword(Keyword::DO)?;
+    let mut body = Vec::new();
+    parser.recovered(
+        "'END'",
+        &|t| matches!(t, Token::Ident(_, Keyword::END)),
+        |parser| parse_statement_list(parser, &mut body),
+    )?;
+    let end_while_span = parser.consume_keywords(&[Keyword::END, Keyword::WHILE])?;
+    let end_label = parse_end_label(parser, &label)?;
+    Ok(While {
+        label,
+        while_span,
+        condition,
+        do_span,
+        body,
+        end_while_span,
+        end_label,
+    })
+}
+
+fn parse_repeat<'a>(
+    parser: &mut Parser<'a, '_>,
+    label: Option<Identifier<'a>>,
+) -> Result<Repeat<'a>, ParseError> {
+    let repeat_span = parser.consume_keyword(Keyword::REPEAT)?;
+    let mut body = Vec::new();
+    parser.recovered(
+        "'UNTIL'",
+        &|t| matches!(t, Token::Ident(_, Keyword::UNTIL)),
+        |parser| parse_statement_list(parser, &mut body),
+    )?;
+    let until_span = parser.consume_keyword(Keyword::UNTIL)?;
+    let condition = parse_expression(parser, false)?;
+    let end_repeat_span = parser.consume_keywords(&[Keyword::END, Keyword::REPEAT])?;
+    let end_label = parse_end_label(parser, &label)?;
+    Ok(Repeat {
+        label,
+        repeat_span,
+        body,
+        until_span,
+        condition,
+        end_repeat_span,
+        end_label,
+    })
+}
+
+fn parse_loop<'a>(
+    parser: &mut Parser<'a, '_>,
+    label: Option<Identifier<'a>>,
+) -> Result<Loop<'a>, ParseError> {
+    let loop_span = parser.consume_keyword(Keyword::LOOP)?;
+    let mut body = Vec::new();
+    parser.recovered(
+        "'END'",
+        &|t| matches!(t, Token::Ident(_, Keyword::END)),
+        |parser| parse_statement_list(parser, &mut body),
+    )?;
+    let end_loop_span = parser.consume_keywords(&[Keyword::END, Keyword::LOOP])?;
+    let end_label = parse_end_label(parser, &label)?;
+    Ok(Loop {
+        label,
+        loop_span,
+        body,
+        end_loop_span,
+        end_label,
+    })
+}
+
+/// Parse a statement preceded by "label:"
+///
+/// Labels only occur in compound statements, so "BEGIN" and "END" are
+/// parsed as block delimiters within the labeled statement
+fn parse_labeled_statement<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
+    let label = Some(parser.consume_plain_identifier()?);
+    parser.consume_token(Token::Colon)?;
+    let old = core::mem::replace(&mut parser.permit_compound_statements, true);
+    let statement = match &parser.token {
+        Token::Ident(_, Keyword::WHILE) => parse_while(parser, label).map(Statement::While),
+        Token::Ident(_, Keyword::REPEAT) => parse_repeat(parser, label).map(Statement::Repeat),
+        Token::Ident(_, Keyword::LOOP) => parse_loop(parser, label).map(Statement::Loop),
+        Token::Ident(_, Keyword::BEGIN) => parse_block(parser, label).map(Statement::Block),
+        _ => parser.expected_failure("'BEGIN', 'WHILE', 'REPEAT' or 'LOOP'"),
+    };
+    parser.permit_compound_statements = old;
+    statement
+}
+
+/// Condition raised by a signal statement
+#[derive(Clone, Debug, PartialEq)]
+pub enum SignalCondition<'a> {
+    /// Span of "SQLSTATE [VALUE]" and the five character state
+    SqlState(Span, SString<'a>),
+    /// Name of a condition declared with "DECLARE ... CONDITION"
+    Name(Identifier<'a>),
+}
+
+impl<'a> Spanned for SignalCondition<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            SignalCondition::SqlState(s, v) => s.join_span(v),
+            SignalCondition::Name(v) => v.span(),
+        }
+    }
+}
+
+impl<'a> SpansMut for SignalCondition<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            SignalCondition::SqlState(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            SignalCondition::Name(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Condition information item assigned in a signal statement
+#[derive(Clone, Debug, PartialEq)]
+pub enum SignalConditionInformation {
+    ClassOrigin(Span),
+    SubclassOrigin(Span),
+    MessageText(Span),
+    MysqlErrno(Span),
+    ConstraintCatalog(Span),
+    ConstraintSchema(Span),
+    ConstraintName(Span),
+    CatalogName(Span),
+    SchemaName(Span),
+    TableName(Span),
+    ColumnName(Span),
+    CursorName(Span),
+}
+
+impl Spanned for SignalConditionInformation {
+    fn span(&self) -> Span {
+        match &self {
+            SignalConditionInformation::ClassOrigin(v) => v.span(),
+            SignalConditionInformation::SubclassOrigin(v) => v.span(),
+            SignalConditionInformation::MessageText(v) => v.span(),
+            SignalConditionInformation::MysqlErrno(v) => v.span(),
+            SignalConditionInformation::ConstraintCatalog(v) => v.span(),
+            SignalConditionInformation::ConstraintSchema(v) => v.span(),
+            SignalConditionInformation::ConstraintName(v) => v.span(),
+            SignalConditionInformation::CatalogName(v) => v.span(),
+            SignalConditionInformation::SchemaName(v) => v.span(),
+            SignalConditionInformation::TableName(v) => v.span(),
+            SignalConditionInformation::ColumnName(v) => v.span(),
+            SignalConditionInformation::CursorName(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for SignalConditionInformation {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            SignalConditionInformation::ClassOrigin(v) => v.spans_mut(f),
+            SignalConditionInformation::SubclassOrigin(v) => v.spans_mut(f),
+            SignalConditionInformation::MessageText(v) => v.spans_mut(f),
+            SignalConditionInformation::MysqlErrno(v) => v.spans_mut(f),
+            SignalConditionInformation::ConstraintCatalog(v) => v.spans_mut(f),
+            SignalConditionInformation::ConstraintSchema(v) => v.spans_mut(f),
+            SignalConditionInformation::ConstraintName(v) => v.spans_mut(f),
+            SignalConditionInformation::CatalogName(v) => v.spans_mut(f),
+            SignalConditionInformation::SchemaName(v) => v.spans_mut(f),
+            SignalConditionInformation::TableName(v) => v.spans_mut(f),
+            SignalConditionInformation::ColumnName(v) => v.spans_mut(f),
+            SignalConditionInformation::CursorName(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Signal or resignal statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, Signal, SignalCondition, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Bad value', MYSQL_ERRNO = 1644;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let signal: Signal = match stmts.pop() {
+///     Some(Statement::Signal(s)) => s,
+///     _ => panic!("We should get a signal statement")
+/// };
+///
+/// assert!(!signal.resignal);
+/// assert!(matches!(signal.condition, Some(SignalCondition::SqlState(_, v)) if v.as_str() == "45000"));
+/// assert!(signal.set_items.len() == 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Signal<'a> {
+    /// Span of "SIGNAL" or "RESIGNAL"
+    pub signal_span: Span,
+    /// True for "RESIGNAL"
+    pub resignal: bool,
+    /// Condition to raise, always present for "SIGNAL"
+    pub condition: Option<SignalCondition<'a>>,
+    /// Span of "SET" if specified
+    pub set_span: Option<Span>,
+    /// Condition information items to assign
+    pub set_items: Vec<(SignalConditionInformation, Expression<'a>)>,
+}
+
+impl<'a> Spanned for Signal<'a> {
+    fn span(&self) -> Span {
+        self.signal_span
+            .join_span(&self.condition)
+            .join_span(&self.set_span)
+            .join_span(&self.set_items)
+    }
+}
+
+impl<'a> SpansMut for Signal<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.signal_span.spans_mut(f);
+        self.condition.spans_mut(f);
+        self.set_span.spans_mut(f);
+        self.set_items.spans_mut(f);
+    }
+}
+
+/// Parse "SQLSTATE [VALUE] 'xxxxx'" returning the span of "SQLSTATE [VALUE]"
+/// and the state
+fn parse_sqlstate<'a>(parser: &mut Parser<'a, '_>) -> Result<(Span, SString<'a>), ParseError> {
+    let sqlstate_span = parser
+        .consume_keyword(Keyword::SQLSTATE)?
+        .join_span(&parser.skip_keyword(Keyword::VALUE));
+    let value = parser.consume_string()?;
+    if value.len() != 5 || value.starts_with("00") {
+        parser.issues.push(Issue::err(
+            "SQLSTATE must be five characters and not start with '00'",
+            &value,
+        ));
+    }
+    Ok((sqlstate_span, value))
+}
+
+fn parse_signal<'a>(parser: &mut Parser<'a, '_>) -> Result<Signal<'a>, ParseError> {
+    let (signal_span, resignal) = match &parser.token {
+        Token::Ident(_, Keyword::RESIGNAL) => (parser.consume(), true),
+        _ => (parser.consume_keyword(Keyword::SIGNAL)?, false),
+    };
+
+    let condition = match &parser.token {
+        Token::Ident(_, Keyword::SQLSTATE) => {
+            let (sqlstate_span, value) = parse_sqlstate(parser)?;
+            Some(SignalCondition::SqlState(sqlstate_span, value))
+        }
+        Token::Ident(_, Keyword::SET) | Token::SemiColon | Token::Eof => None,
+        Token::Ident(_, k) if !k.reserved() => {
+            Some(SignalCondition::Name(parser.consume_plain_identifier()?))
+        }
+        _ => None,
+    };
+    if condition.is_none() && !resignal {
+        parser.expected_failure("'SQLSTATE' or condition name")?;
+    }
+
+    let set_span = parser.skip_keyword(Keyword::SET);
+    let mut set_items = Vec::new();
+    if set_span.is_some() {
+        loop {
+            let item = match &parser.token {
+                Token::Ident(_, Keyword::CLASS_ORIGIN) => {
+                    SignalConditionInformation::ClassOrigin(parser.consume())
+                }
+                Token::Ident(_, Keyword::SUBCLASS_ORIGIN) => {
+                    SignalConditionInformation::SubclassOrigin(parser.consume())
+                }
+                Token::Ident(_, Keyword::MESSAGE_TEXT) => {
+                    SignalConditionInformation::MessageText(parser.consume())
+                }
+                Token::Ident(_, Keyword::MYSQL_ERRNO) => {
+                    SignalConditionInformation::MysqlErrno(parser.consume())
+                }
+                Token::Ident(_, Keyword::CONSTRAINT_CATALOG) => {
+                    SignalConditionInformation::ConstraintCatalog(parser.consume())
+                }
+                Token::Ident(_, Keyword::CONSTRAINT_SCHEMA) => {
+                    SignalConditionInformation::ConstraintSchema(parser.consume())
+                }
+                Token::Ident(_, Keyword::CONSTRAINT_NAME) => {
+                    SignalConditionInformation::ConstraintName(parser.consume())
+                }
+                Token::Ident(_, Keyword::CATALOG_NAME) => {
+                    SignalConditionInformation::CatalogName(parser.consume())
+                }
+                Token::Ident(_, Keyword::SCHEMA_NAME) => {
+                    SignalConditionInformation::SchemaName(parser.consume())
+                }
+                Token::Ident(_, Keyword::TABLE_NAME) => {
+                    SignalConditionInformation::TableName(parser.consume())
+                }
+                Token::Ident(_, Keyword::COLUMN_NAME) => {
+                    SignalConditionInformation::ColumnName(parser.consume())
+                }
+                Token::Ident(_, Keyword::CURSOR_NAME) => {
+                    SignalConditionInformation::CursorName(parser.consume())
+                }
+                _ => parser.expected_failure("condition information item name")?,
+            };
+            parser.consume_token(Token::Eq)?;
+            let value = parse_expression(parser, false)?;
+            set_items.push((item, value));
+            if parser.skip_token(Token::Comma).is_none() {
+                break;
+            }
+        }
+    }
+
+    Ok(Signal {
+        signal_span,
+        resignal,
+        condition,
+        set_span,
+        set_items,
+    })
+}
+
+/// What happens after the statement of a handler has run
+#[derive(Clone, Debug, PartialEq)]
+pub enum HandlerAction {
+    /// Span of "CONTINUE"
+    Continue(Span),
+    /// Span of "EXIT"
+    Exit(Span),
+    /// Span of "UNDO"
+    Undo(Span),
+}
+
+impl Spanned for HandlerAction {
+    fn span(&self) -> Span {
+        match &self {
+            HandlerAction::Continue(v) => v.span(),
+            HandlerAction::Exit(v) => v.span(),
+            HandlerAction::Undo(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for HandlerAction {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            HandlerAction::Continue(v) => v.spans_mut(f),
+            HandlerAction::Exit(v) => v.spans_mut(f),
+            HandlerAction::Undo(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Condition activating a handler
+#[derive(Clone, Debug, PartialEq)]
+pub enum HandlerCondition<'a> {
+    /// MySQL error code and its span
+    ErrorCode((u64, Span)),
+    /// Span of "SQLSTATE [VALUE]" and the five character state
+    SqlState(Span, SString<'a>),
+    /// Name of a condition declared with "DECLARE ... CONDITION"
+    Name(Identifier<'a>),
+    /// Span of "SQLWARNING"
+    SqlWarning(Span),
+    /// Span of "NOT FOUND"
+    NotFound(Span),
+    /// Span of "SQLEXCEPTION"
+    SqlException(Span),
+}
+
+impl<'a> Spanned for HandlerCondition<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            HandlerCondition::ErrorCode(v) => v.span(),
+            HandlerCondition::SqlState(s, v) => s.join_span(v),
+            HandlerCondition::Name(v) => v.span(),
+            HandlerCondition::SqlWarning(v) => v.span(),
+            HandlerCondition::NotFound(v) => v.span(),
+            HandlerCondition::SqlException(v) => v.span(),
+        }
+    }
+}
+
+impl<'a> SpansMut for HandlerCondition<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            HandlerCondition::ErrorCode(v) => v.spans_mut(f),
+            HandlerCondition::SqlState(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            HandlerCondition::Name(v) => v.spans_mut(f),
+            HandlerCondition::SqlWarning(v) => v.spans_mut(f),
+            HandlerCondition::NotFound(v) => v.spans_mut(f),
+            HandlerCondition::SqlException(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Handler declaration in a routine
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, DeclareHandler, HandlerAction, HandlerCondition, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "DECLARE CONTINUE HANDLER FOR NOT FOUND, SQLSTATE '23000' CALL `log_error`();";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let handler: DeclareHandler = match stmts.pop() {
+///     Some(Statement::DeclareHandler(h)) => h,
+///     _ => panic!("We should get a declare handler statement")
+/// };
+///
+/// assert!(matches!(handler.action, HandlerAction::Continue(_)));
+/// assert!(matches!(handler.conditions[0], HandlerCondition::NotFound(_)));
+/// assert!(matches!(&handler.conditions[1], HandlerCondition::SqlState(_, v) if v.as_str() == "23000"));
+/// assert!(matches!(*handler.statement, Statement::Call(_)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeclareHandler<'a> {
+    /// Span of "DECLARE"
+    pub declare_span: Span,
+    /// What happens after the statement has run
+    pub action: HandlerAction,
+    /// Span of "HANDLER FOR"
+    pub handler_for_span: Span,
+    /// Conditions activating the handler
+    pub conditions: Vec<HandlerCondition<'a>>,
+    /// Statement to run when the handler is activated
+    pub statement: Box<Statement<'a>>,
+}
+
+impl<'a> Spanned for DeclareHandler<'a> {
+    fn span(&self) -> Span {
+        self.declare_span
+            .join_span(&self.action)
+            .join_span(&self.handler_for_span)
+            .join_span(&self.conditions)
+            .join_span(&self.statement)
+    }
+}
+
+impl<'a> SpansMut for DeclareHandler<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.declare_span.spans_mut(f);
+        self.action.spans_mut(f);
+        self.handler_for_span.spans_mut(f);
+        self.conditions.spans_mut(f);
+        self.statement.spans_mut(f);
+    }
+}
+
+fn parse_declare<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
+    let declare_span = parser.consume_keyword(Keyword::DECLARE)?;
+    // TODO DECLARE variable, DECLARE ... CONDITION and DECLARE ... CURSOR
+    let action = match &parser.token {
+        Token::Ident(_, Keyword::CONTINUE) => HandlerAction::Continue(parser.consume()),
+        Token::Ident(_, Keyword::EXIT) => HandlerAction::Exit(parser.consume()),
+        Token::Ident(_, Keyword::UNDO) => HandlerAction::Undo(parser.consume()),
+        _ => parser.expected_failure("'CONTINUE', 'EXIT' or 'UNDO'")?,
+    };
+    let handler_for_span = parser.consume_keywords(&[Keyword::HANDLER, Keyword::FOR])?;
+    let mut conditions = Vec::new();
+    loop {
+        let condition = match &parser.token {
+            Token::Integer(_) => HandlerCondition::ErrorCode(parser.consume_int()?),
+            Token::Ident(_, Keyword::SQLSTATE) => {
+                let (sqlstate_span, value) = parse_sqlstate(parser)?;
+                HandlerCondition::SqlState(sqlstate_span, value)
+            }
+            Token::Ident(_, Keyword::SQLWARNING) => HandlerCondition::SqlWarning(parser.consume()),
+            Token::Ident(_, Keyword::NOT) => HandlerCondition::NotFound(
+                parser.consume_keywords(&[Keyword::NOT, Keyword::FOUND])?,
+            ),
+            Token::Ident(_, Keyword::SQLEXCEPTION) => {
+                HandlerCondition::SqlException(parser.consume())
+            }
+            Token::Ident(_, k) if !k.reserved() => {
+                HandlerCondition::Name(parser.consume_plain_identifier()?)
+            }
+            _ => parser.expected_failure("condition")?,
+        };
+        conditions.push(condition);
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
+    }
+    let statement = match parse_statement(parser)? {
+        Some(v) => Box::new(v),
+        None => parser.expected_failure("statement")?,
+    };
+    Ok(Statement::DeclareHandler(DeclareHandler {
+        declare_span,
+        action,
+        handler_for_span,
+        conditions,
+        statement,
+    }))
+}
+
 /// SQL statement
-#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Statement<'a> {
     CreateIndex(CreateIndex<'a>),
     CreateTable(CreateTable<'a>),
@@ -259,11 +1366,14 @@ pub enum Statement<'a> {
     DropView(DropView<'a>),
     Set(Set<'a>),
     AlterTable(AlterTable<'a>),
-    Block(Vec<Statement<'a>>), //TODO we should include begin and end
-    Begin(Span),
-    End(Span),
-    Commit(Span),
-    StartTransaction(Span),
+    /// Compound statement block "BEGIN ... END"
+    ///
+    /// "BEGIN" opens a block only inside routine bodies (functions, triggers and
+    /// "DO" blocks) or after a label. At the top level it otherwise starts a
+    /// transaction and is parsed as [Transaction::Begin]
+    Block(Block<'a>),
+    /// Transaction control statement such as "BEGIN", "COMMIT" or "ROLLBACK"
+    Transaction(Transaction<'a>),
     If(If<'a>),
     /// Invalid statement produced after recovering from parse error
     Invalid(Span),
@@ -272,9 +1382,35 @@ pub enum Statement<'a> {
     Copy(Copy<'a>),
     Stdin(&'a str, Span),
     CreateTypeEnum(CreateTypeEnum<'a>),
+    CreateDatabase(CreateDatabase<'a>),
+    CreateTablespace(CreateTablespace<'a>),
     Do(Vec<Statement<'a>>),
     TruncateTable(TruncateTable<'a>),
     RenameTable(RenameTable<'a>),
+    ShowTables(ShowTables<'a>),
+    ShowDatabases(ShowDatabases<'a>),
+    ShowColumns(ShowColumns<'a>),
+    ShowCreateTable(ShowCreateTable<'a>),
+    Use(Use<'a>),
+    LockTables(LockTables<'a>),
+    /// Span of "UNLOCK TABLES"
+    UnlockTables(Span),
+    TableMaintenance(TableMaintenance<'a>),
+    LoadData(LoadData<'a>),
+    Signal(Signal<'a>),
+    While(While<'a>),
+    Repeat(Repeat<'a>),
+    Loop(Loop<'a>),
+    /// Span of "LEAVE" and the label of the statement to leave
+    Leave(Span, Identifier<'a>),
+    /// Span of "ITERATE" and the label of the loop to iterate
+    Iterate(Span, Identifier<'a>),
+    Call(Call<'a>),
+    Grant(Grant<'a>),
+    Revoke(Revoke<'a>),
+    Explain(Explain<'a>),
+    Describe(Describe<'a>),
+    DeclareHandler(DeclareHandler<'a>),
 }
 
 impl<'a> Spanned for Statement<'a> {
@@ -300,26 +1436,248 @@ impl<'a> Spanned for Statement<'a> {
             Statement::DropView(v) => v.span(),
             Statement::Set(v) => v.span(),
             Statement::AlterTable(v) => v.span(),
-            Statement::Block(v) => v.opt_span().expect("Span of block"),
+            Statement::Block(v) => v.span(),
             Statement::If(v) => v.span(),
             Statement::Invalid(v) => v.span(),
             Statement::Union(v) => v.span(),
             Statement::Case(v) => v.span(),
             Statement::Copy(v) => v.span(),
             Statement::Stdin(_, s) => s.clone(),
-            Statement::Begin(s) => s.clone(),
-            Statement::End(s) => s.clone(),
-            Statement::Commit(s) => s.clone(),
-            Statement::StartTransaction(s) => s.clone(),
+            Statement::Transaction(v) => v.span(),
             Statement::CreateTypeEnum(v) => v.span(),
+            Statement::CreateDatabase(v) => v.span(),
+            Statement::CreateTablespace(v) => v.span(),
             Statement::Do(v) => v.opt_span().expect("Span of block"),
             Statement::TruncateTable(v) => v.span(),
             Statement::RenameTable(v) => v.span(),
+            Statement::ShowTables(v) => v.span(),
+            Statement::ShowDatabases(v) => v.span(),
+            Statement::ShowColumns(v) => v.span(),
+            Statement::ShowCreateTable(v) => v.span(),
+            Statement::Use(v) => v.span(),
+            Statement::LockTables(v) => v.span(),
+            Statement::UnlockTables(s) => s.clone(),
+            Statement::TableMaintenance(v) => v.span(),
+            Statement::LoadData(v) => v.span(),
+            Statement::Signal(v) => v.span(),
+            Statement::While(v) => v.span(),
+            Statement::Repeat(v) => v.span(),
+            Statement::Loop(v) => v.span(),
+            Statement::Leave(s, v) => s.join_span(v),
+            Statement::Iterate(s, v) => s.join_span(v),
+            Statement::Call(v) => v.span(),
+            Statement::Grant(v) => v.span(),
+            Statement::Revoke(v) => v.span(),
+            Statement::Explain(v) => v.span(),
+            Statement::Describe(v) => v.span(),
+            Statement::DeclareHandler(v) => v.span(),
         }
     }
 }
 
-impl Statement<'_> {
+impl<'a> SpansMut for Statement<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            Statement::CreateIndex(v) => v.spans_mut(f),
+            Statement::CreateTable(v) => v.spans_mut(f),
+            Statement::CreateView(v) => v.spans_mut(f),
+            Statement::CreateTrigger(v) => v.spans_mut(f),
+            Statement::CreateFunction(v) => v.spans_mut(f),
+            Statement::Select(v) => v.spans_mut(f),
+            Statement::Delete(v) => v.spans_mut(f),
+            Statement::InsertReplace(v) => v.spans_mut(f),
+            Statement::Update(v) => v.spans_mut(f),
+            Statement::DropIndex(v) => v.spans_mut(f),
+            Statement::DropTable(v) => v.spans_mut(f),
+            Statement::DropFunction(v) => v.spans_mut(f),
+            Statement::DropProcedure(v) => v.spans_mut(f),
+            Statement::DropEvent(v) => v.spans_mut(f),
+            Statement::DropDatabase(v) => v.spans_mut(f),
+            Statement::DropServer(v) => v.spans_mut(f),
+            Statement::DropTrigger(v) => v.spans_mut(f),
+            Statement::DropView(v) => v.spans_mut(f),
+            Statement::Set(v) => v.spans_mut(f),
+            Statement::AlterTable(v) => v.spans_mut(f),
+            Statement::Block(v) => v.spans_mut(f),
+            Statement::Transaction(v) => v.spans_mut(f),
+            Statement::If(v) => v.spans_mut(f),
+            Statement::Invalid(v) => v.spans_mut(f),
+            Statement::Union(v) => v.spans_mut(f),
+            Statement::Case(v) => v.spans_mut(f),
+            Statement::Copy(v) => v.spans_mut(f),
+            Statement::Stdin(_, v) => v.spans_mut(f),
+            Statement::CreateTypeEnum(v) => v.spans_mut(f),
+            Statement::CreateDatabase(v) => v.spans_mut(f),
+            Statement::CreateTablespace(v) => v.spans_mut(f),
+            Statement::Do(v) => v.spans_mut(f),
+            Statement::TruncateTable(v) => v.spans_mut(f),
+            Statement::RenameTable(v) => v.spans_mut(f),
+            Statement::ShowTables(v) => v.spans_mut(f),
+            Statement::ShowDatabases(v) => v.spans_mut(f),
+            Statement::ShowColumns(v) => v.spans_mut(f),
+            Statement::ShowCreateTable(v) => v.spans_mut(f),
+            Statement::Use(v) => v.spans_mut(f),
+            Statement::LockTables(v) => v.spans_mut(f),
+            Statement::UnlockTables(v) => v.spans_mut(f),
+            Statement::TableMaintenance(v) => v.spans_mut(f),
+            Statement::LoadData(v) => v.spans_mut(f),
+            Statement::Signal(v) => v.spans_mut(f),
+            Statement::While(v) => v.spans_mut(f),
+            Statement::Repeat(v) => v.spans_mut(f),
+            Statement::Loop(v) => v.spans_mut(f),
+            Statement::Leave(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            Statement::Iterate(v0, v1) => {
+                v0.spans_mut(f);
+                v1.spans_mut(f);
+            }
+            Statement::Call(v) => v.spans_mut(f),
+            Statement::Grant(v) => v.spans_mut(f),
+            Statement::Revoke(v) => v.spans_mut(f),
+            Statement::Explain(v) => v.spans_mut(f),
+            Statement::Describe(v) => v.spans_mut(f),
+            Statement::DeclareHandler(v) => v.spans_mut(f),
+        }
+    }
+}
+
+impl<'a> Statement<'a> {
+    /// Byte span of the statement
+    ///
+    /// Returns the same span as [Spanned::span]. Selects and inserts record the
+    /// span of their last token while parsing, so for these the span is found
+    /// in constant time, other statements fall back to [Spanned::span]
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Spanned};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let sql = "INSERT INTO `t1` (`a`, `b`) VALUES (1, 2), (3, 4), (5, 6)";
+    /// let stmt = parse_statement(sql, &mut issues, &options).unwrap();
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// assert_eq!(stmt.outer_span(), stmt.span());
+    /// ```
+    pub fn outer_span(&self) -> Span {
+        match self {
+            Statement::Select(v) => v.select_span.start..v.end_span.end,
+            Statement::InsertReplace(v) => v.type_.span().start..v.end_span.end,
+            _ => self.span(),
+        }
+    }
+
+    /// Compare two statements structurally, ignoring all spans and whether
+    /// identifiers were quoted
+    ///
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let a = parse_statement("SELECT `a` FROM `t1` WHERE `b`=1", &mut issues, &options).unwrap();
+    /// let b = parse_statement("SELECT  `a`\nFROM `t1`\nWHERE `b` = 1", &mut issues, &options).unwrap();
+    /// let c = parse_statement("SELECT `a` FROM `t1` WHERE `b`=2", &mut issues, &options).unwrap();
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// assert!(a.semantic_eq(&b));
+    /// assert!(!a.semantic_eq(&c));
+    /// let d = parse_statement("SELECT a FROM t1 WHERE b=1", &mut issues, &options).unwrap();
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// assert!(a.semantic_eq(&d));
+    /// ```
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        without_spans(self) == without_spans(other)
+    }
+
+    /// Offset every span of the statement by delta bytes
+    ///
+    /// Used to map the spans of a statement parsed from a fragment back to
+    /// the document the fragment was taken from. Spans are clamped at zero
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Spanned, Statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let document = "SELECT 1;\nSELECT `a` FROM `t1`";
+    /// let offset = document.find('\n').unwrap() + 1;
+    /// let mut stmt = parse_statement(&document[offset..], &mut issues, &options).unwrap();
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// stmt.shift_spans(offset as isize);
+    /// let select = match &stmt {
+    ///     Statement::Select(s) => s,
+    ///     _ => panic!("We should get a select statement")
+    /// };
+    /// assert_eq!(&document[stmt.span()], "SELECT `a` FROM `t1`");
+    /// assert_eq!(&document[select.select_exprs[0].span()], "`a`");
+    /// ```
+    pub fn shift_spans(&mut self, delta: isize) {
+        self.spans_mut(&mut |span| {
+            span.start = span.start.saturating_add_signed(delta);
+            span.end = span.end.saturating_add_signed(delta);
+        });
+    }
+
+    /// Names of all tables referenced by the statement
+    ///
+    /// Tables are collected from FROM and JOIN clauses, subqueries, the target
+    /// tables of data modifying statements and trigger tables. Each qualified
+    /// name, compared by the case folded values of its parts,
+    /// is included once with the span of its first occurrence, so tables of the
+    /// same name in different databases are all included
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement, Spanned};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let sql = "CREATE VIEW `v1` AS SELECT `a` FROM `t1` JOIN `t2` ON `t1`.`id` = `t2`.`id`
+    ///     WHERE `b` IN (SELECT `b` FROM `t3`) AND `c` IN (SELECT `c` FROM `t1`)";
+    /// let stmt = parse_statement(sql, &mut issues, &options).unwrap();
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let names: Vec<_> = stmt.referenced_tables().iter().map(|v| v.as_str()).collect();
+    /// assert_eq!(names, ["t1", "t2", "t3"]);
+    ///
+    /// let sql = "SELECT 1 FROM `db1`.`t`, `db2`.`t`, `db1`.`t` AS `x`";
+    /// let stmt = parse_statement(sql, &mut issues, &options).unwrap();
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let tables = stmt.referenced_tables();
+    /// assert_eq!(tables.len(), 2);
+    /// assert!(sql[..tables[1].span().end].ends_with("`db2`.`t`"));
+    /// ```
+    pub fn referenced_tables(&self) -> Vec<&Identifier<'a>> {
+        let mut ans = Vec::new();
+        statement_tables(self, &mut ans);
+        ans.into_iter().map(|(_, identifier)| identifier).collect()
+    }
+
+    /// Rename all table, column and alias identifiers named `from` to `to`,
+    /// returning the number of identifiers changed
+    ///
+    /// Names are compared case sensitively with the case folded identifier
+    /// values. Renamed identifiers own a copy of `to` and keep the span of the
+    /// original text
+    /// ```
+    /// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statement};
+    /// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+    /// # let mut issues = Vec::new();
+    /// let sql = "SELECT `t1`.`a`, `b` FROM `t1` JOIN `t2` ON `t2`.`a` = `t1`.`a` WHERE `a` > 2";
+    /// let mut stmt = parse_statement(sql, &mut issues, &options).unwrap();
+    /// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+    /// let to = String::from("c");
+    /// assert_eq!(stmt.rename_identifier("a", &to), 4);
+    /// drop(to);
+    /// assert_eq!(stmt.rename_identifier("t1", "t3"), 3);
+    /// let names: Vec<_> = stmt.referenced_tables().iter().map(|v| v.as_str()).collect();
+    /// assert_eq!(names, ["t3", "t2"]);
+    /// ```
+    pub fn rename_identifier(&mut self, from: &str, to: &str) -> usize {
+        let mut identifiers = Vec::new();
+        statement_identifiers(self, &mut identifiers);
+        let mut count = 0;
+        for identifier in identifiers {
+            if identifier.value == from {
+                identifier.value = Cow::Owned(to.into());
+                count += 1;
+            }
+        }
+        count
+    }
+
     fn reads_from_stdin(&self) -> bool {
         match self {
             Statement::Copy(v) => v.reads_from_stdin(),
@@ -342,15 +1700,28 @@ pub(crate) fn parse_statement<'a>(
         Token::Ident(_, Keyword::UPDATE) => Some(Statement::Update(parse_update(parser)?)),
         Token::Ident(_, Keyword::SET) => Some(Statement::Set(parse_set(parser)?)),
         Token::Ident(_, Keyword::BEGIN) => Some(if parser.permit_compound_statements {
-            Statement::Block(parse_block(parser)?)
+            Statement::Block(parse_block(parser, None)?)
         } else {
-            Statement::Begin(parse_begin(parser)?)
+            Statement::Transaction(Transaction::Begin(parse_begin(parser)?))
         }),
         Token::Ident(_, Keyword::END) if !parser.permit_compound_statements => {
-            Some(Statement::End(parse_end(parser)?))
+            Some(Statement::Transaction(Transaction::End(parse_end(parser)?)))
         }
-        Token::Ident(_, Keyword::START) => Some(parse_start(parser)?),
-        Token::Ident(_, Keyword::COMMIT) => Some(Statement::Commit(parse_commit(parser)?)),
+        Token::Ident(_, Keyword::START) => Some(Statement::Transaction(
+            Transaction::StartTransaction(parse_start(parser)?),
+        )),
+        Token::Ident(_, Keyword::COMMIT) => Some(Statement::Transaction(Transaction::Commit(
+            parse_commit(parser)?,
+        ))),
+        Token::Ident(_, Keyword::ROLLBACK) => Some(Statement::Transaction(Transaction::Rollback(
+            parse_rollback(parser)?,
+        ))),
+        Token::Ident(_, Keyword::SAVEPOINT) => Some(Statement::Transaction(
+            Transaction::Savepoint(parse_savepoint(parser)?),
+        )),
+        Token::Ident(_, Keyword::RELEASE) => Some(Statement::Transaction(
+            Transaction::ReleaseSavepoint(parse_release_savepoint(parser)?),
+        )),
         Token::Ident(_, Keyword::IF) => Some(Statement::If(parse_if(parser)?)),
         Token::Ident(_, Keyword::ALTER) => Some(parse_alter(parser)?),
         Token::Ident(_, Keyword::CASE) => Some(Statement::Case(parse_case_statement(parser)?)),
@@ -362,6 +1733,43 @@ pub(crate) fn parse_statement<'a>(
         Token::Ident(_, Keyword::RENAME) => {
             Some(Statement::RenameTable(parse_rename_table(parser)?))
         }
+        Token::Ident(_, Keyword::SHOW) => Some(parse_show(parser)?),
+        Token::Ident(_, Keyword::USE) => Some(Statement::Use(parse_use(parser)?)),
+        Token::Ident(_, Keyword::LOCK) => Some(Statement::LockTables(parse_lock_tables(parser)?)),
+        Token::Ident(_, Keyword::UNLOCK) => {
+            Some(Statement::UnlockTables(parse_unlock_tables(parser)?))
+        }
+        Token::Ident(
+            _,
+            Keyword::ANALYZE | Keyword::OPTIMIZE | Keyword::CHECK | Keyword::REPAIR,
+        ) => Some(Statement::TableMaintenance(parse_table_maintenance(
+            parser,
+        )?)),
+        Token::Ident(_, Keyword::LOAD) => Some(Statement::LoadData(parse_load_data(parser)?)),
+        Token::Ident(_, Keyword::SIGNAL | Keyword::RESIGNAL) => {
+            Some(Statement::Signal(parse_signal(parser)?))
+        }
+        Token::Ident(_, Keyword::WHILE) => Some(Statement::While(parse_while(parser, None)?)),
+        Token::Ident(_, Keyword::REPEAT) => Some(Statement::Repeat(parse_repeat(parser, None)?)),
+        Token::Ident(_, Keyword::LOOP) => Some(Statement::Loop(parse_loop(parser, None)?)),
+        Token::Ident(_, Keyword::LEAVE) => Some(Statement::Leave(
+            parser.consume(),
+            parser.consume_plain_identifier()?,
+        )),
+        Token::Ident(_, Keyword::CALL) => Some(Statement::Call(parse_call(parser)?)),
+        Token::Ident(_, Keyword::GRANT) => Some(Statement::Grant(parse_grant(parser)?)),
+        Token::Ident(_, Keyword::REVOKE) => Some(Statement::Revoke(parse_revoke(parser)?)),
+        Token::Ident(_, Keyword::EXPLAIN | Keyword::DESCRIBE | Keyword::DESC) => {
+            Some(parse_explain(parser)?)
+        }
+        Token::Ident(_, Keyword::DECLARE) => Some(parse_declare(parser)?),
+        Token::Ident(_, Keyword::ITERATE) => Some(Statement::Iterate(
+            parser.consume(),
+            parser.consume_plain_identifier()?,
+        )),
+        Token::Ident(_, k) if !k.reserved() && parser.peek_n(1) == Token::Colon => {
+            Some(parse_labeled_statement(parser)?)
+        }
         _ => None,
     })
 }
@@ -369,13 +1777,13 @@ pub(crate) fn parse_statement<'a>(
 pub(crate) fn parse_do<'a>(parser: &mut Parser<'a, '_>) -> Result<Statement<'a>, ParseError> {
     parser.consume_keyword(Keyword::DO)?;
     parser.consume_token(Token::DoubleDollar)?;
-    let block = parse_block(parser)?;
+    let block = parse_block(parser, None)?;
     parser.consume_token(Token::DoubleDollar)?;
-    Ok(Statement::Do(block))
+    Ok(Statement::Do(block.statements))
 }
 
 /// When part of case statement
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct WhenStatement<'a> {
     /// Span of "WHEN"
     pub when_span: Span,
@@ -396,8 +1804,17 @@ impl<'a> Spanned for WhenStatement<'a> {
     }
 }
 
+impl<'a> SpansMut for WhenStatement<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.when_span.spans_mut(f);
+        self.when.spans_mut(f);
+        self.then_span.spans_mut(f);
+        self.then.spans_mut(f);
+    }
+}
+
 /// Case statement
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CaseStatement<'a> {
     /// Span of "CASE"
     pub case_span: Span,
@@ -421,6 +1838,16 @@ impl<'a> Spanned for CaseStatement<'a> {
     }
 }
 
+impl<'a> SpansMut for CaseStatement<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.case_span.spans_mut(f);
+        self.value.spans_mut(f);
+        self.whens.spans_mut(f);
+        self.else_.spans_mut(f);
+        self.end_span.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_case_statement<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<CaseStatement<'a>, ParseError> {
@@ -520,13 +1947,23 @@ pub(crate) fn parse_compound_query_bottom<'a>(
 }
 
 /// Type of union to perform
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UnionType {
     All(Span),
     Distinct(Span),
     Default,
 }
 
+impl SpansMut for UnionType {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            UnionType::All(v) => v.spans_mut(f),
+            UnionType::Distinct(v) => v.spans_mut(f),
+            UnionType::Default => (),
+        }
+    }
+}
+
 impl OptSpanned for UnionType {
     fn opt_span(&self) -> Option<Span> {
         match &self {
@@ -538,7 +1975,7 @@ impl OptSpanned for UnionType {
 }
 
 /// Right hand side of a union expression
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct UnionWith<'a> {
     /// Span of "UNION"
     pub union_span: Span,
@@ -556,15 +1993,23 @@ impl<'a> Spanned for UnionWith<'a> {
     }
 }
 
+impl<'a> SpansMut for UnionWith<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.union_span.spans_mut(f);
+        self.union_type.spans_mut(f);
+        self.union_statement.spans_mut(f);
+    }
+}
+
 /// Union statement
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Union<'a> {
     /// Left side of union
     pub left: Box<Statement<'a>>,
     /// List of things to union
     pub with: Vec<UnionWith<'a>>,
     /// Span of "ORDER BY", and list of ordering expressions and directions if specified
-    pub order_by: Option<(Span, Vec<(Expression<'a>, OrderFlag)>)>,
+    pub order_by: Option<(Span, Vec<OrderKey<'a>>)>,
     /// Span of "LIMIT", offset and count expressions if specified
     pub limit: Option<(Span, Option<Expression<'a>>, Expression<'a>)>,
 }
@@ -578,7 +2023,16 @@ impl<'a> Spanned for Union<'a> {
     }
 }
 
-#[derive(Clone, Debug)]
+impl<'a> SpansMut for Union<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.left.spans_mut(f);
+        self.with.spans_mut(f);
+        self.order_by.spans_mut(f);
+        self.limit.spans_mut(f);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Copy<'a> {
     pub copy_span: Span,
     pub table: Identifier<'a>,
@@ -597,6 +2051,16 @@ impl<'a> Spanned for Copy<'a> {
     }
 }
 
+impl<'a> SpansMut for Copy<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.copy_span.spans_mut(f);
+        self.table.spans_mut(f);
+        self.columns.spans_mut(f);
+        self.from_span.spans_mut(f);
+        self.stdin_span.spans_mut(f);
+    }
+}
+
 impl<'a> Copy<'a> {
     fn reads_from_stdin(&self) -> bool {
         // There are COPY statements that don't read from STDIN,
@@ -633,42 +2097,12 @@ pub(crate) fn parse_compound_query<'a>(
         }
     }
 
-    let order_by = if let Some(span) = parser.skip_keyword(Keyword::ORDER) {
-        let span = parser.consume_keyword(Keyword::BY)?.join_span(&span);
-        let mut order = Vec::new();
-        loop {
-            let e = parse_expression(parser, false)?;
-            let f = match &parser.token {
-                Token::Ident(_, Keyword::ASC) => OrderFlag::Asc(parser.consume()),
-                Token::Ident(_, Keyword::DESC) => OrderFlag::Desc(parser.consume()),
-                _ => OrderFlag::None,
-            };
-            order.push((e, f));
-            if parser.skip_token(Token::Comma).is_none() {
-                break;
-            }
-        }
-        Some((span, order))
-    } else {
-        None
+    let order_by = match &parser.token {
+        Token::Ident(_, Keyword::ORDER) => Some(parse_order_by(parser)?),
+        _ => None,
     };
 
-    let limit = if let Some(span) = parser.skip_keyword(Keyword::LIMIT) {
-        let n = parse_expression(parser, true)?;
-        match parser.token {
-            Token::Comma => {
-                parser.consume();
-                Some((span, Some(n), parse_expression(parser, true)?))
-            }
-            Token::Ident(_, Keyword::OFFSET) => {
-                parser.consume();
-                Some((span, Some(parse_expression(parser, true)?), n))
-            }
-            _ => Some((span, None, n)),
-        }
-    } else {
-        None
-    };
+    let limit = parse_limit(parser)?;
 
     Ok(Statement::Union(Union {
         left: Box::new(q),
@@ -693,6 +2127,10 @@ pub(crate) fn parse_statements<'a>(parser: &mut Parser<'a, '_>) -> Vec<Statement
 
         if parser.skip_keyword(Keyword::DELIMITER).is_some() {
             let t = parser.token.clone();
+            if t == Token::Eof {
+                parser.expected_error("delimiter");
+                return ans;
+            }
 
             if !matches!(t, Token::DoubleDollar | Token::SemiColon) {
                 parser
@@ -704,6 +2142,16 @@ pub(crate) fn parse_statements<'a>(parser: &mut Parser<'a, '_>) -> Vec<Statement
             continue;
         }
 
+        if let Some(max_statements) = parser.options.max_statements {
+            if ans.len() >= max_statements {
+                parser.issues.push(crate::Issue::err(
+                    "Maximum number of statements exceeded",
+                    &parser.span,
+                ));
+                return ans;
+            }
+        }
+
         let stmt = match parse_statement(parser) {
             Ok(Some(v)) => Ok(v),
             Ok(None) => parser.expected_failure("Statement"),
@@ -740,3 +2188,305 @@ pub(crate) fn parse_statements<'a>(parser: &mut Parser<'a, '_>) -> Vec<Statement
         }
     }
 }
+
+/// Prefix and name of the tables found by [Statement::referenced_tables]
+type TableNames<'b, 'a> = Vec<(&'b [(Identifier<'a>, Span)], &'b Identifier<'a>)>;
+
+fn add_table<'b, 'a>(
+    prefix: &'b [(Identifier<'a>, Span)],
+    identifier: &'b Identifier<'a>,
+    ans: &mut TableNames<'b, 'a>,
+) {
+    let same = |(p, i): &(&[(Identifier<'a>, Span)], &Identifier<'a>)| {
+        i.value == identifier.value
+            && p.len() == prefix.len()
+            && p.iter()
+                .zip(prefix)
+                .all(|((a, _), (b, _))| a.value == b.value)
+    };
+    if !ans.iter().any(same) {
+        ans.push((prefix, identifier));
+    }
+}
+
+fn add_qualified_table<'b, 'a>(name: &'b QualifiedName<'a>, ans: &mut TableNames<'b, 'a>) {
+    add_table(&name.prefix, &name.identifier, ans)
+}
+
+fn statements_tables<'b, 'a>(statements: &'b [Statement<'a>], ans: &mut TableNames<'b, 'a>) {
+    for statement in statements {
+        statement_tables(statement, ans);
+    }
+}
+
+fn statement_tables<'b, 'a>(statement: &'b Statement<'a>, ans: &mut TableNames<'b, 'a>) {
+    match statement {
+        Statement::CreateView(v) => select_tables(&v.select, ans),
+        Statement::CreateTable(v) => {
+            if let Some(select) = &v.select {
+                statement_tables(select, ans);
+            }
+        }
+        Statement::CreateTrigger(v) => {
+            add_table(&[], &v.table, ans);
+            if let Some((_, when)) = &v.when {
+                expression_tables(when, ans);
+            }
+            statement_tables(&v.statement, ans);
+        }
+        Statement::CreateFunction(v) => {
+            if let Some(return_) = &v.return_ {
+                statement_tables(return_, ans);
+            }
+        }
+        Statement::Select(v) => select_tables(v, ans),
+        Statement::Delete(v) => {
+            for table in &v.tables {
+                add_qualified_table(table, ans);
+            }
+            for reference in &v.using {
+                table_reference_tables(reference, ans);
+            }
+            if let Some((where_, _)) = &v.where_ {
+                expression_tables(where_, ans);
+            }
+        }
+        Statement::InsertReplace(v) => {
+            add_qualified_table(&v.table, ans);
+            if let Some((_, rows)) = &v.values {
+                for row in rows {
+                    expressions_tables(row, ans);
+                }
+            }
+            if let Some(select) = &v.select {
+                select_tables(select, ans);
+            }
+            if let Some(set) = &v.set {
+                for pair in &set.pairs {
+                    expression_tables(&pair.value, ans);
+                }
+            }
+        }
+        Statement::Update(v) => {
+            for reference in &v.tables {
+                table_reference_tables(reference, ans);
+            }
+            for (_, value) in &v.set {
+                expression_tables(value, ans);
+            }
+            if let Some((where_, _)) = &v.where_ {
+                expression_tables(where_, ans);
+            }
+        }
+        Statement::Block(v) => statements_tables(&v.statements, ans),
+        Statement::Do(v) => statements_tables(v, ans),
+        Statement::If(v) => {
+            for condition in &v.conditions {
+                expression_tables(&condition.search_condition, ans);
+                statements_tables(&condition.then, ans);
+            }
+            if let Some((_, else_)) = &v.else_ {
+                statements_tables(else_, ans);
+            }
+        }
+        Statement::While(v) => {
+            expression_tables(&v.condition, ans);
+            statements_tables(&v.body, ans);
+        }
+        Statement::Repeat(v) => {
+            statements_tables(&v.body, ans);
+            expression_tables(&v.condition, ans);
+        }
+        Statement::Loop(v) => statements_tables(&v.body, ans),
+        Statement::Call(v) => {
+            for arg in &v.args {
+                expression_tables(arg, ans);
+            }
+        }
+        Statement::Case(v) => {
+            expression_tables(&v.value, ans);
+            for when in &v.whens {
+                expression_tables(&when.when, ans);
+                statements_tables(&when.then, ans);
+            }
+            if let Some((_, else_)) = &v.else_ {
+                statements_tables(else_, ans);
+            }
+        }
+        Statement::Union(v) => {
+            statement_tables(&v.left, ans);
+            for with in &v.with {
+                statement_tables(&with.union_statement, ans);
+            }
+            if let Some((_, order_by)) = &v.order_by {
+                for key in order_by {
+                    expression_tables(&key.expr, ans);
+                }
+            }
+        }
+        Statement::LockTables(v) => {
+            for table in &v.tables {
+                add_qualified_table(&table.table, ans);
+            }
+        }
+        Statement::TableMaintenance(v) => {
+            for table in &v.tables {
+                add_qualified_table(table, ans);
+            }
+        }
+        Statement::LoadData(v) => add_qualified_table(&v.table, ans),
+        Statement::Grant(v) => grant_level_tables(&v.object_type, &v.level, ans),
+        Statement::Revoke(v) => grant_level_tables(&v.object_type, &v.level, ans),
+        Statement::Explain(v) => statement_tables(&v.inner, ans),
+        Statement::DeclareHandler(v) => statement_tables(&v.statement, ans),
+        Statement::Describe(v) => add_qualified_table(&v.table, ans),
+        _ => (),
+    }
+}
+
+fn grant_level_tables<'b, 'a>(
+    object_type: &Option<GrantObjectType>,
+    level: &'b GrantLevel<'a>,
+    ans: &mut TableNames<'b, 'a>,
+) {
+    if let (None | Some(GrantObjectType::Table(_)), GrantLevel::Object(name)) = (object_type, level)
+    {
+        add_qualified_table(name, ans);
+    }
+}
+
+fn select_tables<'b, 'a>(select: &'b Select<'a>, ans: &mut TableNames<'b, 'a>) {
+    for select_expr in &select.select_exprs {
+        expression_tables(&select_expr.expr, ans);
+    }
+    if let Some(references) = &select.table_references {
+        for reference in references {
+            table_reference_tables(reference, ans);
+        }
+    }
+    if let Some((where_, _)) = &select.where_ {
+        expression_tables(where_, ans);
+    }
+    if let Some((_, group_by)) = &select.group_by {
+        expressions_tables(group_by, ans);
+    }
+    if let Some((having, _)) = &select.having {
+        expression_tables(having, ans);
+    }
+    if let Some((_, order_by)) = &select.order_by {
+        for key in order_by {
+            expression_tables(&key.expr, ans);
+        }
+    }
+}
+
+fn table_reference_tables<'b, 'a>(reference: &'b TableReference<'a>, ans: &mut TableNames<'b, 'a>) {
+    match reference {
+        TableReference::Table { identifier, .. } => add_qualified_table(identifier, ans),
+        TableReference::Query { query, .. } => statement_tables(query, ans),
+        TableReference::Join {
+            left,
+            right,
+            specification,
+            ..
+        } => {
+            table_reference_tables(left, ans);
+            table_reference_tables(right, ans);
+            if let Some(JoinSpecification::On(e, _)) = specification {
+                expression_tables(e, ans);
+            }
+        }
+    }
+}
+
+fn expressions_tables<'b, 'a>(expressions: &'b [Expression<'a>], ans: &mut TableNames<'b, 'a>) {
+    for expression in expressions {
+        expression_tables(expression, ans);
+    }
+}
+
+fn expression_tables<'b, 'a>(expression: &'b Expression<'a>, ans: &mut TableNames<'b, 'a>) {
+    match expression {
+        Expression::Binary { lhs, rhs, .. } => {
+            expression_tables(lhs, ans);
+            expression_tables(rhs, ans);
+        }
+        Expression::Unary { operand, .. } => expression_tables(operand, ans),
+        Expression::Subquery(s) | Expression::Exists(s) => statement_tables(s, ans),
+        Expression::Function(_, args, _)
+        | Expression::AggregateFunction { args, .. }
+        | Expression::Row { values: args, .. } => expressions_tables(args, ans),
+        Expression::WindowFunction {
+            args, window_spec, ..
+        } => {
+            expressions_tables(args, ans);
+            for key in &window_spec.order_by.1 {
+                expression_tables(&key.expr, ans);
+            }
+        }
+        Expression::Match {
+            columns, against, ..
+        } => {
+            expressions_tables(columns, ans);
+            expression_tables(against, ans);
+        }
+        Expression::In { lhs, rhs, .. } => {
+            expression_tables(lhs, ans);
+            expressions_tables(rhs, ans);
+        }
+        Expression::Is(e, _, _) => expression_tables(e, ans),
+        Expression::Case {
+            value,
+            whens,
+            else_,
+            ..
+        } => {
+            if let Some(value) = value {
+                expression_tables(value, ans);
+            }
+            for when in whens {
+                expression_tables(&when.when, ans);
+                expression_tables(&when.then, ans);
+            }
+            if let Some((_, else_)) = else_ {
+                expression_tables(else_, ans);
+            }
+        }
+        Expression::Trim { remove, expr, .. } => {
+            if let Some(remove) = remove {
+                expression_tables(remove, ans);
+            }
+            expression_tables(expr, ans);
+        }
+        Expression::JsonValue {
+            doc,
+            path,
+            on_empty,
+            on_error,
+            ..
+        } => {
+            expression_tables(doc, ans);
+            expression_tables(path, ans);
+            for (response, _) in [on_empty, on_error].into_iter().flatten() {
+                if let JsonOnResponse::Default(_, value) = response {
+                    expression_tables(value, ans);
+                }
+            }
+        }
+        Expression::GroupConcat { expr, order_by, .. } => {
+            expression_tables(expr, ans);
+            if let Some((_, order)) = order_by {
+                for key in order {
+                    expression_tables(&key.expr, ans);
+                }
+            }
+        }
+        Expression::Cast { expr, .. }
+        | Expression::Collate { expr, .. }
+        | Expression::JsonExtract { target: expr, .. }
+        | Expression::Count { expr, .. }
+        | Expression::Convert { expr, .. }
+        | Expression::Interval { expr, .. } => expression_tables(expr, ans),
+        _ => (),
+    }
+}
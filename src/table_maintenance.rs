@@ -0,0 +1,246 @@
+use alloc::vec::Vec;
+
+use crate::{
+    keywords::Keyword,
+    lexer::Token,
+    parser::{ParseError, Parser},
+    qualified_name::parse_qualified_name,
+    Issue, QualifiedName, Span, Spanned, SpansMut,
+};
+
+/// The kind of table maintenance to perform
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableMaintenanceKind {
+    /// Span of "ANALYZE"
+    Analyze(Span),
+    /// Span of "OPTIMIZE"
+    Optimize(Span),
+    /// Span of "CHECK"
+    Check(Span),
+    /// Span of "REPAIR"
+    Repair(Span),
+}
+
+impl Spanned for TableMaintenanceKind {
+    fn span(&self) -> Span {
+        match &self {
+            TableMaintenanceKind::Analyze(v) => v.span(),
+            TableMaintenanceKind::Optimize(v) => v.span(),
+            TableMaintenanceKind::Check(v) => v.span(),
+            TableMaintenanceKind::Repair(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for TableMaintenanceKind {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TableMaintenanceKind::Analyze(v) => v.spans_mut(f),
+            TableMaintenanceKind::Optimize(v) => v.spans_mut(f),
+            TableMaintenanceKind::Check(v) => v.spans_mut(f),
+            TableMaintenanceKind::Repair(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Option given to a table maintenance statement
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableMaintenanceOption {
+    /// Span of "NO_WRITE_TO_BINLOG"
+    NoWriteToBinlog(Span),
+    /// Span of "LOCAL"
+    Local(Span),
+    /// Span of "FOR UPGRADE"
+    ForUpgrade(Span),
+    /// Span of "QUICK"
+    Quick(Span),
+    /// Span of "FAST"
+    Fast(Span),
+    /// Span of "MEDIUM"
+    Medium(Span),
+    /// Span of "EXTENDED"
+    Extended(Span),
+    /// Span of "CHANGED"
+    Changed(Span),
+    /// Span of "USE_FRM"
+    UseFrm(Span),
+}
+
+impl Spanned for TableMaintenanceOption {
+    fn span(&self) -> Span {
+        match &self {
+            TableMaintenanceOption::NoWriteToBinlog(v) => v.span(),
+            TableMaintenanceOption::Local(v) => v.span(),
+            TableMaintenanceOption::ForUpgrade(v) => v.span(),
+            TableMaintenanceOption::Quick(v) => v.span(),
+            TableMaintenanceOption::Fast(v) => v.span(),
+            TableMaintenanceOption::Medium(v) => v.span(),
+            TableMaintenanceOption::Extended(v) => v.span(),
+            TableMaintenanceOption::Changed(v) => v.span(),
+            TableMaintenanceOption::UseFrm(v) => v.span(),
+        }
+    }
+}
+
+impl SpansMut for TableMaintenanceOption {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            TableMaintenanceOption::NoWriteToBinlog(v) => v.spans_mut(f),
+            TableMaintenanceOption::Local(v) => v.spans_mut(f),
+            TableMaintenanceOption::ForUpgrade(v) => v.spans_mut(f),
+            TableMaintenanceOption::Quick(v) => v.spans_mut(f),
+            TableMaintenanceOption::Fast(v) => v.spans_mut(f),
+            TableMaintenanceOption::Medium(v) => v.spans_mut(f),
+            TableMaintenanceOption::Extended(v) => v.spans_mut(f),
+            TableMaintenanceOption::Changed(v) => v.spans_mut(f),
+            TableMaintenanceOption::UseFrm(v) => v.spans_mut(f),
+        }
+    }
+}
+
+/// Represent an analyze, optimize, check or repair table statement
+/// ```
+/// # use sql_parse::{SQLDialect, SQLArguments, ParseOptions, parse_statements, TableMaintenance, TableMaintenanceKind, TableMaintenanceOption, Statement};
+/// # let options = ParseOptions::new().dialect(SQLDialect::MariaDB);
+/// # let mut issues = Vec::new();
+/// #
+/// let sql = "ANALYZE NO_WRITE_TO_BINLOG TABLE `t1`; CHECK TABLE `t1`, `t2` QUICK FAST;";
+/// let mut stmts = parse_statements(sql, &mut issues, &options);
+///
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// #
+/// let check: TableMaintenance = match stmts.pop() {
+///     Some(Statement::TableMaintenance(c)) => c,
+///     _ => panic!("We should get a table maintenance statement")
+/// };
+///
+/// assert!(matches!(check.kind, TableMaintenanceKind::Check(_)));
+/// assert!(check.tables.len() == 2);
+/// assert!(matches!(check.options[0], TableMaintenanceOption::Quick(_)));
+///
+/// let analyze: TableMaintenance = match stmts.pop() {
+///     Some(Statement::TableMaintenance(c)) => c,
+///     _ => panic!("We should get a table maintenance statement")
+/// };
+/// assert!(matches!(analyze.kind, TableMaintenanceKind::Analyze(_)));
+/// assert!(matches!(analyze.flags[0], TableMaintenanceOption::NoWriteToBinlog(_)));
+///
+/// // REPAIR does not accept the CHECK options
+/// let sql = "REPAIR TABLE `t1` FAST;";
+/// parse_statements(sql, &mut issues, &options);
+/// assert!(issues.len() == 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableMaintenance<'a> {
+    /// What maintenance to perform
+    pub kind: TableMaintenanceKind,
+    /// Flags specified before "TABLE"
+    pub flags: Vec<TableMaintenanceOption>,
+    /// Span of "TABLE"
+    pub table_span: Span,
+    /// Tables to perform maintenance on
+    pub tables: Vec<QualifiedName<'a>>,
+    /// Options specified after the tables
+    pub options: Vec<TableMaintenanceOption>,
+}
+
+impl<'a> Spanned for TableMaintenance<'a> {
+    fn span(&self) -> Span {
+        self.kind
+            .join_span(&self.flags)
+            .join_span(&self.table_span)
+            .join_span(&self.tables)
+            .join_span(&self.options)
+    }
+}
+
+impl<'a> SpansMut for TableMaintenance<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.kind.spans_mut(f);
+        self.flags.spans_mut(f);
+        self.table_span.spans_mut(f);
+        self.tables.spans_mut(f);
+        self.options.spans_mut(f);
+    }
+}
+
+pub(crate) fn parse_table_maintenance<'a>(
+    parser: &mut Parser<'a, '_>,
+) -> Result<TableMaintenance<'a>, ParseError> {
+    let kind = match &parser.token {
+        Token::Ident(_, Keyword::ANALYZE) => TableMaintenanceKind::Analyze(parser.consume()),
+        Token::Ident(_, Keyword::OPTIMIZE) => TableMaintenanceKind::Optimize(parser.consume()),
+        Token::Ident(_, Keyword::CHECK) => TableMaintenanceKind::Check(parser.consume()),
+        Token::Ident(_, Keyword::REPAIR) => TableMaintenanceKind::Repair(parser.consume()),
+        _ => parser.expected_failure("'ANALYZE', 'OPTIMIZE', 'CHECK' or 'REPAIR'")?,
+    };
+
+    let mut flags = Vec::new();
+    loop {
+        match &parser.token {
+            Token::Ident(_, Keyword::NO_WRITE_TO_BINLOG) => {
+                flags.push(TableMaintenanceOption::NoWriteToBinlog(parser.consume()))
+            }
+            Token::Ident(_, Keyword::LOCAL) => {
+                flags.push(TableMaintenanceOption::Local(parser.consume()))
+            }
+            _ => break,
+        }
+    }
+    if let (TableMaintenanceKind::Check(_), Some(flag)) = (&kind, flags.first()) {
+        parser
+            .issues
+            .push(Issue::err("Not supported by CHECK TABLE", flag));
+    }
+
+    let table_span = parser.consume_keyword(Keyword::TABLE)?;
+    let mut tables = Vec::new();
+    loop {
+        tables.push(parse_qualified_name(parser)?);
+        if parser.skip_token(Token::Comma).is_none() {
+            break;
+        }
+    }
+
+    let mut options = Vec::new();
+    loop {
+        let option = match &parser.token {
+            Token::Ident(_, Keyword::FOR) => TableMaintenanceOption::ForUpgrade(
+                parser.consume_keywords(&[Keyword::FOR, Keyword::UPGRADE])?,
+            ),
+            Token::Ident(_, Keyword::QUICK) => TableMaintenanceOption::Quick(parser.consume()),
+            Token::Ident(_, Keyword::FAST) => TableMaintenanceOption::Fast(parser.consume()),
+            Token::Ident(_, Keyword::MEDIUM) => TableMaintenanceOption::Medium(parser.consume()),
+            Token::Ident(_, Keyword::EXTENDED) => {
+                TableMaintenanceOption::Extended(parser.consume())
+            }
+            Token::Ident(_, Keyword::CHANGED) => TableMaintenanceOption::Changed(parser.consume()),
+            Token::Ident(_, Keyword::USE_FRM) => TableMaintenanceOption::UseFrm(parser.consume()),
+            _ => break,
+        };
+        let supported = match &kind {
+            TableMaintenanceKind::Check(_) => !matches!(option, TableMaintenanceOption::UseFrm(_)),
+            TableMaintenanceKind::Repair(_) => matches!(
+                option,
+                TableMaintenanceOption::Quick(_)
+                    | TableMaintenanceOption::Extended(_)
+                    | TableMaintenanceOption::UseFrm(_)
+            ),
+            TableMaintenanceKind::Analyze(_) | TableMaintenanceKind::Optimize(_) => false,
+        };
+        if !supported {
+            parser
+                .issues
+                .push(Issue::err("Option not supported here", &option));
+        }
+        options.push(option);
+    }
+
+    Ok(TableMaintenance {
+        kind,
+        flags,
+        table_span,
+        tables,
+        options,
+    })
+}
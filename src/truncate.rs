@@ -2,7 +2,7 @@ use crate::{
     keywords::Keyword,
     parser::{ParseError, Parser},
     qualified_name::parse_qualified_name,
-    QualifiedName, Span, Spanned,
+    QualifiedName, Span, Spanned, SpansMut,
 };
 
 /// Represent a truncate table statement
@@ -22,15 +22,26 @@ use crate::{
 /// };
 ///
 /// assert!(truncate_table.table_name.identifier.as_str() == "t1");
+/// assert!(truncate_table.table_span.is_some());
 ///
+/// // The "TABLE" keyword is optional
+/// let mut stmts = parse_statements("TRUNCATE `t2`;", &mut issues, &options);
+/// # assert!(issues.is_empty(), "Issues: {:#?}", issues);
+/// let truncate_table: TruncateTable = match stmts.pop() {
+///     Some(Statement::TruncateTable(c)) => c,
+///     _ => panic!("We should get a truncate table statement")
+/// };
+/// assert!(truncate_table.table_name.identifier.as_str() == "t2");
+/// assert!(truncate_table.table_span.is_none());
 /// ```
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TruncateTable<'a> {
     /// Span of "TRUNCATE"
     pub truncate_span: Span,
     /// Span of "TABLE" if specified
     pub table_span: Option<Span>,
+    /// Name of the table to truncate
     pub table_name: QualifiedName<'a>,
 }
 
@@ -42,6 +53,14 @@ impl<'a> Spanned for TruncateTable<'a> {
     }
 }
 
+impl<'a> SpansMut for TruncateTable<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.truncate_span.spans_mut(f);
+        self.table_span.spans_mut(f);
+        self.table_name.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_truncate_table<'a>(
     parser: &mut Parser<'a, '_>,
 ) -> Result<TruncateTable<'a>, ParseError> {
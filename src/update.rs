@@ -20,11 +20,11 @@ use crate::{
     parser::{ParseError, Parser},
     select::{parse_table_reference, TableReference},
     span::OptSpanned,
-    Identifier, Span, Spanned,
+    Identifier, Span, Spanned, SpansMut,
 };
 
 /// Flags specified after "UPDATE"
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum UpdateFlag {
     LowPriority(Span),
     Ignore(Span),
@@ -39,6 +39,15 @@ impl Spanned for UpdateFlag {
     }
 }
 
+impl SpansMut for UpdateFlag {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        match self {
+            UpdateFlag::LowPriority(v) => v.spans_mut(f),
+            UpdateFlag::Ignore(v) => v.spans_mut(f),
+        }
+    }
+}
+
 /// Representation of replace Statement
 ///
 /// ```
@@ -58,7 +67,7 @@ impl Spanned for UpdateFlag {
 ///
 /// println!("{:#?}", u.where_.unwrap())
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Update<'a> {
     /// Span of "UPDATE"
     pub update_span: Span,
@@ -90,6 +99,17 @@ impl<'a> Spanned for Update<'a> {
     }
 }
 
+impl<'a> SpansMut for Update<'a> {
+    fn spans_mut(&mut self, f: &mut dyn FnMut(&mut Span)) {
+        self.update_span.spans_mut(f);
+        self.flags.spans_mut(f);
+        self.tables.spans_mut(f);
+        self.set_span.spans_mut(f);
+        self.set.spans_mut(f);
+        self.where_.spans_mut(f);
+    }
+}
+
 pub(crate) fn parse_update<'a>(parser: &mut Parser<'a, '_>) -> Result<Update<'a>, ParseError> {
     let update_span = parser.consume_keyword(Keyword::UPDATE)?;
     let mut flags = Vec::new();
@@ -0,0 +1,123 @@
+//! Property tests checking that the parser never panics on arbitrary input
+
+use proptest::prelude::*;
+use sql_parse::{
+    parse_statement, parse_statements, ParseOptions, SQLArguments, SQLDialect, Spanned,
+};
+
+/// Keywords, identifiers, literals and punctuation to build statements from
+#[rustfmt::skip]
+const FRAGMENTS: &[&str] = &[
+    "SELECT", "FROM", "WHERE", "INSERT", "INTO", "VALUES", "UPDATE", "SET", "DELETE", "CREATE",
+    "TABLE", "ALTER", "DROP", "INDEX", "JOIN", "LEFT", "ON", "USING", "AS", "AND", "OR", "NOT",
+    "NULL", "IS", "IN", "LIKE", "BETWEEN", "CASE", "WHEN", "THEN", "ELSE", "END", "GROUP", "BY",
+    "ORDER", "LIMIT", "OFFSET", "HAVING", "UNION", "ALL", "DISTINCT", "COUNT", "GROUP_CONCAT",
+    "SEPARATOR", "INTERVAL", "DAY", "CAST", "CONVERT", "TRIM", "DEFAULT", "PRIMARY", "KEY",
+    "CHECK", "CONSTRAINT", "FUNCTION", "RETURNS", "BEGIN", "DELIMITER", "IF", "EXISTS", "INT",
+    "VARCHAR", "CHARSET", "COLLATE", "`a`", "`b`", "t1", "x", "_utf8mb4", "'str'", "'it''s'",
+    "\"q\"", "1", "0", "-", "+", "*", "/", "%", "=", "<>", "<=", ">", "(", ")", ",", ";", ".", "?",
+    "x'", "0x1F", "1e5", "1.5e", ".5", "$", "@", "@@", "'", "`", "/*+", "::", "!", "~", "\\N",
+    "E'", "0b1", "1e", "$99999999999999999999999", "18446744073709551616", "$1", "%s", "@@GLOBAL",
+    "->", "->>", "/*", "*/", "--", "#", "$$", "\\", "\n", "é", "∑",
+];
+
+/// Sequences of fragments, joined by spaces or glued together to also exercise the lexer
+fn sql_fragments() -> impl Strategy<Value = String> {
+    (
+        prop::collection::vec(prop::sample::select(FRAGMENTS), 0..40),
+        any::<bool>(),
+    )
+        .prop_map(|(v, glue)| v.join(if glue { "" } else { " " }))
+}
+
+fn check(sql: &str) {
+    for dialect in [SQLDialect::MariaDB, SQLDialect::PostgreSQL] {
+        let arguments = match dialect {
+            SQLDialect::PostgreSQL => SQLArguments::Dollar,
+            SQLDialect::MariaDB => SQLArguments::QuestionMark,
+        };
+        let options = ParseOptions::new()
+            .dialect(dialect)
+            .arguments(arguments)
+            .warn_unquoted_identifiers(true);
+
+        let mut issues = Vec::new();
+        let statement = parse_statement(sql, &mut issues, &options);
+        assert!(
+            statement.is_some() || !issues.is_empty(),
+            "No statement and no issues for {:?}",
+            sql
+        );
+        if let Some(statement) = &statement {
+            let span = statement.span();
+            assert!(
+                span.end <= sql.len(),
+                "Statement span out of bounds for {:?}",
+                sql
+            );
+        }
+        for issue in &issues {
+            assert!(
+                issue.span.start <= issue.span.end,
+                "Reversed issue span for {:?}",
+                sql
+            );
+            assert!(
+                issue.span.end <= sql.len(),
+                "Issue span out of bounds for {:?}",
+                sql
+            );
+            for fragment in &issue.fragments {
+                assert!(
+                    fragment.1.end <= sql.len(),
+                    "Fragment span out of bounds for {:?}",
+                    sql
+                );
+            }
+        }
+
+        let mut issues = Vec::new();
+        for statement in parse_statements(sql, &mut issues, &options) {
+            assert!(
+                statement.span().end <= sql.len(),
+                "Statement span out of bounds for {:?}",
+                sql
+            );
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 1000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn parse_fragments_does_not_panic(sql in sql_fragments()) {
+        check(&sql);
+    }
+
+    #[test]
+    fn parse_arbitrary_does_not_panic(sql in "\\PC{0,80}") {
+        check(&sql);
+    }
+}
+
+#[test]
+fn parse_hex_literal_does_not_panic() {
+    check("SELECT x'");
+    check("SELECT X'4D' + 1");
+}
+
+#[test]
+fn parse_trailing_delimiter_terminates() {
+    check("DELIMITER");
+    check("SELECT 1; DELIMITER");
+}
+
+#[test]
+fn parse_large_dollar_argument_does_not_panic() {
+    check("SELECT $99999999999999999999999");
+}